@@ -10,7 +10,6 @@ use std::sync::Arc;
 /// Speech recognition API
 #[derive(Clone)]
 pub struct Speech {
-    #[allow(dead_code)]
     config: Arc<Config>,
 }
 
@@ -19,6 +18,16 @@ impl Speech {
     pub fn new(config: Arc<Config>) -> Self {
         Self { config }
     }
+
+    /// 识别流式语音（单个分片请求）。
+    pub fn stream_recognize(&self) -> stream_recognize::StreamRecognizeRequest {
+        stream_recognize::StreamRecognizeRequest::new((*self.config).clone())
+    }
+
+    /// 创建流式语音识别会话，自动处理分片、编码与序号。
+    pub fn stream(&self) -> crate::common::SpeechStream {
+        crate::common::SpeechStream::new((*self.config).clone())
+    }
 }
 
 #[cfg(test)]
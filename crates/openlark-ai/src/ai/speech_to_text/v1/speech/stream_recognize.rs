@@ -6,46 +6,92 @@ use openlark_core::{
     validate_required,
 };
 use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::common::api_utils::{extract_response_data, serialize_params};
 use crate::endpoints::SPEECH_TO_TEXT_V1_SPEECH_STREAM_RECOGNIZE;
 
+/// 流式识别分片动作。
+///
+/// 同一个 `stream_id` 的分片需按 `sequence_id` 递增顺序发送，首片为 `Start`，
+/// 末片为 `End`，中间分片为 `Continue`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(i32)]
+pub enum StreamAction {
+    /// 正常发送中间分片。
+    Continue = 0,
+    /// 开始识别（首个分片）。
+    Start = 1,
+    /// 结束识别并返回最终结果。
+    End = 2,
+    /// 中断识别，不再返回结果。
+    Cancel = 3,
+}
+
+/// 流式识别音频分片。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StreamSpeech {
+    /// 音频分片（16kHz、16bit、单声道 PCM 的 Base64 编码）。
+    pub speech: String,
+}
+
+/// 流式识别配置。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamRecognizeConfig {
+    /// 数据流标识，16 位字母、数字或下划线组成的字符串，由调用方生成。
+    pub stream_id: String,
+    /// 分片序号，从 0 开始，每次请求递增 1。
+    pub sequence_id: i32,
+    /// 分片动作。
+    pub action: StreamAction,
+    /// 音频格式，目前仅支持 `pcm`。
+    pub format: String,
+    /// 引擎类型，目前仅支持 `16k_auto`。
+    pub engine_type: String,
+}
+
 /// 识别流式语音请求体
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamRecognizeBody {
-    /// 音频数据（Base64 编码）
-    pub audio: String,
-    /// 语言类型
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
-    /// 识别格式
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
-    /// 是否开启中间结果
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_intermediate_result: Option<bool>,
-    /// 是否开启标点符号
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_punctuation: Option<bool>,
-    /// 是否开启自动分段
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_automatic_segmentation: Option<bool>,
+    /// 音频分片。
+    pub speech: StreamSpeech,
+    /// 识别配置。
+    pub config: StreamRecognizeConfig,
 }
 
 impl StreamRecognizeBody {
     /// 校验请求体。
     pub fn validate(&self) -> openlark_core::SDKResult<()> {
-        validate_required!(self.audio, "audio 不能为空");
+        validate_required!(self.config.stream_id, "stream_id 不能为空");
+        if self.config.sequence_id < 0 {
+            return Err(openlark_core::error::validation_error(
+                "sequence_id",
+                "sequence_id 不能小于 0",
+            ));
+        }
+        // 结束/中断分片允许不携带音频数据。
+        if matches!(
+            self.config.action,
+            StreamAction::Start | StreamAction::Continue
+        ) {
+            validate_required!(self.speech.speech, "speech 不能为空");
+        }
         Ok(())
     }
 }
 
 /// 识别流式语音响应
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StreamRecognizeResponse {
-    /// data 字段。
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<serde_json::Value>,
+    /// 数据流标识。
+    #[serde(default)]
+    pub stream_id: String,
+    /// 分片序号。
+    #[serde(default)]
+    pub sequence_id: i32,
+    /// 截至当前分片的识别文本。
+    #[serde(default)]
+    pub recognition_text: String,
 }
 
 impl openlark_core::api::ApiResponseTrait for StreamRecognizeResponse {}
@@ -104,17 +150,31 @@ mod tests {
         assert_eq!(value["field"], "data");
     }
 
+    fn body(action: StreamAction, speech: &str) -> StreamRecognizeBody {
+        StreamRecognizeBody {
+            speech: StreamSpeech {
+                speech: speech.to_string(),
+            },
+            config: StreamRecognizeConfig {
+                stream_id: "asd1234567890ddd".to_string(),
+                sequence_id: 1,
+                action,
+                format: "pcm".to_string(),
+                engine_type: "16k_auto".to_string(),
+            },
+        }
+    }
+
     #[test]
     fn test_validate_requires_audio() {
-        let body = StreamRecognizeBody {
-            audio: String::new(),
-            language: None,
-            format: None,
-            enable_intermediate_result: None,
-            enable_punctuation: None,
-            enable_automatic_segmentation: None,
-        };
-
-        assert!(body.validate().is_err());
+        assert!(body(StreamAction::Continue, "").validate().is_err());
+        assert!(body(StreamAction::End, "").validate().is_ok());
+    }
+
+    #[test]
+    fn test_body_serializes_action_as_number() {
+        let value = serde_json::to_value(body(StreamAction::Start, "AAAA")).expect("序列化失败");
+        assert_eq!(value["config"]["action"], 1);
+        assert_eq!(value["speech"]["speech"], "AAAA");
     }
 }
@@ -100,10 +100,7 @@ mod tests {
 
     #[test]
     fn test_extract_response_data_success() {
-        let response: Response<String> = Response {
-            data: Some("test_data".to_string()),
-            raw_response: RawResponse::success(),
-        };
+        let response: Response<String> = Response::new(Some("test_data".to_string()), RawResponse::success());
         let result = extract_response_data(response, "测试");
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "test_data");
@@ -111,10 +108,7 @@ mod tests {
 
     #[test]
     fn test_extract_response_data_empty() {
-        let response: Response<String> = Response {
-            data: None,
-            raw_response: RawResponse::success(),
-        };
+        let response: Response<String> = Response::new(None, RawResponse::success());
         let result = extract_response_data(response, "测试");
         assert!(result.is_err());
     }
@@ -30,6 +30,11 @@ impl DocumentAiClient {
     pub fn config(&self) -> &Config {
         &self.config
     }
+
+    /// 文档统一识别入口：直接上传文件并返回强类型识别结果。
+    pub fn recognizer(&self) -> crate::common::document_recognition::DocumentRecognizer {
+        crate::common::document_recognition::DocumentRecognizer::new((*self.config).clone())
+    }
 }
 
 /// Document AI v1：`document_ai.v1`
@@ -7,8 +7,29 @@ pub mod api_utils;
 // 链式调用入口
 pub mod chain;
 
+// 文档统一识别与批量识别
+pub mod document_batch;
+pub mod document_recognition;
+
+// PCM 音频处理工具
+pub mod pcm;
+
+// 流式语音识别会话
+pub mod speech_stream;
+
 // 重导出 API 工具函数，方便外部使用
 pub use api_utils::{ensure_success, extract_response_data, serialize_params};
 
 // 重导出链式调用入口
 pub use chain::{DocumentAiClient, DocumentAiV1Client, RecognizeResource};
+
+// 重导出流式语音识别会话
+pub use pcm::PcmFormat;
+pub use speech_stream::{SpeechStream, Transcript};
+
+// 重导出文档统一识别与批量识别
+pub use document_batch::{BatchItem, BatchReport, CsvSummary, DocumentBatch};
+pub use document_recognition::{
+    BankCard, BusinessLicense, DocumentEntity, DocumentInput, DocumentKind, DocumentRecognizer,
+    IdCard, RecognizedDocument, RecognizedField, TaxiInvoice, TrainInvoice, VatInvoice,
+};
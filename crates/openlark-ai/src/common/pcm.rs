@@ -0,0 +1,305 @@
+//! PCM 音频处理工具
+//!
+//! 流式语音识别要求 16kHz、16bit、单声道的小端 PCM 数据。本模块提供 WAV 头解析、
+//! 声道下混和采样率转换（例如 8kHz / 48kHz → 16kHz），用于在发送前规整音频格式。
+
+use std::ops::Range;
+
+use openlark_core::{SDKResult, error::validation_error};
+
+/// 流式语音识别要求的采样率。
+pub const TARGET_SAMPLE_RATE: u32 = 16_000;
+
+/// PCM 音频格式（固定为 16bit 小端采样）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcmFormat {
+    /// 采样率（Hz）。
+    pub sample_rate: u32,
+    /// 声道数。
+    pub channels: u16,
+}
+
+impl Default for PcmFormat {
+    fn default() -> Self {
+        Self::TARGET
+    }
+}
+
+impl PcmFormat {
+    /// 识别接口要求的格式：16kHz 单声道。
+    pub const TARGET: PcmFormat = PcmFormat {
+        sample_rate: TARGET_SAMPLE_RATE,
+        channels: 1,
+    };
+
+    /// 创建 PCM 格式描述。
+    pub fn new(sample_rate: u32, channels: u16) -> Self {
+        Self {
+            sample_rate,
+            channels,
+        }
+    }
+
+    /// 单声道格式。
+    pub fn mono(sample_rate: u32) -> Self {
+        Self::new(sample_rate, 1)
+    }
+
+    /// 每个采样帧（所有声道各一个采样）的字节数。
+    pub fn frame_size(&self) -> usize {
+        usize::from(self.channels.max(1)) * 2
+    }
+
+    /// 指定时长（毫秒）对应的字节数，按采样帧对齐。
+    pub fn bytes_for_millis(&self, millis: u64) -> usize {
+        let frames = (u64::from(self.sample_rate) * millis / 1000).max(1) as usize;
+        frames * self.frame_size()
+    }
+
+    /// 是否已经是识别接口要求的格式。
+    pub fn is_target(&self) -> bool {
+        *self == Self::TARGET
+    }
+
+    fn validate(&self) -> SDKResult<()> {
+        if self.sample_rate == 0 {
+            return Err(validation_error("sample_rate", "采样率不能为 0"));
+        }
+        if self.channels == 0 {
+            return Err(validation_error("channels", "声道数不能为 0"));
+        }
+        Ok(())
+    }
+}
+
+/// WAV 文件信息。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WavInfo {
+    /// 音频格式。
+    pub format: PcmFormat,
+    /// 采样位深。
+    pub bits_per_sample: u16,
+    /// `data` 块在原始字节中的范围。
+    pub data: Range<usize>,
+}
+
+/// 解析 WAV（RIFF/WAVE）文件头。
+///
+/// 仅支持未压缩的 16bit PCM；会跳过 `LIST` 等与音频无关的块。
+pub fn parse_wav_header(bytes: &[u8]) -> SDKResult<WavInfo> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(validation_error("wav", "不是有效的 RIFF/WAVE 文件"));
+    }
+
+    let mut offset = 12;
+    let mut format: Option<(PcmFormat, u16)> = None;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes([
+            bytes[offset + 4],
+            bytes[offset + 5],
+            bytes[offset + 6],
+            bytes[offset + 7],
+        ]) as usize;
+        let body = offset + 8;
+
+        match id {
+            b"fmt " => {
+                if size < 16 || body + 16 > bytes.len() {
+                    return Err(validation_error("wav", "fmt 块长度不足"));
+                }
+                let audio_format = u16::from_le_bytes([bytes[body], bytes[body + 1]]);
+                let channels = u16::from_le_bytes([bytes[body + 2], bytes[body + 3]]);
+                let sample_rate = u32::from_le_bytes([
+                    bytes[body + 4],
+                    bytes[body + 5],
+                    bytes[body + 6],
+                    bytes[body + 7],
+                ]);
+                let bits_per_sample = u16::from_le_bytes([bytes[body + 14], bytes[body + 15]]);
+                // 1: PCM；0xFFFE: WAVE_FORMAT_EXTENSIBLE（常见于多声道 PCM）
+                if audio_format != 1 && audio_format != 0xFFFE {
+                    return Err(validation_error("wav", "仅支持未压缩的 PCM 编码"));
+                }
+                if bits_per_sample != 16 {
+                    return Err(validation_error("wav", "仅支持 16bit 采样"));
+                }
+                let pcm = PcmFormat::new(sample_rate, channels);
+                pcm.validate()?;
+                format = Some((pcm, bits_per_sample));
+            }
+            b"data" => {
+                let (format, bits_per_sample) =
+                    format.ok_or_else(|| validation_error("wav", "data 块出现在 fmt 块之前"))?;
+                // 部分录音设备在流式写入时会把 data 长度写成 0 或超长，按实际长度截断。
+                let end = if size == 0 {
+                    bytes.len()
+                } else {
+                    (body + size).min(bytes.len())
+                };
+                return Ok(WavInfo {
+                    format,
+                    bits_per_sample,
+                    data: body..end,
+                });
+            }
+            _ => {}
+        }
+
+        // RIFF 块按 2 字节对齐
+        offset = body + size + (size & 1);
+    }
+
+    Err(validation_error("wav", "未找到 data 块"))
+}
+
+/// 把 16bit 小端 PCM 字节转换为采样值，末尾不足一个采样的字节会被忽略。
+pub fn bytes_to_samples(bytes: &[u8]) -> Vec<i16> {
+    bytes
+        .chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+        .collect()
+}
+
+/// 把采样值转换为 16bit 小端 PCM 字节。
+pub fn samples_to_bytes(samples: &[i16]) -> Vec<u8> {
+    samples.iter().flat_map(|s| s.to_le_bytes()).collect()
+}
+
+/// 多声道交错采样下混为单声道（取各声道平均值）。
+pub fn downmix_to_mono(samples: &[i16], channels: u16) -> Vec<i16> {
+    let channels = usize::from(channels.max(1));
+    if channels == 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks_exact(channels)
+        .map(|frame| {
+            let sum: i32 = frame.iter().map(|&s| i32::from(s)).sum();
+            (sum / channels as i32) as i16
+        })
+        .collect()
+}
+
+/// 单声道采样率转换。
+///
+/// 整数倍降采样（如 48kHz → 16kHz）使用分组平均，起到简单的低通作用；
+/// 其余情况（如 8kHz → 16kHz）使用线性插值。
+pub fn resample(samples: &[i16], from_rate: u32, to_rate: u32) -> Vec<i16> {
+    if from_rate == to_rate || samples.is_empty() || from_rate == 0 || to_rate == 0 {
+        return samples.to_vec();
+    }
+
+    if from_rate > to_rate && from_rate.is_multiple_of(to_rate) {
+        let factor = (from_rate / to_rate) as usize;
+        return samples
+            .chunks(factor)
+            .map(|group| {
+                let sum: i32 = group.iter().map(|&s| i32::from(s)).sum();
+                (sum / group.len() as i32) as i16
+            })
+            .collect();
+    }
+
+    let out_len = (samples.len() as u64 * u64::from(to_rate) / u64::from(from_rate)) as usize;
+    let step = f64::from(from_rate) / f64::from(to_rate);
+    (0..out_len)
+        .map(|i| {
+            let position = i as f64 * step;
+            let index = position.floor() as usize;
+            let fraction = position - index as f64;
+            let current = f64::from(samples[index.min(samples.len() - 1)]);
+            let next = f64::from(samples[(index + 1).min(samples.len() - 1)]);
+            (current + (next - current) * fraction).round() as i16
+        })
+        .collect()
+}
+
+/// 把任意 16bit PCM 转换为识别接口要求的 16kHz 单声道 PCM 字节。
+pub fn convert_to_target(bytes: &[u8], format: PcmFormat) -> SDKResult<Vec<u8>> {
+    format.validate()?;
+    if format.is_target() {
+        return Ok(bytes.to_vec());
+    }
+    let mono = downmix_to_mono(&bytes_to_samples(bytes), format.channels);
+    Ok(samples_to_bytes(&resample(
+        &mono,
+        format.sample_rate,
+        TARGET_SAMPLE_RATE,
+    )))
+}
+
+/// 从 WAV 文件中提取音频并转换为 16kHz 单声道 PCM 字节。
+pub fn wav_to_target_pcm(bytes: &[u8]) -> SDKResult<Vec<u8>> {
+    let info = parse_wav_header(bytes)?;
+    convert_to_target(&bytes[info.data], info.format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav(sample_rate: u32, channels: u16, samples: &[i16]) -> Vec<u8> {
+        let data = samples_to_bytes(samples);
+        let mut out = Vec::new();
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        out.extend_from_slice(b"WAVE");
+        out.extend_from_slice(b"fmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&1u16.to_le_bytes());
+        out.extend_from_slice(&channels.to_le_bytes());
+        out.extend_from_slice(&sample_rate.to_le_bytes());
+        out.extend_from_slice(&(sample_rate * u32::from(channels) * 2).to_le_bytes());
+        out.extend_from_slice(&(channels * 2).to_le_bytes());
+        out.extend_from_slice(&16u16.to_le_bytes());
+        out.extend_from_slice(b"LIST");
+        out.extend_from_slice(&3u32.to_le_bytes());
+        out.extend_from_slice(&[0, 0, 0, 0]);
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(&data);
+        out
+    }
+
+    #[test]
+    fn test_parse_wav_header_skips_unknown_chunks() {
+        let bytes = wav(48_000, 2, &[1, 2, 3, 4]);
+        let info = parse_wav_header(&bytes).expect("解析失败");
+        assert_eq!(info.format, PcmFormat::new(48_000, 2));
+        assert_eq!(info.bits_per_sample, 16);
+        assert_eq!(bytes_to_samples(&bytes[info.data]), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_parse_wav_header_rejects_invalid_input() {
+        assert!(parse_wav_header(b"not a wav file").is_err());
+    }
+
+    #[test]
+    fn test_downmix_and_resample_48k_stereo() {
+        let samples: Vec<i16> = (0..12).map(|i| i * 10).collect();
+        let mono = downmix_to_mono(&samples, 2);
+        assert_eq!(mono, vec![5, 25, 45, 65, 85, 105]);
+        assert_eq!(resample(&mono, 48_000, 16_000), vec![25, 85]);
+    }
+
+    #[test]
+    fn test_resample_8k_upsamples_with_interpolation() {
+        let out = resample(&[0, 100, 200], 8_000, 16_000);
+        assert_eq!(out, vec![0, 50, 100, 150, 200, 200]);
+    }
+
+    #[test]
+    fn test_wav_to_target_pcm() {
+        let bytes = wav(8_000, 1, &[0, 100]);
+        let pcm = wav_to_target_pcm(&bytes).expect("转换失败");
+        assert_eq!(bytes_to_samples(&pcm), vec![0, 50, 100, 100]);
+    }
+
+    #[test]
+    fn test_bytes_for_millis_aligns_to_frames() {
+        assert_eq!(PcmFormat::TARGET.bytes_for_millis(100), 3_200);
+        assert_eq!(PcmFormat::new(48_000, 2).bytes_for_millis(100), 19_200);
+    }
+}
@@ -0,0 +1,373 @@
+//! 流式语音识别会话 helper
+//!
+//! 说明：
+//! - `stream_recognize` 接口本身是"一次请求一个分片"，同一段音频需要调用方自行维护
+//!   `stream_id`、递增的 `sequence_id` 与首/中/末分片动作。
+//! - [`SpeechStream`] 把 `AsyncRead` 或帧流切分为有界分片，统一转换为 16kHz 单声道 PCM、
+//!   Base64 编码后按顺序发送，并以 `Stream` 的形式逐片返回识别文本。
+
+use std::{pin::Pin, time::Duration};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use futures::{Stream, StreamExt};
+use openlark_core::{
+    SDKResult, config::Config, error::validation_error, req_option::RequestOption,
+};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::ai::speech_to_text::v1::speech::stream_recognize::{
+    StreamAction, StreamRecognizeBody, StreamRecognizeConfig, StreamRecognizeRequest, StreamSpeech,
+};
+use crate::common::pcm::{PcmFormat, convert_to_target, parse_wav_header};
+
+/// 默认分片时长（接口建议 100-200ms）。
+pub const DEFAULT_CHUNK_DURATION: Duration = Duration::from_millis(200);
+/// 允许的最小分片时长。
+pub const MIN_CHUNK_DURATION: Duration = Duration::from_millis(20);
+/// 允许的最大分片时长。
+pub const MAX_CHUNK_DURATION: Duration = Duration::from_millis(1000);
+
+const DEFAULT_ENGINE_TYPE: &str = "16k_auto";
+const PCM_FORMAT: &str = "pcm";
+
+/// 识别文本片段。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    /// 数据流标识。
+    pub stream_id: String,
+    /// 对应的分片序号。
+    pub sequence_id: i32,
+    /// 截至该分片的识别文本。
+    pub text: String,
+    /// 是否为最终结果（结束分片的返回）。
+    pub is_final: bool,
+}
+
+/// 流式语音识别会话。
+///
+/// ```rust,ignore
+/// use futures::StreamExt;
+/// use openlark_ai::SpeechStream;
+///
+/// let file = tokio::fs::File::open("speech.pcm").await?;
+/// let mut transcripts = SpeechStream::new(config).recognize_reader(file);
+/// while let Some(transcript) = transcripts.next().await {
+///     let transcript = transcript?;
+///     println!("{} (final: {})", transcript.text, transcript.is_final);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpeechStream {
+    config: Config,
+    option: RequestOption,
+    input_format: PcmFormat,
+    chunk_duration: Duration,
+    engine_type: String,
+    stream_id: Option<String>,
+}
+
+impl SpeechStream {
+    /// 创建识别会话，默认输入为 16kHz 单声道 PCM。
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            option: RequestOption::default(),
+            input_format: PcmFormat::TARGET,
+            chunk_duration: DEFAULT_CHUNK_DURATION,
+            engine_type: DEFAULT_ENGINE_TYPE.to_string(),
+            stream_id: None,
+        }
+    }
+
+    /// 设置输入音频格式，非 16kHz 单声道的输入会在发送前自动转换。
+    pub fn input_format(mut self, format: PcmFormat) -> Self {
+        self.input_format = format;
+        self
+    }
+
+    /// 设置分片时长，超出 20ms-1000ms 的取值会被截断到边界。
+    pub fn chunk_duration(mut self, duration: Duration) -> Self {
+        self.chunk_duration = duration.clamp(MIN_CHUNK_DURATION, MAX_CHUNK_DURATION);
+        self
+    }
+
+    /// 设置引擎类型，默认 `16k_auto`。
+    pub fn engine_type(mut self, engine_type: impl Into<String>) -> Self {
+        self.engine_type = engine_type.into();
+        self
+    }
+
+    /// 指定数据流标识（16 位字母、数字或下划线），默认自动生成。
+    pub fn stream_id(mut self, stream_id: impl Into<String>) -> Self {
+        self.stream_id = Some(stream_id.into());
+        self
+    }
+
+    /// 设置底层请求使用的请求选项。
+    pub fn request_option(mut self, option: RequestOption) -> Self {
+        self.option = option;
+        self
+    }
+
+    /// 识别 `AsyncRead` 中的 PCM 音频。
+    pub fn recognize_reader<R>(self, reader: R) -> impl Stream<Item = SDKResult<Transcript>> + Send
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.run(AudioSource::Reader(Box::pin(reader)))
+    }
+
+    /// 识别 PCM 帧流，帧大小任意，内部会重新切分为有界分片。
+    pub fn recognize_frames<S>(self, frames: S) -> impl Stream<Item = SDKResult<Transcript>> + Send
+    where
+        S: Stream<Item = Vec<u8>> + Send + 'static,
+    {
+        self.run(AudioSource::Frames {
+            frames: Box::pin(frames),
+            buffer: Vec::new(),
+        })
+    }
+
+    /// 识别完整的 WAV 文件内容，输入格式取自 WAV 头。
+    pub fn recognize_wav(
+        self,
+        wav: Vec<u8>,
+    ) -> SDKResult<impl Stream<Item = SDKResult<Transcript>> + Send> {
+        let info = parse_wav_header(&wav)?;
+        let data = wav[info.data].to_vec();
+        Ok(self
+            .input_format(info.format)
+            .recognize_reader(std::io::Cursor::new(data)))
+    }
+
+    fn run(self, source: AudioSource) -> impl Stream<Item = SDKResult<Transcript>> + Send {
+        let chunk_bytes = self
+            .input_format
+            .bytes_for_millis(self.chunk_duration.as_millis() as u64);
+        let stream_id = self.stream_id.clone().unwrap_or_else(generate_stream_id);
+        let state = SessionState {
+            session: self,
+            source,
+            stream_id,
+            chunk_bytes,
+            sequence_id: 0,
+            pending: None,
+            done: false,
+        };
+
+        futures::stream::unfold(state, |mut state| async move {
+            if state.done {
+                return None;
+            }
+            let result = state.step().await;
+            match result {
+                Ok(Some(transcript)) => Some((Ok(transcript), state)),
+                Ok(None) => None,
+                Err(err) => {
+                    state.done = true;
+                    Some((Err(err), state))
+                }
+            }
+        })
+    }
+}
+
+struct SessionState {
+    session: SpeechStream,
+    source: AudioSource,
+    stream_id: String,
+    chunk_bytes: usize,
+    sequence_id: i32,
+    pending: Option<Vec<u8>>,
+    done: bool,
+}
+
+impl SessionState {
+    async fn step(&mut self) -> SDKResult<Option<Transcript>> {
+        if self.sequence_id == 0 {
+            validate_stream_id(&self.stream_id)?;
+        }
+
+        let chunk = match self.pending.take() {
+            Some(chunk) => chunk,
+            None => {
+                let first = self.source.next_chunk(self.chunk_bytes).await?;
+                if first.is_empty() {
+                    // 没有任何音频数据，不发起会话。
+                    return Ok(None);
+                }
+                first
+            }
+        };
+
+        // 预读下一片以判断当前分片是否为末片。
+        let next = self.source.next_chunk(self.chunk_bytes).await?;
+        let is_last = next.is_empty();
+        let action = chunk_action(self.sequence_id, is_last);
+        if action == StreamAction::Start && is_last {
+            // 音频只有一片时先以 Start 发送，再补一个空的 End 分片取最终结果。
+            self.pending = Some(Vec::new());
+        } else {
+            self.pending = Some(next);
+        }
+
+        let speech = convert_to_target(&chunk, self.session.input_format)?;
+        let body = StreamRecognizeBody {
+            speech: StreamSpeech {
+                speech: STANDARD.encode(speech),
+            },
+            config: StreamRecognizeConfig {
+                stream_id: self.stream_id.clone(),
+                sequence_id: self.sequence_id,
+                action,
+                format: PCM_FORMAT.to_string(),
+                engine_type: self.session.engine_type.clone(),
+            },
+        };
+
+        let response = StreamRecognizeRequest::new(self.session.config.clone())
+            .execute_with_options(body, self.session.option.clone())
+            .await?;
+
+        let transcript = Transcript {
+            stream_id: self.stream_id.clone(),
+            sequence_id: self.sequence_id,
+            text: response.recognition_text,
+            is_final: action == StreamAction::End,
+        };
+        self.sequence_id += 1;
+        self.done = transcript.is_final;
+        Ok(Some(transcript))
+    }
+}
+
+enum AudioSource {
+    Reader(Pin<Box<dyn AsyncRead + Send>>),
+    Frames {
+        frames: Pin<Box<dyn Stream<Item = Vec<u8>> + Send>>,
+        buffer: Vec<u8>,
+    },
+}
+
+impl AudioSource {
+    /// 读取至多 `size` 字节，返回空数组表示音频已结束。
+    async fn next_chunk(&mut self, size: usize) -> SDKResult<Vec<u8>> {
+        match self {
+            AudioSource::Reader(reader) => {
+                let mut chunk = vec![0u8; size];
+                let mut filled = 0;
+                while filled < size {
+                    let read = reader
+                        .read(&mut chunk[filled..])
+                        .await
+                        .map_err(|e| validation_error("audio", e.to_string().as_str()))?;
+                    if read == 0 {
+                        break;
+                    }
+                    filled += read;
+                }
+                chunk.truncate(filled);
+                Ok(chunk)
+            }
+            AudioSource::Frames { frames, buffer } => {
+                while buffer.len() < size {
+                    match frames.next().await {
+                        Some(frame) => buffer.extend_from_slice(&frame),
+                        None => break,
+                    }
+                }
+                let take = buffer.len().min(size);
+                Ok(buffer.drain(..take).collect())
+            }
+        }
+    }
+}
+
+fn chunk_action(sequence_id: i32, is_last: bool) -> StreamAction {
+    match (sequence_id, is_last) {
+        (0, _) => StreamAction::Start,
+        (_, true) => StreamAction::End,
+        (_, false) => StreamAction::Continue,
+    }
+}
+
+fn generate_stream_id() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..16].to_string()
+}
+
+fn validate_stream_id(stream_id: &str) -> SDKResult<()> {
+    if stream_id.len() != 16
+        || !stream_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(validation_error(
+            "stream_id",
+            "stream_id 必须是 16 位字母、数字或下划线",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_action_sequence() {
+        assert_eq!(chunk_action(0, false), StreamAction::Start);
+        assert_eq!(chunk_action(0, true), StreamAction::Start);
+        assert_eq!(chunk_action(3, false), StreamAction::Continue);
+        assert_eq!(chunk_action(3, true), StreamAction::End);
+    }
+
+    #[test]
+    fn test_generated_stream_id_is_valid() {
+        let stream_id = generate_stream_id();
+        assert!(validate_stream_id(&stream_id).is_ok());
+        assert!(validate_stream_id("too-short").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reader_source_chunks_until_eof() {
+        let mut source = AudioSource::Reader(Box::pin(std::io::Cursor::new(vec![1u8; 10])));
+        assert_eq!(source.next_chunk(4).await.expect("读取失败").len(), 4);
+        assert_eq!(source.next_chunk(4).await.expect("读取失败").len(), 4);
+        assert_eq!(source.next_chunk(4).await.expect("读取失败").len(), 2);
+        assert!(source.next_chunk(4).await.expect("读取失败").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_frame_source_rechunks_frames() {
+        let frames = futures::stream::iter(vec![vec![1u8; 3], vec![2u8; 3], vec![3u8; 1]]);
+        let mut source = AudioSource::Frames {
+            frames: Box::pin(frames),
+            buffer: Vec::new(),
+        };
+        assert_eq!(
+            source.next_chunk(4).await.expect("读取失败"),
+            vec![1, 1, 1, 2]
+        );
+        assert_eq!(source.next_chunk(4).await.expect("读取失败"), vec![2, 2, 3]);
+        assert!(source.next_chunk(4).await.expect("读取失败").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_empty_input_yields_no_transcripts() {
+        let config = Config::builder()
+            .app_id("test_app")
+            .app_secret("test_secret")
+            .build();
+        let transcripts: Vec<_> = SpeechStream::new(config)
+            .recognize_reader(std::io::Cursor::new(Vec::new()))
+            .collect()
+            .await;
+        assert!(transcripts.is_empty());
+    }
+
+    #[test]
+    fn test_chunk_duration_is_bounded() {
+        let session = SpeechStream::new(Config::default()).chunk_duration(Duration::from_secs(5));
+        assert_eq!(session.chunk_duration, MAX_CHUNK_DURATION);
+    }
+}
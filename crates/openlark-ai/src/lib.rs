@@ -71,6 +71,16 @@ pub use endpoints::*;
 // 重新导出 Document AI 链式调用入口
 pub use common::chain::DocumentAiClient;
 
+// 重新导出文档统一识别与批量识别
+pub use common::{
+    BankCard, BatchItem, BatchReport, BusinessLicense, CsvSummary, DocumentBatch, DocumentEntity,
+    DocumentInput, DocumentKind, DocumentRecognizer, IdCard, RecognizedDocument, RecognizedField,
+    TaxiInvoice, TrainInvoice, VatInvoice,
+};
+
+// 重新导出流式语音识别会话
+pub use common::{PcmFormat, SpeechStream, Transcript};
+
 /// Re-exports from openlark-core for convenience.
 pub mod prelude {
     pub use openlark_core::{SDKResult, config::Config};
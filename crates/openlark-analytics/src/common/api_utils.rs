@@ -0,0 +1,38 @@
+//! API 通用工具函数
+
+use openlark_core::{SDKResult, api::Response, error};
+
+/// 写接口响应处理：`code != 0` 时返回错误，成功但未返回 `data` 时使用默认值。
+pub fn write_response<T: Default>(response: Response<T>, context: &str) -> SDKResult<T> {
+    if response.is_success() {
+        Ok(response.data.unwrap_or_default())
+    } else {
+        Err(error::validation_error(
+            format!("{context}失败: {}", response.code()),
+            response.raw_response.msg.to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openlark_core::api::RawResponse;
+
+    #[test]
+    fn test_write_response_defaults_missing_data() {
+        let response: Response<Vec<String>> = Response::new(None, RawResponse::success());
+        assert_eq!(
+            write_response(response, "测试").unwrap(),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_write_response_error_code() {
+        let mut raw = RawResponse::success();
+        raw.code = 1_230_001;
+        let response: Response<Vec<String>> = Response::new(None, raw);
+        assert!(write_response(response, "测试").is_err());
+    }
+}
@@ -1,5 +1,12 @@
 //! 通用数据模型
 
+pub mod api_utils;
+
+#[cfg(feature = "search")]
+pub mod search_connector;
+#[cfg(feature = "search")]
+pub mod search_models;
+
 use serde::{Deserialize, Serialize};
 
 /// 搜索结果
@@ -7,18 +7,26 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::common::{
+    api_utils::write_response,
+    search_models::{DataSource, DataSourceBody, DataSourceState},
+};
+
 #[derive(Debug, Clone)]
 pub struct CreateDataSourceRequest {
     config: Arc<Config>,
+    body: DataSourceBody,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateDataSourceResponse {
-    pub data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub data_source: Option<DataSource>,
 }
 
 impl ApiResponseTrait for CreateDataSourceResponse {
@@ -28,8 +36,44 @@ impl ApiResponseTrait for CreateDataSourceResponse {
 }
 
 impl CreateDataSourceRequest {
-    pub fn new(config: Arc<Config>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<Config>, name: impl Into<String>) -> Self {
+        Self {
+            config,
+            body: DataSourceBody {
+                name: Some(name.into()),
+                ..DataSourceBody::default()
+            },
+        }
+    }
+
+    /// 设置数据源状态。
+    pub fn state(mut self, state: DataSourceState) -> Self {
+        self.body.state = Some(state);
+        self
+    }
+
+    /// 设置数据源描述。
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.body.description = Some(description.into());
+        self
+    }
+
+    /// 设置数据源图标。
+    pub fn icon_url(mut self, icon_url: impl Into<String>) -> Self {
+        self.body.icon_url = Some(icon_url.into());
+        self
+    }
+
+    /// 关联数据范式。
+    pub fn schema_id(mut self, schema_id: impl Into<String>) -> Self {
+        self.body.schema_id = Some(schema_id.into());
+        self
+    }
+
+    /// 设置是否启用问答。
+    pub fn enable_answer(mut self, enable_answer: bool) -> Self {
+        self.body.enable_answer = Some(enable_answer);
+        self
     }
 
     pub async fn execute(self) -> SDKResult<CreateDataSourceResponse> {
@@ -40,32 +84,36 @@ impl CreateDataSourceRequest {
         self,
         option: RequestOption,
     ) -> SDKResult<CreateDataSourceResponse> {
+        let name = self.body.name.clone().unwrap_or_default();
+        validate_required!(name, "name 不能为空");
+
         let path = "/open-apis/search/v2/data_sources".to_string();
-        let req: ApiRequest<CreateDataSourceResponse> = ApiRequest::post(&path);
+        let req: ApiRequest<CreateDataSourceResponse> =
+            ApiRequest::post(&path).json_body(&self.body);
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("创建数据源", "响应数据为空"))
+        write_response(resp, "创建数据源")
     }
 }
 
 #[cfg(test)]
-#[allow(unused_imports)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_response_deserialization() {
+        let json = r#"{"data_source": {"id": "ds_1", "name": "内部 Wiki", "state": 0, "schema_id": "wiki"}}"#;
+        let resp: CreateDataSourceResponse = serde_json::from_str(json).expect("JSON 反序列化失败");
+        let data_source = resp.data_source.expect("data_source 为空");
+        assert_eq!(data_source.id, "ds_1");
+        assert_eq!(data_source.state, Some(DataSourceState::Online));
     }
 
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    #[tokio::test]
+    async fn test_empty_name_rejected() {
+        let result = CreateDataSourceRequest::new(Arc::new(Config::default()), "")
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
@@ -1,5 +1,5 @@
 //! 为指定数据项创建索引
-//! docPath: https://open.feishu.cn/document/server-docs/search-v2/open-search/data_source/create
+//! docPath: https://open.feishu.cn/document/server-docs/search-v2/open-search/data_source-item/create
 
 use openlark_core::{
     SDKResult,
@@ -7,19 +7,23 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::common::{api_utils::write_response, search_models::Item};
+
+/// 为指定数据项创建索引。数据项 ID 已存在时会覆盖原有索引。
 #[derive(Debug, Clone)]
 pub struct CreateDataSourceItemRequest {
     config: Arc<Config>,
+    data_source_id: String,
+    item: Item,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreateDataSourceItemResponse {
-    pub data: Option<serde_json::Value>,
-}
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateDataSourceItemResponse {}
 
 impl ApiResponseTrait for CreateDataSourceItemResponse {
     fn data_format() -> ResponseFormat {
@@ -28,8 +32,12 @@ impl ApiResponseTrait for CreateDataSourceItemResponse {
 }
 
 impl CreateDataSourceItemRequest {
-    pub fn new(config: Arc<Config>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<Config>, data_source_id: impl Into<String>, item: Item) -> Self {
+        Self {
+            config,
+            data_source_id: data_source_id.into(),
+            item,
+        }
     }
 
     pub async fn execute(self) -> SDKResult<CreateDataSourceItemResponse> {
@@ -40,33 +48,39 @@ impl CreateDataSourceItemRequest {
         self,
         option: RequestOption,
     ) -> SDKResult<CreateDataSourceItemResponse> {
-        let path = "/open-apis/search/v2/data_sources/{}/items".to_string();
-        let req: ApiRequest<CreateDataSourceItemResponse> = ApiRequest::post(&path);
+        validate_required!(self.data_source_id, "data_source_id 不能为空");
+        self.item.validate()?;
+
+        let path = format!(
+            "/open-apis/search/v2/data_sources/{}/items",
+            self.data_source_id
+        );
+        let req: ApiRequest<CreateDataSourceItemResponse> =
+            ApiRequest::post(&path).json_body(&self.item);
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data.ok_or_else(|| {
-            openlark_core::error::validation_error("为指定数据项创建索引", "响应数据为空")
-        })
+        write_response(resp, "为指定数据项创建索引")
     }
 }
 
 #[cfg(test)]
-#[allow(unused_imports)]
 mod tests {
     use super::*;
+    use crate::common::search_models::AclPrincipal;
 
-    #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
-    }
+    #[tokio::test]
+    async fn test_invalid_item_rejected_before_request() {
+        let item = Item::new("T-1", "标题", "https://example.com/T-1");
+        let result = CreateDataSourceItemRequest::new(Arc::new(Config::default()), "ds", item)
+            .execute()
+            .await;
+        assert!(result.is_err());
 
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+        let item =
+            Item::new("T-1", "标题", "https://example.com/T-1").allow(AclPrincipal::Everyone);
+        let result = CreateDataSourceItemRequest::new(Arc::new(Config::default()), "", item)
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
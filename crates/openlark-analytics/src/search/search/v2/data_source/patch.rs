@@ -7,18 +7,27 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::common::{
+    api_utils::write_response,
+    search_models::{DataSource, DataSourceBody, DataSourceState},
+};
+
 #[derive(Debug, Clone)]
 pub struct PatchDataSourceRequest {
     config: Arc<Config>,
+    data_source_id: String,
+    body: DataSourceBody,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PatchDataSourceResponse {
-    pub data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub data_source: Option<DataSource>,
 }
 
 impl ApiResponseTrait for PatchDataSourceResponse {
@@ -28,8 +37,42 @@ impl ApiResponseTrait for PatchDataSourceResponse {
 }
 
 impl PatchDataSourceRequest {
-    pub fn new(config: Arc<Config>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<Config>, data_source_id: impl Into<String>) -> Self {
+        Self {
+            config,
+            data_source_id: data_source_id.into(),
+            body: DataSourceBody::default(),
+        }
+    }
+
+    /// 设置数据源名称。
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+
+    /// 设置数据源状态。
+    pub fn state(mut self, state: DataSourceState) -> Self {
+        self.body.state = Some(state);
+        self
+    }
+
+    /// 设置数据源描述。
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.body.description = Some(description.into());
+        self
+    }
+
+    /// 设置数据源图标。
+    pub fn icon_url(mut self, icon_url: impl Into<String>) -> Self {
+        self.body.icon_url = Some(icon_url.into());
+        self
+    }
+
+    /// 设置是否启用问答。
+    pub fn enable_answer(mut self, enable_answer: bool) -> Self {
+        self.body.enable_answer = Some(enable_answer);
+        self
     }
 
     pub async fn execute(self) -> SDKResult<PatchDataSourceResponse> {
@@ -40,32 +83,26 @@ impl PatchDataSourceRequest {
         self,
         option: RequestOption,
     ) -> SDKResult<PatchDataSourceResponse> {
-        let path = "/open-apis/search/v2/data_sources/{}".to_string();
-        let req: ApiRequest<PatchDataSourceResponse> = ApiRequest::patch(&path);
+        validate_required!(self.data_source_id, "data_source_id 不能为空");
+
+        let path = format!("/open-apis/search/v2/data_sources/{}", self.data_source_id);
+        let req: ApiRequest<PatchDataSourceResponse> =
+            ApiRequest::patch(&path).json_body(&self.body);
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("修改数据源", "响应数据为空"))
+        write_response(resp, "修改数据源")
     }
 }
 
 #[cfg(test)]
-#[allow(unused_imports)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
-    }
-
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    fn test_body_serialization_skips_unset_fields() {
+        let request = PatchDataSourceRequest::new(Arc::new(Config::default()), "ds_1")
+            .state(DataSourceState::Offline);
+        let value = serde_json::to_value(&request.body).expect("序列化失败");
+        assert_eq!(value, serde_json::json!({"state": 1}));
     }
 }
@@ -11,14 +11,19 @@ use openlark_core::{
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::common::{api_utils::write_response, search_models::Schema};
+
 #[derive(Debug, Clone)]
 pub struct CreateSchemaRequest {
     config: Arc<Config>,
+    schema: Schema,
+    validate_only: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateSchemaResponse {
-    pub data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub schema: Option<Schema>,
 }
 
 impl ApiResponseTrait for CreateSchemaResponse {
@@ -28,8 +33,18 @@ impl ApiResponseTrait for CreateSchemaResponse {
 }
 
 impl CreateSchemaRequest {
-    pub fn new(config: Arc<Config>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<Config>, schema: Schema) -> Self {
+        Self {
+            config,
+            schema,
+            validate_only: None,
+        }
+    }
+
+    /// 仅校验数据范式而不真正创建。
+    pub fn validate_only(mut self, validate_only: bool) -> Self {
+        self.validate_only = Some(validate_only);
+        self
     }
 
     pub async fn execute(self) -> SDKResult<CreateSchemaResponse> {
@@ -40,32 +55,38 @@ impl CreateSchemaRequest {
         self,
         option: RequestOption,
     ) -> SDKResult<CreateSchemaResponse> {
+        self.schema.validate()?;
+
         let path = "/open-apis/search/v2/schemas".to_string();
-        let req: ApiRequest<CreateSchemaResponse> = ApiRequest::post(&path);
+        let req: ApiRequest<CreateSchemaResponse> = ApiRequest::post(&path)
+            .query_opt("validate_only", self.validate_only.map(|v| v.to_string()))
+            .json_body(&self.schema);
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("创建数据范式", "响应数据为空"))
+        write_response(resp, "创建数据范式")
     }
 }
 
 #[cfg(test)]
-#[allow(unused_imports)]
 mod tests {
     use super::*;
+    use crate::common::search_models::SchemaProperty;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_response_deserialization() {
+        let json = r#"{"schema": {"schema_id": "wiki", "properties": [{"name": "summary", "type": "text", "is_searchable": true}]}}"#;
+        let resp: CreateSchemaResponse = serde_json::from_str(json).expect("JSON 反序列化失败");
+        let schema = resp.schema.expect("schema 为空");
+        assert_eq!(schema.schema_id, "wiki");
+        assert!(schema.properties[0].is_searchable);
     }
 
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    #[tokio::test]
+    async fn test_invalid_schema_rejected_before_request() {
+        let schema = Schema::new("").property(SchemaProperty::text("summary"));
+        let result = CreateSchemaRequest::new(Arc::new(Config::default()), schema)
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
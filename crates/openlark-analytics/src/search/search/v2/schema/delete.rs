@@ -7,19 +7,21 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::common::api_utils::write_response;
+
 #[derive(Debug, Clone)]
 pub struct DeleteSchemaRequest {
     config: Arc<Config>,
+    schema_id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeleteSchemaResponse {
-    pub data: Option<serde_json::Value>,
-}
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeleteSchemaResponse {}
 
 impl ApiResponseTrait for DeleteSchemaResponse {
     fn data_format() -> ResponseFormat {
@@ -28,8 +30,11 @@ impl ApiResponseTrait for DeleteSchemaResponse {
 }
 
 impl DeleteSchemaRequest {
-    pub fn new(config: Arc<Config>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<Config>, schema_id: impl Into<String>) -> Self {
+        Self {
+            config,
+            schema_id: schema_id.into(),
+        }
     }
 
     pub async fn execute(self) -> SDKResult<DeleteSchemaResponse> {
@@ -40,32 +45,25 @@ impl DeleteSchemaRequest {
         self,
         option: RequestOption,
     ) -> SDKResult<DeleteSchemaResponse> {
-        let path = "/open-apis/search/v2/schemas/{}".to_string();
+        validate_required!(self.schema_id, "schema_id 不能为空");
+
+        let path = format!("/open-apis/search/v2/schemas/{}", self.schema_id);
         let req: ApiRequest<DeleteSchemaResponse> = ApiRequest::delete(&path);
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("删除数据范式", "响应数据为空"))
+        write_response(resp, "删除数据范式")
     }
 }
 
 #[cfg(test)]
-#[allow(unused_imports)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
-    }
-
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    #[tokio::test]
+    async fn test_empty_id_rejected() {
+        let result = DeleteSchemaRequest::new(Arc::new(Config::default()), "")
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
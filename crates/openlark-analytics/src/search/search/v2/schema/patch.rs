@@ -7,18 +7,62 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::common::{
+    api_utils::write_response,
+    search_models::{Schema, SchemaDisplay},
+};
+
 #[derive(Debug, Clone)]
 pub struct PatchSchemaRequest {
     config: Arc<Config>,
+    schema_id: String,
+    body: PatchSchemaBody,
 }
 
+/// 修改数据范式请求体。接口仅支持修改展示选项与属性描述。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PatchSchemaBody {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<SchemaDisplay>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<PatchSchemaProperty>,
+}
+
+/// 可修改的属性字段。
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchSchemaProperty {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desc: Option<String>,
+}
+
+impl PatchSchemaBody {
+    /// 从完整数据范式中提取可修改的部分。
+    pub fn from_schema(schema: &Schema) -> Self {
+        Self {
+            display: schema.display.clone(),
+            properties: schema
+                .properties
+                .iter()
+                .filter(|p| p.desc.is_some())
+                .map(|p| PatchSchemaProperty {
+                    name: p.name.clone(),
+                    desc: p.desc.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PatchSchemaResponse {
-    pub data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub schema: Option<Schema>,
 }
 
 impl ApiResponseTrait for PatchSchemaResponse {
@@ -28,8 +72,33 @@ impl ApiResponseTrait for PatchSchemaResponse {
 }
 
 impl PatchSchemaRequest {
-    pub fn new(config: Arc<Config>) -> Self {
-        Self { config }
+    pub fn new(config: Arc<Config>, schema_id: impl Into<String>) -> Self {
+        Self {
+            config,
+            schema_id: schema_id.into(),
+            body: PatchSchemaBody::default(),
+        }
+    }
+
+    /// 设置展示选项。
+    pub fn display(mut self, display: SchemaDisplay) -> Self {
+        self.body.display = Some(display);
+        self
+    }
+
+    /// 修改属性描述。
+    pub fn property_desc(mut self, name: impl Into<String>, desc: impl Into<String>) -> Self {
+        self.body.properties.push(PatchSchemaProperty {
+            name: name.into(),
+            desc: Some(desc.into()),
+        });
+        self
+    }
+
+    /// 直接设置请求体。
+    pub fn body(mut self, body: PatchSchemaBody) -> Self {
+        self.body = body;
+        self
     }
 
     pub async fn execute(self) -> SDKResult<PatchSchemaResponse> {
@@ -40,32 +109,33 @@ impl PatchSchemaRequest {
         self,
         option: RequestOption,
     ) -> SDKResult<PatchSchemaResponse> {
-        let path = "/open-apis/search/v2/schemas/{}".to_string();
-        let req: ApiRequest<PatchSchemaResponse> = ApiRequest::patch(&path);
+        validate_required!(self.schema_id, "schema_id 不能为空");
+
+        let path = format!("/open-apis/search/v2/schemas/{}", self.schema_id);
+        let req: ApiRequest<PatchSchemaResponse> = ApiRequest::patch(&path).json_body(&self.body);
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("修改数据范式", "响应数据为空"))
+        write_response(resp, "修改数据范式")
     }
 }
 
 #[cfg(test)]
-#[allow(unused_imports)]
 mod tests {
     use super::*;
+    use crate::common::search_models::SchemaProperty;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
-    }
-
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    fn test_body_from_schema() {
+        let schema = Schema::new("wiki")
+            .property(SchemaProperty::text("summary").description("摘要"))
+            .property(SchemaProperty::tag("space"))
+            .display(SchemaDisplay::common_card().field("summary", "summary"));
+        let body = PatchSchemaBody::from_schema(&schema);
+        let value = serde_json::to_value(&body).expect("序列化失败");
+        assert_eq!(
+            value["properties"],
+            serde_json::json!([{"name": "summary", "desc": "摘要"}])
+        );
+        assert_eq!(value["display"]["card_key"], "search_common_card");
     }
 }
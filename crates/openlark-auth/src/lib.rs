@@ -15,6 +15,7 @@
 //!
 //! - [`services`][]: 核心认证服务
 //! - [`models`][]: 数据模型定义
+//! - `user_token`: 用户访问令牌管理（授权链接、PKCE、自动刷新，需启用 `oauth` 特性）
 //! - `auth`: 认证 API (包含 auth, authen, oauth 子模块)
 //!
 //! ## 快速开始
@@ -62,12 +63,19 @@ pub mod models;
 pub mod passport;
 pub mod services;
 pub mod token_provider;
+#[cfg(feature = "oauth")]
+pub mod user_token;
 pub mod verification;
 pub mod verification_information;
 
 // 重新导出核心类型，方便用户使用
 pub use services::{AuthService, AuthenService, OAuthService};
 pub use token_provider::AuthTokenProvider;
+#[cfg(feature = "oauth")]
+pub use user_token::{
+    AuthorizationRequest, AuthorizedUser, MemoryUserTokenStore, UserToken, UserTokenManager,
+    UserTokenStore,
+};
 
 /// 认证模块的预导入，包含最常用的类型和特征
 pub mod prelude {
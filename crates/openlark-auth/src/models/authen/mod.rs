@@ -266,6 +266,7 @@ impl PartialEq for UserCustomAttr {
 
 // 为所有响应类型实现ApiResponseTrait
 impl ApiResponseTrait for UserInfoResponse {}
+impl ApiResponseTrait for UserInfo {}
 impl ApiResponseTrait for UserAccessTokenResponse {}
 
 /// 用户令牌信息
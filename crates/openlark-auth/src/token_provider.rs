@@ -2,10 +2,14 @@
 //!
 //! `openlark-core` 通过 `TokenProvider` 抽象获取 token，而不关心具体获取/刷新/缓存策略。
 //! 这里提供一个带缓存的实现：缓存 token 并在过期前复用。
+//!
+//! - 同一缓存键的并发获取合并为一次请求（single-flight），避免 token 过期瞬间的请求洪峰
+//! - 进入提前刷新窗口后由下一个请求换取新 token，换取失败时继续使用尚未过期的旧 token
+//! - 服务端判定 token 无效时，`Transport` 通过 [`TokenProvider::invalidate`] 作废缓存
 
 use openlark_core::{
     SDKResult,
-    auth::{TokenProvider, TokenRequest},
+    auth::{TokenProvider, TokenRequest, app_ticket::apply_app_ticket},
     config::Config,
     constants::{AccessTokenType, AppType},
     error::{api_error, configuration_error},
@@ -15,8 +19,11 @@ use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{Mutex, RwLock};
+
+/// 默认提前刷新窗口：过期前 5 分钟
+const DEFAULT_REFRESH_AHEAD: Duration = Duration::from_secs(300);
 
 /// 缓存的 token 信息
 #[derive(Clone)]
@@ -25,6 +32,8 @@ struct CachedToken {
     token: String,
     /// 过期时间戳（Unix 时间戳，秒）
     expires_at: i64,
+    /// 开始提前刷新的时间戳（Unix 时间戳，秒）
+    refresh_at: i64,
 }
 
 impl std::fmt::Debug for CachedToken {
@@ -32,6 +41,7 @@ impl std::fmt::Debug for CachedToken {
         f.debug_struct("CachedToken")
             .field("token", &"***")
             .field("expires_at", &self.expires_at)
+            .field("refresh_at", &self.refresh_at)
             .finish()
     }
 }
@@ -45,18 +55,28 @@ impl CachedToken {
     }
 
     /// 创建新的缓存 token
-    fn new(token: String, expires_in_seconds: i64) -> Self {
+    fn new(token: String, expires_in_seconds: i64, refresh_ahead: Duration) -> Self {
         let now = Self::now_epoch_secs();
         // 提前 60 秒过期，避免临界情况（小于 60 秒则视为立即过期）
         let expires_at = now.saturating_add(expires_in_seconds.saturating_sub(60));
+        let refresh_at = expires_at.saturating_sub(refresh_ahead.as_secs() as i64);
 
-        Self { token, expires_at }
+        Self {
+            token,
+            expires_at,
+            refresh_at,
+        }
     }
 
     /// 检查 token 是否已过期
     fn is_expired(&self) -> bool {
         Self::now_epoch_secs() >= self.expires_at
     }
+
+    /// 检查 token 是否已进入提前刷新窗口
+    fn needs_refresh(&self) -> bool {
+        Self::now_epoch_secs() >= self.refresh_at
+    }
 }
 
 /// 基于 openlark-auth API 的 TokenProvider（带缓存）
@@ -65,6 +85,10 @@ pub struct AuthTokenProvider {
     config: Config,
     /// token 缓存：key 为 token 类型字符串，value 为缓存的 token
     cache: Arc<RwLock<HashMap<String, CachedToken>>>,
+    /// 每个缓存键一把获取锁，合并并发获取
+    fetch_locks: Arc<std::sync::Mutex<HashMap<String, Arc<Mutex<()>>>>>,
+    /// 提前刷新窗口
+    refresh_ahead: Duration,
 }
 
 impl Clone for AuthTokenProvider {
@@ -72,6 +96,8 @@ impl Clone for AuthTokenProvider {
         Self {
             config: self.config.clone(),
             cache: Arc::clone(&self.cache),
+            fetch_locks: Arc::clone(&self.fetch_locks),
+            refresh_ahead: self.refresh_ahead,
         }
     }
 }
@@ -85,9 +111,19 @@ impl AuthTokenProvider {
         Self {
             config,
             cache: Arc::new(RwLock::new(HashMap::new())),
+            fetch_locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            refresh_ahead: DEFAULT_REFRESH_AHEAD,
         }
     }
 
+    /// 设置提前刷新窗口（默认 5 分钟）
+    ///
+    /// 飞书在 token 剩余有效期不足 30 分钟时才会返回新 token，窗口不宜超过 30 分钟。
+    pub fn with_refresh_ahead(mut self, refresh_ahead: Duration) -> Self {
+        self.refresh_ahead = refresh_ahead;
+        self
+    }
+
     /// 生成缓存键
     fn cache_key(
         token_type: &AccessTokenType,
@@ -107,32 +143,106 @@ impl AuthTokenProvider {
         }
     }
 
-    async fn get_cached(&self, cache_key: &str) -> Option<String> {
+    async fn get_cached(&self, cache_key: &str) -> Option<CachedToken> {
         let cache = self.cache.read().await;
         cache
             .get(cache_key)
             .filter(|cached| !cached.is_expired())
-            .map(|cached| cached.token.clone())
+            .cloned()
     }
 
     async fn set_cached(&self, cache_key: String, token: String, expires_in_seconds: i64) {
-        let cached = CachedToken::new(token, expires_in_seconds);
+        let cached = CachedToken::new(token, expires_in_seconds, self.refresh_ahead);
         self.cache.write().await.insert(cache_key, cached);
     }
 
+    fn fetch_lock(&self, cache_key: &str) -> Arc<Mutex<()>> {
+        let mut locks = self
+            .fetch_locks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Arc::clone(locks.entry(cache_key.to_string()).or_default())
+    }
+
     async fn get_or_fetch<F, Fut>(&self, cache_key: String, fetch: F) -> SDKResult<String>
     where
         F: FnOnce() -> Fut,
         Fut: Future<Output = SDKResult<(String, i64)>>,
     {
-        if let Some(token) = self.get_cached(&cache_key).await {
-            return Ok(token);
+        if let Some(cached) = self.get_cached(&cache_key).await
+            && !cached.needs_refresh()
+        {
+            return Ok(cached.token);
         }
 
-        let (token, expires_in_seconds) = fetch().await?;
-        self.set_cached(cache_key, token.clone(), expires_in_seconds)
-            .await;
-        Ok(token)
+        // 拿到锁后再查一次：排在前面的任务可能已经换到了新 token
+        let lock = self.fetch_lock(&cache_key);
+        let _guard = lock.lock().await;
+        let cached = self.get_cached(&cache_key).await;
+        if let Some(cached) = &cached
+            && !cached.needs_refresh()
+        {
+            return Ok(cached.token.clone());
+        }
+
+        match fetch().await {
+            Ok((token, expires_in_seconds)) => {
+                self.set_cached(cache_key, token.clone(), expires_in_seconds)
+                    .await;
+                Ok(token)
+            }
+            Err(err) => match cached {
+                Some(cached) => {
+                    tracing::warn!("提前刷新 token 失败，继续使用未过期的旧 token: {err}");
+                    Ok(cached.token)
+                }
+                None => Err(err),
+            },
+        }
+    }
+
+    /// 商店应用的 app_ticket：优先使用请求中显式传入的值，否则读取 `Config` 挂载的票据存储
+    ///
+    /// 存储中没有票据时触发一次重新推送（尽力而为），并返回配置错误提示稍后重试。
+    async fn resolve_app_ticket(&self, request: &TokenRequest) -> SDKResult<String> {
+        if let Some(ticket) = request.app_ticket.clone() {
+            return Ok(ticket);
+        }
+        if let Some(store) = self.config.app_ticket_store()
+            && let Some(ticket) = store.get(self.config.app_id()).await?
+        {
+            return Ok(ticket.ticket);
+        }
+        if let Err(err) = apply_app_ticket(&self.config).await {
+            tracing::warn!("触发 app_ticket 重新推送失败: {err}");
+        }
+        Err(configuration_error(
+            "token_provider: marketplace app 尚未收到 app_ticket，已请求飞书重新推送，请稍后重试",
+        ))
+    }
+
+    async fn fetch_marketplace_app_token(
+        &self,
+        request: &TokenRequest,
+    ) -> SDKResult<(String, i64)> {
+        let app_ticket = self.resolve_app_ticket(request).await?;
+        self.fetch_token_via_http(
+            "/open-apis/auth/v3/app_access_token",
+            json!({
+                "app_id": self.config.app_id(),
+                "app_secret": self.config.app_secret(),
+                "app_ticket": app_ticket,
+            }),
+            "app_access_token",
+        )
+        .await
+    }
+
+    /// 商店应用的 app_access_token（复用 App 类型的缓存），用于换取 tenant_access_token
+    async fn marketplace_app_token(&self, request: &TokenRequest) -> SDKResult<String> {
+        let cache_key = Self::cache_key(&AccessTokenType::App, &AppType::Marketplace, request);
+        self.get_or_fetch(cache_key, || self.fetch_marketplace_app_token(request))
+            .await
     }
 
     async fn fetch_token_via_http(
@@ -212,15 +322,7 @@ impl TokenProvider for AuthTokenProvider {
                                 .await?
                             }
                             AppType::Marketplace => {
-                                self.fetch_token_via_http(
-                                    "/open-apis/auth/v3/app_access_token",
-                                    json!({
-                                        "app_id": self.config.app_id(),
-                                        "app_secret": self.config.app_secret(),
-                                    }),
-                                    "app_access_token",
-                                )
-                                .await?
+                                self.fetch_marketplace_app_token(&request).await?
                             }
                         };
                         Ok((token, expires_in))
@@ -247,18 +349,18 @@ impl TokenProvider for AuthTokenProvider {
                             .await?
                         }
                         AppType::Marketplace => {
-                            let app_ticket = request.app_ticket.clone().ok_or_else(|| {
+                            let tenant_key = request.tenant_key.clone().ok_or_else(|| {
                                 configuration_error(
-                                    "token_provider: marketplace app requires app_ticket to fetch tenant_access_token",
+                                    "token_provider: marketplace app requires tenant_key to fetch tenant_access_token",
                                 )
                             })?;
+                            let app_access_token = self.marketplace_app_token(&request).await?;
 
                             self.fetch_token_via_http(
                                 "/open-apis/auth/v3/tenant_access_token",
                                 json!({
-                                    "app_id": self.config.app_id(),
-                                    "app_secret": self.config.app_secret(),
-                                    "app_ticket": app_ticket,
+                                    "app_access_token": app_access_token,
+                                    "tenant_key": tenant_key,
                                 }),
                                 "tenant_access_token",
                             )
@@ -278,6 +380,13 @@ impl TokenProvider for AuthTokenProvider {
             }
         })
     }
+
+    fn invalidate(&self, request: TokenRequest) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            let cache_key = Self::cache_key(&request.token_type, &self.config.app_type(), &request);
+            self.cache.write().await.remove(&cache_key);
+        })
+    }
 }
 
 #[cfg(test)]
@@ -285,7 +394,7 @@ impl TokenProvider for AuthTokenProvider {
 mod tests {
     use super::AuthTokenProvider;
     use openlark_core::{
-        auth::{TokenProvider, TokenRequest},
+        auth::{TokenProvider, TokenRequest, app_ticket::apply_app_ticket},
         config::Config,
         constants::AppType,
     };
@@ -332,4 +441,187 @@ mod tests {
 
         assert_eq!(key, "App_Marketplace_ticket_001");
     }
+
+    #[tokio::test]
+    async fn marketplace_tenant_token_uses_stored_app_ticket() {
+        use openlark_core::auth::{AppTicket, AppTicketStore, MemoryAppTicketStore};
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/auth/v3/app_access_token"))
+            .and(body_json(json!({
+                "app_id": "cli_market",
+                "app_secret": "secret",
+                "app_ticket": "ticket_001",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "app_access_token": "a-market",
+                "expire": 7200,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/auth/v3/tenant_access_token"))
+            .and(body_json(json!({
+                "app_access_token": "a-market",
+                "tenant_key": "tenant_001",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "tenant_access_token": "t-tenant_001",
+                "expire": 7200,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let store = MemoryAppTicketStore::new();
+        store
+            .set("cli_market", AppTicket::new("ticket_001"))
+            .await
+            .unwrap();
+        let config = Config::builder()
+            .app_id("cli_market")
+            .app_secret("secret")
+            .app_type(AppType::Marketplace)
+            .app_ticket_store(store)
+            .base_url(server.uri())
+            .build();
+        let provider = AuthTokenProvider::new(config);
+
+        for _ in 0..2 {
+            let token = provider
+                .get_token(TokenRequest::tenant().tenant_key("tenant_001"))
+                .await
+                .unwrap();
+            assert_eq!(token, "t-tenant_001");
+        }
+    }
+
+    #[tokio::test]
+    async fn marketplace_tenant_token_requires_tenant_key() {
+        let config = Config::builder()
+            .app_id("cli_market")
+            .app_secret("secret")
+            .app_type(AppType::Marketplace)
+            .base_url("http://127.0.0.1:9")
+            .build();
+        let err = AuthTokenProvider::new(config)
+            .get_token(TokenRequest::tenant())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("tenant_key"));
+    }
+
+    fn self_build_config(server: &wiremock::MockServer) -> Config {
+        Config::builder()
+            .app_id("cli_test")
+            .app_secret("secret")
+            .base_url(server.uri())
+            .build()
+    }
+
+    async fn mount_tenant_token(server: &wiremock::MockServer, token: &str, times: u64) {
+        use serde_json::json;
+        use wiremock::{
+            Mock, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        Mock::given(method("POST"))
+            .and(path("/open-apis/auth/v3/tenant_access_token/internal"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(std::time::Duration::from_millis(50))
+                    .set_body_json(json!({
+                        "code": 0,
+                        "tenant_access_token": token,
+                        "expire": 7200,
+                    })),
+            )
+            .up_to_n_times(times)
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn concurrent_fetches_are_coalesced() {
+        let server = wiremock::MockServer::start().await;
+        mount_tenant_token(&server, "t-1", 1).await;
+
+        let provider = AuthTokenProvider::new(self_build_config(&server));
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let provider = provider.clone();
+                tokio::spawn(async move { provider.get_token(TokenRequest::tenant()).await })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap(), "t-1");
+        }
+    }
+
+    #[tokio::test]
+    async fn invalidate_forces_refetch() {
+        let server = wiremock::MockServer::start().await;
+        mount_tenant_token(&server, "t-1", 1).await;
+
+        let provider = AuthTokenProvider::new(self_build_config(&server));
+        assert_eq!(
+            provider.get_token(TokenRequest::tenant()).await.unwrap(),
+            "t-1"
+        );
+        assert_eq!(
+            provider.get_token(TokenRequest::tenant()).await.unwrap(),
+            "t-1"
+        );
+
+        mount_tenant_token(&server, "t-2", 1).await;
+        provider.invalidate(TokenRequest::tenant()).await;
+        assert_eq!(
+            provider.get_token(TokenRequest::tenant()).await.unwrap(),
+            "t-2"
+        );
+    }
+
+    #[tokio::test]
+    async fn refresh_ahead_falls_back_to_valid_token_on_failure() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = wiremock::MockServer::start().await;
+        mount_tenant_token(&server, "t-1", 1).await;
+
+        // 窗口大于有效期：每次获取都会尝试刷新
+        let provider = AuthTokenProvider::new(self_build_config(&server))
+            .with_refresh_ahead(std::time::Duration::from_secs(7200));
+        assert_eq!(
+            provider.get_token(TokenRequest::tenant()).await.unwrap(),
+            "t-1"
+        );
+
+        Mock::given(method("POST"))
+            .and(path("/open-apis/auth/v3/tenant_access_token/internal"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"code": 10003, "msg": "busy"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        assert_eq!(
+            provider.get_token(TokenRequest::tenant()).await.unwrap(),
+            "t-1"
+        );
+    }
 }
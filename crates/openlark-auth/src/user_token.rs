@@ -0,0 +1,796 @@
+//! 用户访问令牌管理
+//!
+//! 内部工具大多以用户身份调用开放平台，[`UserTokenManager`] 封装了完整的用户授权流程：
+//!
+//! - [`UserTokenManager::authorization_url`]：生成带 `state` 与 PKCE（S256）参数的授权链接
+//! - [`UserTokenManager::exchange_code`]：校验 `state` 后用授权码换取令牌，并按用户 `open_id` 保存
+//! - [`UserTokenManager::access_token`]：过期前主动刷新，同一用户的并发刷新只会发起一次请求
+//! - [`UserTokenManager::request_option`]：生成携带 `user_access_token` 的 [`RequestOption`]
+//!
+//! 令牌通过 [`UserTokenStore`] 持久化，默认使用进程内的 [`MemoryUserTokenStore`]。
+//!
+//! ```rust,no_run
+//! use openlark_auth::UserTokenManager;
+//! use openlark_core::config::Config;
+//!
+//! # async fn example(config: Config, code: String, state: String) -> openlark_core::SDKResult<()> {
+//! let manager = UserTokenManager::new(config);
+//!
+//! // 1. 引导用户访问授权链接
+//! let authorization = manager.authorization_url("https://example.com/callback", &["contact:user.base:readonly"]);
+//! println!("{}", authorization.url);
+//!
+//! // 2. 回调中用授权码换取令牌
+//! let user = manager.exchange_code(&code, &state).await?;
+//!
+//! // 3. 以用户身份调用接口
+//! let option = manager.request_option(&user.user_id).await?;
+//! # let _ = option;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant, SystemTime},
+};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use openlark_core::{
+    SDKResult,
+    api::ApiRequest,
+    config::Config,
+    constants::AccessTokenType,
+    error::{api_error, authentication_error, token_expired_error},
+    http::Transport,
+    req_option::RequestOption,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::{common::api_endpoints::AuthenApiV1, models::authen::UserInfo};
+
+/// 授权页路径（位于 `accounts` 域名下）
+const AUTHORIZE_PATH: &str = "/open-apis/authen/v1/authorize";
+/// OAuth 令牌接口（授权码换取 / 刷新）
+const OAUTH_TOKEN_PATH: &str = "/open-apis/authen/v2/oauth/token";
+/// 默认在过期前 5 分钟刷新
+const DEFAULT_REFRESH_AHEAD: Duration = Duration::from_secs(300);
+/// 授权链接中 `state` 的默认有效期
+const DEFAULT_STATE_TTL: Duration = Duration::from_secs(600);
+
+/// 用户访问令牌
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserToken {
+    /// `user_access_token`
+    pub access_token: String,
+    /// `refresh_token`（未申请 `offline_access` 权限时为空）
+    pub refresh_token: Option<String>,
+    /// `access_token` 过期时间
+    pub expires_at: SystemTime,
+    /// `refresh_token` 过期时间
+    pub refresh_expires_at: Option<SystemTime>,
+    /// 实际授予的权限范围（空格分隔）
+    pub scope: Option<String>,
+}
+
+impl UserToken {
+    /// `access_token` 是否已过期
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expires_at
+    }
+
+    /// `access_token` 是否会在 `window` 内过期
+    pub fn expires_within(&self, window: Duration) -> bool {
+        SystemTime::now() + window >= self.expires_at
+    }
+
+    /// 是否还能用 `refresh_token` 换取新令牌
+    pub fn can_refresh(&self) -> bool {
+        self.refresh_token.is_some()
+            && self
+                .refresh_expires_at
+                .is_none_or(|expires_at| SystemTime::now() < expires_at)
+    }
+}
+
+impl fmt::Debug for UserToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserToken")
+            .field("access_token", &"***")
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| "***"))
+            .field("expires_at", &self.expires_at)
+            .field("refresh_expires_at", &self.refresh_expires_at)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+/// 用户令牌存储返回的 Future
+pub type UserTokenFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 用户令牌存储
+///
+/// 以用户 `open_id` 为键保存令牌。`refresh_token` 只能使用一次，
+/// 多实例部署时应使用共享存储（如 Redis、数据库），避免各实例持有过期的刷新令牌。
+pub trait UserTokenStore: Send + Sync + fmt::Debug {
+    /// 读取用户的令牌
+    fn get<'a>(&'a self, user_id: &'a str) -> UserTokenFuture<'a, SDKResult<Option<UserToken>>>;
+
+    /// 保存用户的令牌
+    fn set<'a>(&'a self, user_id: &'a str, token: UserToken) -> UserTokenFuture<'a, SDKResult<()>>;
+
+    /// 删除用户的令牌
+    fn remove<'a>(&'a self, user_id: &'a str) -> UserTokenFuture<'a, SDKResult<()>>;
+}
+
+/// 内存用户令牌存储（单进程）
+#[derive(Debug, Default)]
+pub struct MemoryUserTokenStore {
+    tokens: RwLock<HashMap<String, UserToken>>,
+}
+
+impl MemoryUserTokenStore {
+    /// 创建空存储
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl UserTokenStore for MemoryUserTokenStore {
+    fn get<'a>(&'a self, user_id: &'a str) -> UserTokenFuture<'a, SDKResult<Option<UserToken>>> {
+        let token = self
+            .tokens
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(user_id)
+            .cloned();
+        Box::pin(async move { Ok(token) })
+    }
+
+    fn set<'a>(&'a self, user_id: &'a str, token: UserToken) -> UserTokenFuture<'a, SDKResult<()>> {
+        self.tokens
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(user_id.to_string(), token);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn remove<'a>(&'a self, user_id: &'a str) -> UserTokenFuture<'a, SDKResult<()>> {
+        self.tokens
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(user_id);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// 待用户访问的授权链接
+#[derive(Clone)]
+pub struct AuthorizationRequest {
+    /// 完整的授权链接
+    pub url: String,
+    /// 防 CSRF 的随机 `state`，回调时原样带回
+    pub state: String,
+    /// PKCE `code_verifier`，多实例部署时可自行保存并调用
+    /// [`UserTokenManager::exchange_code_with_verifier`]
+    pub code_verifier: String,
+}
+
+impl fmt::Debug for AuthorizationRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthorizationRequest")
+            .field("url", &self.url)
+            .field("state", &self.state)
+            .field("code_verifier", &"***")
+            .finish()
+    }
+}
+
+/// 完成授权的用户
+#[derive(Debug, Clone)]
+pub struct AuthorizedUser {
+    /// 用户 `open_id`，即令牌存储的键
+    pub user_id: String,
+    /// 用户信息
+    pub user_info: UserInfo,
+    /// 用户令牌
+    pub token: UserToken,
+}
+
+#[derive(Debug)]
+struct PendingAuthorization {
+    redirect_uri: String,
+    code_verifier: String,
+    created_at: Instant,
+}
+
+/// OAuth 令牌接口响应（扁平结构，不包在 `data` 中）
+#[derive(Debug, Deserialize)]
+struct OAuthTokenResponse {
+    #[serde(default)]
+    code: i64,
+    access_token: Option<String>,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+    refresh_token_expires_in: Option<u64>,
+    scope: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+    msg: Option<String>,
+}
+
+/// 用户访问令牌管理器
+///
+/// 克隆开销很小，克隆后的实例共享存储、待完成授权和刷新锁。
+#[derive(Clone)]
+pub struct UserTokenManager {
+    inner: Arc<ManagerInner>,
+}
+
+struct ManagerInner {
+    config: Config,
+    store: Arc<dyn UserTokenStore>,
+    refresh_ahead: Duration,
+    state_ttl: Duration,
+    authorize_base_url: String,
+    pending: Mutex<HashMap<String, PendingAuthorization>>,
+    refresh_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl fmt::Debug for UserTokenManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserTokenManager")
+            .field("app_id", &self.inner.config.app_id())
+            .field("store", &self.inner.store)
+            .field("refresh_ahead", &self.inner.refresh_ahead)
+            .field("authorize_base_url", &self.inner.authorize_base_url)
+            .finish()
+    }
+}
+
+impl UserTokenManager {
+    /// 使用默认设置（内存存储、提前 5 分钟刷新）创建管理器
+    pub fn new(config: Config) -> Self {
+        Self::builder(config).build()
+    }
+
+    /// 创建构建器
+    pub fn builder(config: Config) -> UserTokenManagerBuilder {
+        UserTokenManagerBuilder {
+            config,
+            store: None,
+            refresh_ahead: DEFAULT_REFRESH_AHEAD,
+            state_ttl: DEFAULT_STATE_TTL,
+            authorize_base_url: None,
+        }
+    }
+
+    /// 令牌存储
+    pub fn store(&self) -> &Arc<dyn UserTokenStore> {
+        &self.inner.store
+    }
+
+    /// 生成授权链接
+    ///
+    /// `scopes` 为需要用户授予的权限；需要长期访问时应包含 `offline_access`，否则不会返回 `refresh_token`。
+    pub fn authorization_url(
+        &self,
+        redirect_uri: impl Into<String>,
+        scopes: &[&str],
+    ) -> AuthorizationRequest {
+        let redirect_uri = redirect_uri.into();
+        let state = random_token();
+        let code_verifier = random_token();
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+        let mut url = format!(
+            "{}{}?client_id={}&response_type=code&redirect_uri={}&state={}&code_challenge={}&code_challenge_method=S256",
+            self.inner.authorize_base_url,
+            AUTHORIZE_PATH,
+            urlencoding::encode(self.inner.config.app_id()),
+            urlencoding::encode(&redirect_uri),
+            state,
+            code_challenge,
+        );
+        if !scopes.is_empty() {
+            url.push_str("&scope=");
+            url.push_str(&urlencoding::encode(&scopes.join(" ")));
+        }
+
+        let mut pending = self.pending();
+        let ttl = self.inner.state_ttl;
+        pending.retain(|_, item| item.created_at.elapsed() < ttl);
+        pending.insert(
+            state.clone(),
+            PendingAuthorization {
+                redirect_uri,
+                code_verifier: code_verifier.clone(),
+                created_at: Instant::now(),
+            },
+        );
+
+        AuthorizationRequest {
+            url,
+            state,
+            code_verifier,
+        }
+    }
+
+    /// 校验回调中的 `state`，用授权码换取令牌并保存
+    pub async fn exchange_code(&self, code: &str, state: &str) -> SDKResult<AuthorizedUser> {
+        let pending = self
+            .pending()
+            .remove(state)
+            .filter(|item| item.created_at.elapsed() < self.inner.state_ttl)
+            .ok_or_else(|| authentication_error("OAuth state 无效或已过期，请重新发起授权"))?;
+        self.exchange_code_with_verifier(code, &pending.redirect_uri, Some(&pending.code_verifier))
+            .await
+    }
+
+    /// 用授权码换取令牌并保存（由调用方自行校验 `state`、保存 `code_verifier`）
+    pub async fn exchange_code_with_verifier(
+        &self,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: Option<&str>,
+    ) -> SDKResult<AuthorizedUser> {
+        let mut payload = json!({
+            "grant_type": "authorization_code",
+            "client_id": self.inner.config.app_id(),
+            "client_secret": self.inner.config.app_secret(),
+            "code": code,
+            "redirect_uri": redirect_uri,
+        });
+        if let Some(code_verifier) = code_verifier {
+            payload["code_verifier"] = Value::from(code_verifier);
+        }
+        let token = self.request_token(payload).await?;
+
+        let user_info = self.user_info(&token.access_token).await?;
+        let user_id = user_info.open_id.clone();
+        self.inner.store.set(&user_id, token.clone()).await?;
+        tracing::debug!(user_id = %user_id, "用户授权完成");
+
+        Ok(AuthorizedUser {
+            user_id,
+            user_info,
+            token,
+        })
+    }
+
+    /// 获取用户可用的 `user_access_token`，临近过期时自动刷新
+    pub async fn access_token(&self, user_id: &str) -> SDKResult<String> {
+        let token = self.stored_token(user_id).await?;
+        if !token.expires_within(self.inner.refresh_ahead) {
+            return Ok(token.access_token);
+        }
+        Ok(self.refresh_single_flight(user_id).await?.access_token)
+    }
+
+    /// 生成以该用户身份调用接口的 [`RequestOption`]
+    pub async fn request_option(&self, user_id: &str) -> SDKResult<RequestOption> {
+        let token = self.access_token(user_id).await?;
+        Ok(RequestOption::builder().user_access_token(token).build())
+    }
+
+    /// 删除用户的令牌（用户退出登录或撤销授权时调用）
+    pub async fn remove(&self, user_id: &str) -> SDKResult<()> {
+        self.inner.store.remove(user_id).await
+    }
+
+    async fn stored_token(&self, user_id: &str) -> SDKResult<UserToken> {
+        self.inner
+            .store
+            .get(user_id)
+            .await?
+            .ok_or_else(|| authentication_error(format!("用户 {user_id} 尚未授权")))
+    }
+
+    /// 同一用户同时只有一个刷新请求；等待锁的调用方拿到锁后直接复用已刷新的令牌
+    async fn refresh_single_flight(&self, user_id: &str) -> SDKResult<UserToken> {
+        let lock = Arc::clone(
+            self.inner
+                .refresh_locks
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .entry(user_id.to_string())
+                .or_default(),
+        );
+        let _guard = lock.lock().await;
+
+        let token = self.stored_token(user_id).await?;
+        if !token.expires_within(self.inner.refresh_ahead) {
+            return Ok(token);
+        }
+        let refresh_token = match &token.refresh_token {
+            Some(refresh_token) if token.can_refresh() => refresh_token.clone(),
+            _ if !token.is_expired() => return Ok(token),
+            _ => {
+                return Err(token_expired_error(format!(
+                    "用户 {user_id} 的 refresh_token 不可用，需要重新授权"
+                )));
+            }
+        };
+
+        let refreshed = self
+            .request_token(json!({
+                "grant_type": "refresh_token",
+                "client_id": self.inner.config.app_id(),
+                "client_secret": self.inner.config.app_secret(),
+                "refresh_token": refresh_token,
+            }))
+            .await?;
+        self.inner.store.set(user_id, refreshed.clone()).await?;
+        tracing::debug!(user_id = %user_id, "user_access_token 已刷新");
+        Ok(refreshed)
+    }
+
+    async fn request_token(&self, payload: Value) -> SDKResult<UserToken> {
+        let url = format!(
+            "{}{}",
+            self.inner.config.base_url().trim_end_matches('/'),
+            OAUTH_TOKEN_PATH
+        );
+        let response = self
+            .inner
+            .config
+            .http_client()
+            .post(&url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| {
+                api_error(
+                    500,
+                    OAUTH_TOKEN_PATH,
+                    format!("请求飞书 OAuth 接口失败: {e}"),
+                    None,
+                )
+            })?;
+
+        let status = response.status().as_u16();
+        let body: OAuthTokenResponse = response.json().await.map_err(|e| {
+            api_error(
+                status,
+                OAUTH_TOKEN_PATH,
+                format!("解析飞书 OAuth 响应失败: {e}"),
+                None,
+            )
+        })?;
+
+        let access_token = match body.access_token {
+            Some(access_token) if body.code == 0 => access_token,
+            _ => {
+                let msg = body
+                    .error_description
+                    .or(body.msg)
+                    .or(body.error)
+                    .unwrap_or_else(|| "未知错误".to_string());
+                return Err(api_error(
+                    status,
+                    OAUTH_TOKEN_PATH,
+                    format!("飞书 OAuth 接口返回错误: code={}, msg={msg}", body.code),
+                    None,
+                ));
+            }
+        };
+
+        let now = SystemTime::now();
+        Ok(UserToken {
+            access_token,
+            refresh_token: body.refresh_token,
+            expires_at: now + Duration::from_secs(body.expires_in.unwrap_or(7200)),
+            refresh_expires_at: body
+                .refresh_token_expires_in
+                .map(|seconds| now + Duration::from_secs(seconds)),
+            scope: body.scope,
+        })
+    }
+
+    async fn user_info(&self, access_token: &str) -> SDKResult<UserInfo> {
+        let request: ApiRequest<UserInfo> = ApiRequest::get(AuthenApiV1::UserInfo.path())
+            .access_token_types([AccessTokenType::User]);
+        let option = RequestOption::builder()
+            .user_access_token(access_token)
+            .build();
+        Transport::request(request, &self.inner.config, Some(option))
+            .await?
+            .into_result()
+    }
+
+    fn pending(&self) -> std::sync::MutexGuard<'_, HashMap<String, PendingAuthorization>> {
+        self.inner
+            .pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// [`UserTokenManager`] 构建器
+#[derive(Debug)]
+pub struct UserTokenManagerBuilder {
+    config: Config,
+    store: Option<Arc<dyn UserTokenStore>>,
+    refresh_ahead: Duration,
+    state_ttl: Duration,
+    authorize_base_url: Option<String>,
+}
+
+impl UserTokenManagerBuilder {
+    /// 设置令牌存储（默认内存存储）
+    pub fn store(mut self, store: impl UserTokenStore + 'static) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// 设置提前刷新的时间窗口（默认 5 分钟）
+    pub fn refresh_ahead(mut self, refresh_ahead: Duration) -> Self {
+        self.refresh_ahead = refresh_ahead;
+        self
+    }
+
+    /// 设置授权链接 `state` 的有效期（默认 10 分钟）
+    pub fn state_ttl(mut self, state_ttl: Duration) -> Self {
+        self.state_ttl = state_ttl;
+        self
+    }
+
+    /// 设置授权页域名（默认由 `base_url` 推导，如 `https://accounts.feishu.cn`）
+    pub fn authorize_base_url(mut self, url: impl Into<String>) -> Self {
+        self.authorize_base_url = Some(url.into());
+        self
+    }
+
+    /// 构建管理器
+    pub fn build(self) -> UserTokenManager {
+        let authorize_base_url = self
+            .authorize_base_url
+            .unwrap_or_else(|| accounts_base_url(self.config.base_url()));
+        UserTokenManager {
+            inner: Arc::new(ManagerInner {
+                store: self
+                    .store
+                    .unwrap_or_else(|| Arc::new(MemoryUserTokenStore::new())),
+                refresh_ahead: self.refresh_ahead,
+                state_ttl: self.state_ttl,
+                authorize_base_url: authorize_base_url.trim_end_matches('/').to_string(),
+                config: self.config,
+                pending: Mutex::new(HashMap::new()),
+                refresh_locks: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+}
+
+/// 授权页位于 `accounts` 子域：`open.feishu.cn` → `accounts.feishu.cn`
+fn accounts_base_url(base_url: &str) -> String {
+    let Ok(mut url) = url::Url::parse(base_url) else {
+        return base_url.to_string();
+    };
+    let accounts_host = url
+        .host_str()
+        .and_then(|host| host.strip_prefix("open."))
+        .map(|domain| format!("accounts.{domain}"));
+    if let Some(host) = accounts_host {
+        let _ = url.set_host(Some(&host));
+    }
+    url.as_str().trim_end_matches('/').to_string()
+}
+
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_partial_json, header, method, path},
+    };
+
+    fn config(server: &MockServer) -> Config {
+        Config::builder()
+            .app_id("cli_test")
+            .app_secret("secret")
+            .base_url(server.uri())
+            .build()
+    }
+
+    fn query_param(url: &str, key: &str) -> Option<String> {
+        url::Url::parse(url)
+            .ok()?
+            .query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    }
+
+    #[test]
+    fn test_authorization_url_with_pkce() {
+        let config = Config::builder()
+            .app_id("cli_test")
+            .app_secret("secret")
+            .build();
+        let manager = UserTokenManager::new(config);
+        let request =
+            manager.authorization_url("https://example.com/cb", &["offline_access", "im:message"]);
+
+        assert!(
+            request
+                .url
+                .starts_with("https://accounts.feishu.cn/open-apis/authen/v1/authorize?")
+        );
+        assert_eq!(
+            query_param(&request.url, "state").as_deref(),
+            Some(request.state.as_str())
+        );
+        assert_eq!(
+            query_param(&request.url, "redirect_uri").as_deref(),
+            Some("https://example.com/cb")
+        );
+        assert_eq!(
+            query_param(&request.url, "scope").as_deref(),
+            Some("offline_access im:message")
+        );
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(request.code_verifier.as_bytes()));
+        assert_eq!(
+            query_param(&request.url, "code_challenge").as_deref(),
+            Some(challenge.as_str())
+        );
+        assert!(!format!("{request:?}").contains(&request.code_verifier));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_stores_token_and_builds_option() {
+        let server = MockServer::start().await;
+        let manager = UserTokenManager::new(config(&server));
+        let request = manager.authorization_url("https://example.com/cb", &[]);
+
+        Mock::given(method("POST"))
+            .and(path(OAUTH_TOKEN_PATH))
+            .and(body_partial_json(json!({
+                "grant_type": "authorization_code",
+                "client_id": "cli_test",
+                "code": "code-1",
+                "code_verifier": request.code_verifier,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "access_token": "u-1",
+                "expires_in": 7200,
+                "refresh_token": "r-1",
+                "refresh_token_expires_in": 604800,
+                "scope": "offline_access",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/authen/v1/user_info"))
+            .and(header("Authorization", "Bearer u-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "data": {"open_id": "ou_1", "name": "张三"},
+            })))
+            .mount(&server)
+            .await;
+
+        let user = manager
+            .exchange_code("code-1", &request.state)
+            .await
+            .unwrap();
+        assert_eq!(user.user_id, "ou_1");
+        assert_eq!(user.user_info.name.as_deref(), Some("张三"));
+        assert!(user.token.can_refresh());
+
+        // state 只能使用一次
+        let err = manager
+            .exchange_code("code-1", &request.state)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("state"));
+
+        Mock::given(method("GET"))
+            .and(path("/open-apis/contact/v3/users/me"))
+            .and(header("Authorization", "Bearer u-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 0, "data": {}})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let option = manager.request_option("ou_1").await.unwrap();
+        let request: ApiRequest<Value> = ApiRequest::get("/open-apis/contact/v3/users/me")
+            .access_token_types([AccessTokenType::User]);
+        Transport::<Value>::request(request, &manager.inner.config, Some(option))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_is_single_flight() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(OAUTH_TOKEN_PATH))
+            .and(body_partial_json(json!({
+                "grant_type": "refresh_token",
+                "refresh_token": "r-old",
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(Duration::from_millis(100))
+                    .set_body_json(json!({
+                        "code": 0,
+                        "access_token": "u-new",
+                        "expires_in": 7200,
+                        "refresh_token": "r-new",
+                    })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let manager = UserTokenManager::new(config(&server));
+        manager
+            .store()
+            .set(
+                "ou_1",
+                UserToken {
+                    access_token: "u-old".to_string(),
+                    refresh_token: Some("r-old".to_string()),
+                    expires_at: SystemTime::now() + Duration::from_secs(60),
+                    refresh_expires_at: None,
+                    scope: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let tasks: Vec<_> = (0..5)
+            .map(|_| {
+                let manager = manager.clone();
+                tokio::spawn(async move { manager.access_token("ou_1").await })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap(), "u-new");
+        }
+        let stored = manager.store().get("ou_1").await.unwrap().unwrap();
+        assert_eq!(stored.refresh_token.as_deref(), Some("r-new"));
+    }
+
+    #[tokio::test]
+    async fn test_expired_without_refresh_token_requires_reauthorization() {
+        let manager = UserTokenManager::new(
+            Config::builder()
+                .app_id("cli_test")
+                .app_secret("secret")
+                .build(),
+        );
+        manager
+            .store()
+            .set(
+                "ou_1",
+                UserToken {
+                    access_token: "u-old".to_string(),
+                    refresh_token: None,
+                    expires_at: SystemTime::now() - Duration::from_secs(1),
+                    refresh_expires_at: None,
+                    scope: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(manager.access_token("ou_1").await.is_err());
+        assert!(manager.access_token("ou_unknown").await.is_err());
+    }
+}
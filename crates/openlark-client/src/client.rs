@@ -207,6 +207,15 @@ impl Client {
         &self.core_config
     }
 
+    /// 🎫 确保商店应用已收到 app_ticket
+    ///
+    /// 票据存储中已有票据时直接返回；否则请求飞书重新推送，并在 `timeout` 内等待
+    /// 事件处理器（[`openlark_core::auth::AppTicketHandler`]）写入新票据。
+    /// 适合在启动长连接或 HTTP 回调服务之后调用。
+    pub async fn ensure_app_ticket(&self, timeout: std::time::Duration) -> Result<String> {
+        openlark_core::auth::app_ticket::ensure_app_ticket(&self.core_config, timeout).await
+    }
+
     /// ✅ 检查客户端是否已正确配置
     pub fn is_configured(&self) -> bool {
         !self.config.app_id.is_empty() && !self.config.app_secret.is_empty()
@@ -231,14 +240,26 @@ impl Client {
 
         // 从 client Config 获取 core Config
         #[cfg(feature = "auth")]
-        let base_core_config = config.as_ref().build_core_config();
-        #[cfg(feature = "auth")]
         let core_config = config
             .as_ref()
             .get_or_build_core_config_with_token_provider();
         #[cfg(not(feature = "auth"))]
         let core_config = config.as_ref().get_or_build_core_config();
 
+        Self::assemble(config, registry, core_config)
+    }
+
+    /// 基于已加载的服务注册表与 core 配置组装客户端
+    ///
+    /// 供 [`crate::pool::ClientPool`] 为每个租户生成共享注册表、连接池与令牌缓存的客户端视图。
+    pub(crate) fn assemble(
+        config: Arc<Config>,
+        registry: Arc<DefaultServiceRegistry>,
+        core_config: openlark_core::config::Config,
+    ) -> Result<Self> {
+        #[cfg(feature = "auth")]
+        let base_core_config = config.as_ref().build_core_config();
+
         #[cfg(feature = "cardkit")]
         let cardkit = openlark_cardkit::CardkitClient::new(core_config.clone());
 
@@ -3,9 +3,11 @@
 //! 提供灵活的配置系统，支持环境变量、验证和默认值
 
 use crate::Result;
+use std::sync::Arc;
 use std::time::Duration;
 
-use openlark_core::config::Config as CoreConfig;
+use openlark_core::auth::AppTicketStore;
+use openlark_core::config::{Config as CoreConfig, ConfigBuilder as CoreConfigBuilder};
 use openlark_core::constants::AppType;
 
 /// Check if the base_url points to a known Lark/Feishu domain
@@ -71,6 +73,8 @@ pub struct Config {
     pub headers: std::collections::HashMap<String, String>,
     /// 响应体最大大小限制（字节），默认 100MB
     pub max_response_size: u64,
+    /// 🎫 商店应用的 app_ticket 存储（未设置时商店应用使用内存存储）
+    pub app_ticket_store: Option<Arc<dyn AppTicketStore>>,
     /// 🔧 底层 core 配置（按需生成）
     #[doc(hidden)]
     pub(crate) core_config: Option<CoreConfig>,
@@ -89,6 +93,7 @@ impl std::fmt::Debug for Config {
             .field("enable_log", &self.enable_log)
             .field("headers", &format!("{} headers", self.headers.len()))
             .field("max_response_size", &self.max_response_size)
+            .field("app_ticket_store", &self.app_ticket_store)
             .finish()
     }
 }
@@ -107,6 +112,7 @@ impl Default for Config {
             enable_log: true,
             headers: std::collections::HashMap::new(),
             max_response_size: 100 * 1024 * 1024, // 100MB
+            app_ticket_store: None,
             core_config: None,
         }
     }
@@ -333,7 +339,11 @@ impl Config {
 
     /// 🔧 构建底层 core 配置（不含 TokenProvider）
     pub fn build_core_config(&self) -> CoreConfig {
-        CoreConfig::builder()
+        self.core_config_builder().build()
+    }
+
+    fn core_config_builder(&self) -> CoreConfigBuilder {
+        let builder = CoreConfig::builder()
             .app_id(self.app_id.clone())
             .app_secret(self.app_secret.clone())
             .base_url(self.base_url.clone())
@@ -341,8 +351,26 @@ impl Config {
             .enable_token_cache(self.enable_token_cache)
             .req_timeout(self.timeout)
             .max_response_size(self.max_response_size)
-            .header(self.headers.clone())
-            .build()
+            .header(self.headers.clone());
+        match &self.app_ticket_store {
+            Some(store) => builder.app_ticket_store(Arc::clone(store)),
+            None => builder,
+        }
+    }
+
+    /// 🔧 构建复用指定 HTTP 连接池的 core 配置（启用 `auth` 时附带默认 TokenProvider）
+    pub(crate) fn build_core_config_with_http_client(
+        &self,
+        http_client: reqwest::Client,
+    ) -> CoreConfig {
+        let base_config = self.core_config_builder().http_client(http_client).build();
+        #[cfg(feature = "auth")]
+        {
+            let provider = openlark_auth::AuthTokenProvider::new(base_config.clone());
+            base_config.with_token_provider(provider)
+        }
+        #[cfg(not(feature = "auth"))]
+        base_config
     }
 
     /// 🔧 构建带有默认 TokenProvider 的 core 配置
@@ -456,6 +484,12 @@ impl ConfigBuilder {
         self
     }
 
+    /// 🎫 设置商店应用的 app_ticket 存储（多实例部署时使用共享存储）
+    pub fn app_ticket_store(mut self, store: impl AppTicketStore + 'static) -> Self {
+        self.config.app_ticket_store = Some(Arc::new(store));
+        self
+    }
+
     /// 🔧 添加自定义HTTP header
     pub fn add_header<K, V>(mut self, key: K, value: V) -> Self
     where
@@ -647,6 +681,7 @@ mod tests {
             enable_log: true,
             headers: std::collections::HashMap::new(),
             max_response_size: 100 * 1024 * 1024,
+            app_ticket_store: None,
             core_config: None,
         };
         assert!(config.validate().is_ok());
@@ -715,6 +750,7 @@ mod tests {
             enable_log: true,
             headers: std::collections::HashMap::new(),
             max_response_size: 100 * 1024 * 1024,
+            app_ticket_store: None,
             core_config: None,
         };
 
@@ -768,6 +804,7 @@ fn test_config_validation_known_base_url() {
             enable_log: true,
             headers: std::collections::HashMap::new(),
             max_response_size: 100 * 1024 * 1024,
+            app_ticket_store: None,
             core_config: None,
         };
         assert!(config.validate().is_ok(), "URL {url} should be valid");
@@ -798,6 +835,7 @@ fn test_config_validation_unknown_base_url_rejected() {
             enable_log: true,
             headers: std::collections::HashMap::new(),
             max_response_size: 100 * 1024 * 1024,
+            app_ticket_store: None,
             core_config: None,
         };
         assert!(config.validate().is_err(), "URL {url} should be rejected");
@@ -819,6 +857,7 @@ fn test_config_validation_custom_base_url_allowed() {
         enable_log: true,
         headers: std::collections::HashMap::new(),
         max_response_size: 100 * 1024 * 1024,
+        app_ticket_store: None,
         core_config: None,
     };
     assert!(config.validate().is_ok());
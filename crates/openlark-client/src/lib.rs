@@ -244,6 +244,8 @@ pub mod client;
 pub mod config;
 pub mod error;
 pub mod features;
+pub mod pool;
+pub mod raw;
 pub mod registry;
 pub mod traits;
 pub mod types;
@@ -275,6 +277,8 @@ pub mod ws_client;
 // 客户端和配置
 pub use client::{Client, ClientBuilder};
 pub use config::Config;
+pub use pool::{ClientPool, TenantClient};
+pub use raw::RawRequest;
 
 // 企业级错误处理系统 - 基于 CoreError
 pub use error::{Error, Result};
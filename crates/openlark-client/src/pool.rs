@@ -0,0 +1,802 @@
+//! 多应用、多租户客户端池
+//!
+//! 面向服务多个租户的商店应用（ISV）：`ClientPool` 管理多个应用，按 `(app_id, tenant_key)`
+//! 发放租户视图 [`TenantClient`]。租户视图自动注入 `tenant_key`，同一应用下的所有租户共享
+//! 令牌缓存，所有应用共享同一个 HTTP 连接池；每个租户有独立的限流与调用统计。
+//!
+//! ```rust,no_run
+//! use openlark_client::{Config, pool::{ClientPool, RateLimit}};
+//! use std::time::Duration;
+//!
+//! # async fn demo() -> openlark_client::Result<()> {
+//! let pool = ClientPool::builder()
+//!     .tenant_rate_limit(RateLimit::new(50, Duration::from_secs(1)))
+//!     .build();
+//! pool.add_app(
+//!     Config::builder()
+//!         .app_id("cli_xxx")
+//!         .app_secret("xxx")
+//!         .app_type(openlark_core::constants::AppType::Marketplace)
+//!         .build()?,
+//! )?;
+//!
+//! let tenant = pool.tenant("cli_xxx", "tenant_key_xxx")?;
+//! // 与 Client 的用法一致，无需再传 tenant_key
+//! let _ = tenant.api_config();
+//! println!("{:?}", tenant.stats());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Deref,
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use openlark_core::{
+    SDKResult,
+    api::RawResponse,
+    error::{CoreError, validation_error},
+    middleware::{Middleware, MiddlewareFuture, RequestContext},
+};
+use serde_json::Value;
+
+use crate::{Client, Config, DefaultServiceRegistry, Result};
+
+/// 限流配置：`per` 时间窗口内最多 `max_requests` 次请求
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// 窗口内允许的请求数
+    pub max_requests: u32,
+    /// 窗口长度
+    pub per: Duration,
+}
+
+impl RateLimit {
+    /// 创建限流配置
+    pub fn new(max_requests: u32, per: Duration) -> Self {
+        Self { max_requests, per }
+    }
+}
+
+/// 租户调用统计快照
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantStats {
+    /// 应用 ID
+    pub app_id: String,
+    /// 租户 key
+    pub tenant_key: String,
+    /// 请求总数
+    pub requests: u64,
+    /// 网络/传输层错误数
+    pub errors: u64,
+    /// 接口返回非 0 `code` 的次数
+    pub api_errors: u64,
+    /// 因限流而等待的请求数
+    pub throttled: u64,
+    /// 平均耗时（含限流等待）
+    pub avg_latency: Duration,
+}
+
+/// 租户生命周期事件类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TenantEventKind {
+    /// 首次开通应用（`app_open`）
+    Installed,
+    /// 租户启用应用（`app_status_change`，`start_by_tenant`）
+    Enabled,
+    /// 租户停用或平台下架（`app_status_change`，`stop_by_tenant` / `stop_by_platform`）
+    Disabled {
+        /// 原始状态值
+        status: String,
+    },
+}
+
+/// 租户生命周期事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantEvent {
+    /// 应用 ID
+    pub app_id: String,
+    /// 租户 key
+    pub tenant_key: String,
+    /// 事件类型
+    pub kind: TenantEventKind,
+    /// 操作人（安装者）open_id
+    pub operator_open_id: Option<String>,
+}
+
+impl TenantEvent {
+    /// 从事件推送负载解析 `app_open` / `app_status_change`，其他事件返回 `None`
+    ///
+    /// 同时兼容 1.0（`event.type`）与 2.0（`header.event_type`）两种结构。
+    pub fn parse(payload: &[u8]) -> Option<Self> {
+        let value: Value = serde_json::from_slice(payload).ok()?;
+        let event = value.get("event")?;
+        let header = value.get("header");
+        let field = |name: &str| {
+            event
+                .get(name)
+                .or_else(|| header.and_then(|header| header.get(name)))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+
+        let event_type = header
+            .and_then(|header| header.get("event_type"))
+            .or_else(|| event.get("type"))
+            .and_then(Value::as_str)?;
+        let kind = match event_type {
+            "app_open" => TenantEventKind::Installed,
+            "app_status_change" => match event.get("status").and_then(Value::as_str)? {
+                "start_by_tenant" => TenantEventKind::Enabled,
+                status => TenantEventKind::Disabled {
+                    status: status.to_string(),
+                },
+            },
+            _ => return None,
+        };
+        let operator_open_id = ["installer", "operator"]
+            .iter()
+            .find_map(|key| event.get(key)?.get("open_id")?.as_str())
+            .map(str::to_string);
+
+        Some(Self {
+            app_id: field("app_id")?,
+            tenant_key: field("tenant_key")?,
+            kind,
+            operator_open_id,
+        })
+    }
+}
+
+type TenantHook = Arc<dyn Fn(&TenantEvent) + Send + Sync>;
+
+/// 多应用、多租户客户端池
+#[derive(Clone)]
+pub struct ClientPool {
+    inner: Arc<PoolInner>,
+}
+
+struct PoolInner {
+    http_client: reqwest::Client,
+    tenant_rate_limit: Option<RateLimit>,
+    apps: RwLock<HashMap<String, AppEntry>>,
+    hooks: RwLock<Vec<TenantHook>>,
+}
+
+struct AppEntry {
+    config: Arc<Config>,
+    registry: Arc<DefaultServiceRegistry>,
+    client: Client,
+    tenants: HashMap<String, TenantClient>,
+    metrics: HashMap<String, Arc<TenantMetrics>>,
+    rate_limits: HashMap<String, RateLimit>,
+}
+
+impl std::fmt::Debug for ClientPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientPool")
+            .field("apps", &self.app_ids())
+            .field("tenant_rate_limit", &self.inner.tenant_rate_limit)
+            .finish()
+    }
+}
+
+impl Default for ClientPool {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// [`ClientPool`] 构建器
+#[derive(Debug, Default)]
+pub struct ClientPoolBuilder {
+    http_client: Option<reqwest::Client>,
+    tenant_rate_limit: Option<RateLimit>,
+}
+
+impl ClientPoolBuilder {
+    /// 使用自定义 HTTP 客户端（所有应用、租户共享其连接池）
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// 每个租户的默认限流
+    pub fn tenant_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.tenant_rate_limit = Some(limit);
+        self
+    }
+
+    /// 构建客户端池
+    pub fn build(self) -> ClientPool {
+        ClientPool {
+            inner: Arc::new(PoolInner {
+                http_client: self.http_client.unwrap_or_default(),
+                tenant_rate_limit: self.tenant_rate_limit,
+                apps: RwLock::new(HashMap::new()),
+                hooks: RwLock::new(Vec::new()),
+            }),
+        }
+    }
+}
+
+impl ClientPool {
+    /// 创建构建器
+    pub fn builder() -> ClientPoolBuilder {
+        ClientPoolBuilder::default()
+    }
+
+    /// 注册应用；同一 `app_id` 重复注册时替换原配置并清空其租户视图
+    pub fn add_app(&self, config: Config) -> Result<()> {
+        config.validate()?;
+        let mut registry = DefaultServiceRegistry::new();
+        crate::registry::bootstrap::register_compiled_services(&mut registry)?;
+        let registry = Arc::new(registry);
+
+        let core_config = config.build_core_config_with_http_client(self.inner.http_client.clone());
+        let app_id = config.app_id.clone();
+        let config = Arc::new(config);
+        let client = Client::assemble(config.clone(), registry.clone(), core_config)?;
+        self.write_apps().insert(
+            app_id,
+            AppEntry {
+                config,
+                registry,
+                client,
+                tenants: HashMap::new(),
+                metrics: HashMap::new(),
+                rate_limits: HashMap::new(),
+            },
+        );
+        Ok(())
+    }
+
+    /// 移除应用，返回是否存在
+    pub fn remove_app(&self, app_id: &str) -> bool {
+        self.write_apps().remove(app_id).is_some()
+    }
+
+    /// 已注册的应用 ID
+    pub fn app_ids(&self) -> Vec<String> {
+        let mut ids: Vec<_> = self.read_apps().keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// 应用级客户端（不绑定租户，适用于自建应用或应用维度的接口）
+    pub fn app(&self, app_id: &str) -> Result<Client> {
+        self.read_apps()
+            .get(app_id)
+            .map(|entry| entry.client.clone())
+            .ok_or_else(|| unknown_app(app_id))
+    }
+
+    /// 获取租户视图，首次访问时创建
+    pub fn tenant(&self, app_id: &str, tenant_key: &str) -> Result<TenantClient> {
+        if let Some(tenant) = self
+            .read_apps()
+            .get(app_id)
+            .and_then(|entry| entry.tenants.get(tenant_key))
+        {
+            return Ok(tenant.clone());
+        }
+
+        let mut apps = self.write_apps();
+        let entry = apps.get_mut(app_id).ok_or_else(|| unknown_app(app_id))?;
+        if let Some(tenant) = entry.tenants.get(tenant_key) {
+            return Ok(tenant.clone());
+        }
+        let metrics = entry
+            .metrics
+            .entry(tenant_key.to_string())
+            .or_default()
+            .clone();
+        let limit = entry
+            .rate_limits
+            .get(tenant_key)
+            .copied()
+            .or(self.inner.tenant_rate_limit);
+        let core_config = entry
+            .client
+            .api_config()
+            .with_tenant_key(tenant_key)
+            .with_middleware(TenantMiddleware {
+                limiter: limit.map(RateLimiter::new),
+                metrics: metrics.clone(),
+            });
+        let tenant = TenantClient {
+            app_id: app_id.to_string(),
+            tenant_key: tenant_key.to_string(),
+            client: Client::assemble(entry.config.clone(), entry.registry.clone(), core_config)?,
+            metrics,
+        };
+        entry.tenants.insert(tenant_key.to_string(), tenant.clone());
+        Ok(tenant)
+    }
+
+    /// 单独设置某个租户的限流，覆盖池级默认值
+    ///
+    /// 已发放的租户视图保持原限流，之后通过 [`Self::tenant`] 获取的视图生效。
+    pub fn set_tenant_rate_limit(
+        &self,
+        app_id: &str,
+        tenant_key: &str,
+        limit: RateLimit,
+    ) -> Result<()> {
+        let mut apps = self.write_apps();
+        let entry = apps.get_mut(app_id).ok_or_else(|| unknown_app(app_id))?;
+        entry.rate_limits.insert(tenant_key.to_string(), limit);
+        entry.tenants.remove(tenant_key);
+        Ok(())
+    }
+
+    /// 当前缓存了视图的租户
+    pub fn tenants(&self, app_id: &str) -> Vec<String> {
+        let mut keys: Vec<_> = self
+            .read_apps()
+            .get(app_id)
+            .map(|entry| entry.tenants.keys().cloned().collect())
+            .unwrap_or_default();
+        keys.sort();
+        keys
+    }
+
+    /// 移除租户视图（统计数据保留），返回是否存在
+    pub fn evict_tenant(&self, app_id: &str, tenant_key: &str) -> bool {
+        self.write_apps()
+            .get_mut(app_id)
+            .and_then(|entry| entry.tenants.remove(tenant_key))
+            .is_some()
+    }
+
+    /// 所有租户的调用统计
+    pub fn stats(&self) -> Vec<TenantStats> {
+        let apps = self.read_apps();
+        let mut stats: Vec<_> = apps
+            .iter()
+            .flat_map(|(app_id, entry)| {
+                entry
+                    .metrics
+                    .iter()
+                    .map(move |(tenant_key, metrics)| metrics.snapshot(app_id, tenant_key))
+            })
+            .collect();
+        stats.sort_by(|a, b| (&a.app_id, &a.tenant_key).cmp(&(&b.app_id, &b.tenant_key)));
+        stats
+    }
+
+    /// 注册租户生命周期钩子
+    pub fn on_tenant_event<F>(&self, hook: F)
+    where
+        F: Fn(&TenantEvent) + Send + Sync + 'static,
+    {
+        self.inner
+            .hooks
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(Arc::new(hook));
+    }
+
+    /// 处理事件推送负载
+    ///
+    /// 识别 `app_open` / `app_status_change`：开通或启用时预建租户视图，停用时移除租户视图，
+    /// 随后调用已注册的钩子。可在长连接或 Webhook 的原始事件处理器中直接调用。
+    pub fn handle_event(&self, payload: &[u8]) -> Result<Option<TenantEvent>> {
+        let Some(event) = TenantEvent::parse(payload) else {
+            return Ok(None);
+        };
+        let known_app = self.read_apps().contains_key(&event.app_id);
+        if known_app {
+            match event.kind {
+                TenantEventKind::Installed | TenantEventKind::Enabled => {
+                    self.tenant(&event.app_id, &event.tenant_key)?;
+                }
+                TenantEventKind::Disabled { .. } => {
+                    self.evict_tenant(&event.app_id, &event.tenant_key);
+                }
+            }
+        }
+
+        let hooks = self
+            .inner
+            .hooks
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        for hook in hooks {
+            hook(&event);
+        }
+        Ok(Some(event))
+    }
+
+    fn read_apps(&self) -> std::sync::RwLockReadGuard<'_, HashMap<String, AppEntry>> {
+        self.inner
+            .apps
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write_apps(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<String, AppEntry>> {
+        self.inner
+            .apps
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn unknown_app(app_id: &str) -> CoreError {
+    validation_error("app_id", format!("应用 {app_id} 未注册到 ClientPool"))
+}
+
+/// 绑定租户的客户端视图
+///
+/// 解引用为 [`Client`]，所有调用自动携带 `tenant_key` 并计入该租户的限流与统计。
+#[derive(Clone)]
+pub struct TenantClient {
+    app_id: String,
+    tenant_key: String,
+    client: Client,
+    metrics: Arc<TenantMetrics>,
+}
+
+impl std::fmt::Debug for TenantClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TenantClient")
+            .field("app_id", &self.app_id)
+            .field("tenant_key", &self.tenant_key)
+            .finish()
+    }
+}
+
+impl TenantClient {
+    /// 应用 ID
+    pub fn app_id(&self) -> &str {
+        &self.app_id
+    }
+
+    /// 租户 key
+    pub fn tenant_key(&self) -> &str {
+        &self.tenant_key
+    }
+
+    /// 底层客户端
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// 当前调用统计
+    pub fn stats(&self) -> TenantStats {
+        self.metrics.snapshot(&self.app_id, &self.tenant_key)
+    }
+}
+
+impl Deref for TenantClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self.client
+    }
+}
+
+#[derive(Debug, Default)]
+struct TenantMetrics {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    api_errors: AtomicU64,
+    throttled: AtomicU64,
+    latency_micros: AtomicU64,
+}
+
+impl TenantMetrics {
+    fn snapshot(&self, app_id: &str, tenant_key: &str) -> TenantStats {
+        let requests = self.requests.load(Ordering::Relaxed);
+        let latency = self.latency_micros.load(Ordering::Relaxed);
+        TenantStats {
+            app_id: app_id.to_string(),
+            tenant_key: tenant_key.to_string(),
+            requests,
+            errors: self.errors.load(Ordering::Relaxed),
+            api_errors: self.api_errors.load(Ordering::Relaxed),
+            throttled: self.throttled.load(Ordering::Relaxed),
+            avg_latency: Duration::from_micros(latency.checked_div(requests).unwrap_or(0)),
+        }
+    }
+}
+
+/// 滑动窗口限流器
+#[derive(Debug)]
+struct RateLimiter {
+    limit: RateLimit,
+    sent: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            sent: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// 获取一次发送许可，返回是否发生了等待
+    async fn acquire(&self) -> bool {
+        let mut waited = false;
+        loop {
+            let wait = {
+                let mut sent = self
+                    .sent
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                let now = Instant::now();
+                while sent
+                    .front()
+                    .is_some_and(|first| now.duration_since(*first) >= self.limit.per)
+                {
+                    sent.pop_front();
+                }
+                if sent.len() < self.limit.max_requests.max(1) as usize {
+                    sent.push_back(now);
+                    return waited;
+                }
+                self.limit.per - now.duration_since(sent[0])
+            };
+            waited = true;
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 租户限流与统计中间件
+#[derive(Debug)]
+struct TenantMiddleware {
+    limiter: Option<RateLimiter>,
+    metrics: Arc<TenantMetrics>,
+}
+
+impl Middleware for TenantMiddleware {
+    fn name(&self) -> &str {
+        "tenant"
+    }
+
+    fn before_request<'a>(
+        &'a self,
+        ctx: &'a mut RequestContext,
+    ) -> MiddlewareFuture<'a, SDKResult<Option<RawResponse>>> {
+        let _ = ctx;
+        Box::pin(async move {
+            self.metrics.requests.fetch_add(1, Ordering::Relaxed);
+            if let Some(limiter) = &self.limiter
+                && limiter.acquire().await
+            {
+                self.metrics.throttled.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(None)
+        })
+    }
+
+    fn after_response<'a>(
+        &'a self,
+        ctx: &'a RequestContext,
+        result: std::result::Result<&'a RawResponse, &'a CoreError>,
+    ) -> MiddlewareFuture<'a, SDKResult<()>> {
+        Box::pin(async move {
+            self.metrics
+                .latency_micros
+                .fetch_add(ctx.elapsed().as_micros() as u64, Ordering::Relaxed);
+            match result {
+                Ok(raw) if !raw.is_success() => {
+                    self.metrics.api_errors.fetch_add(1, Ordering::Relaxed);
+                }
+                Ok(_) => {}
+                Err(_) => {
+                    self.metrics.errors.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use crate::raw::HttpMethod;
+    use openlark_core::constants::AppType;
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_partial_json, header, method, path},
+    };
+
+    fn app_config(server: &MockServer, app_id: &str) -> Config {
+        let mut config = Config::builder()
+            .app_id(app_id)
+            .app_secret("secret")
+            .base_url(server.uri())
+            .build_unvalidated();
+        config.allow_custom_base_url = true;
+        config
+    }
+
+    async fn mount_tenant_tokens(server: &MockServer) {
+        for tenant in ["t1", "t2"] {
+            Mock::given(method("POST"))
+                .and(path("/open-apis/auth/v3/tenant_access_token/internal"))
+                .and(body_partial_json(
+                    json!({"app_id": format!("cli_{tenant}")}),
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "code": 0,
+                    "tenant_access_token": format!("token-{tenant}"),
+                    "expire": 7200
+                })))
+                .mount(server)
+                .await;
+        }
+    }
+
+    #[test]
+    fn test_parse_lifecycle_events() {
+        let open = json!({
+            "type": "event_callback",
+            "event": {
+                "app_id": "cli_a",
+                "tenant_key": "tk",
+                "type": "app_open",
+                "installer": {"open_id": "ou_1"}
+            }
+        });
+        let event = TenantEvent::parse(open.to_string().as_bytes()).unwrap();
+        assert_eq!(event.kind, TenantEventKind::Installed);
+        assert_eq!(event.operator_open_id.as_deref(), Some("ou_1"));
+
+        let stop = json!({
+            "schema": "2.0",
+            "header": {"event_type": "app_status_change", "app_id": "cli_a", "tenant_key": "tk"},
+            "event": {"status": "stop_by_platform"}
+        });
+        let event = TenantEvent::parse(stop.to_string().as_bytes()).unwrap();
+        assert_eq!(
+            event.kind,
+            TenantEventKind::Disabled {
+                status: "stop_by_platform".to_string()
+            }
+        );
+
+        let other = json!({"header": {"event_type": "im.message.receive_v1"}, "event": {}});
+        assert!(TenantEvent::parse(other.to_string().as_bytes()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tenant_views_share_apps_and_track_stats() {
+        let server = MockServer::start().await;
+        mount_tenant_tokens(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/ok"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 0, "data": {}})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/fail"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 1, "msg": "no"})))
+            .mount(&server)
+            .await;
+
+        let pool = ClientPool::default();
+        pool.add_app(app_config(&server, "cli_t1")).unwrap();
+        pool.add_app(app_config(&server, "cli_t2")).unwrap();
+        assert_eq!(pool.app_ids(), vec!["cli_t1", "cli_t2"]);
+        assert!(pool.tenant("cli_missing", "x").is_err());
+
+        let tenant = pool.tenant("cli_t1", "tenant_a").unwrap();
+        assert_eq!(tenant.api_config().tenant_key(), Some("tenant_a"));
+        let _: Value = tenant.raw(HttpMethod::Get, "ok").send().await.unwrap();
+        assert!(
+            tenant
+                .raw(HttpMethod::Get, "fail")
+                .send::<Value>()
+                .await
+                .is_err()
+        );
+
+        let other = pool.tenant("cli_t2", "tenant_b").unwrap();
+        let _: Value = other.raw(HttpMethod::Get, "ok").send().await.unwrap();
+
+        let stats = tenant.stats();
+        assert_eq!((stats.requests, stats.api_errors, stats.errors), (2, 1, 0));
+        assert_eq!(pool.stats().len(), 2);
+        assert_eq!(pool.tenants("cli_t1"), vec!["tenant_a"]);
+
+        let requests = server.received_requests().await.unwrap();
+        let authorizations: Vec<_> = requests
+            .iter()
+            .filter(|request| request.url.path() == "/open-apis/ok")
+            .filter_map(|request| request.headers.get("authorization"))
+            .map(|value| value.to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(authorizations, vec!["Bearer token-t1", "Bearer token-t2"]);
+    }
+
+    #[tokio::test]
+    async fn test_marketplace_tenant_key_injected() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/ok"))
+            .and(header("Authorization", "Bearer explicit"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 0, "data": {}})))
+            .mount(&server)
+            .await;
+
+        let mut config = app_config(&server, "cli_market");
+        config.app_type = AppType::Marketplace;
+        let pool = ClientPool::default();
+        pool.add_app(config).unwrap();
+
+        // 未绑定租户时，商店应用的 tenant 调用缺少 tenant_key
+        let app = pool.app("cli_market").unwrap();
+        let option = openlark_core::req_option::RequestOption::builder()
+            .tenant_access_token("explicit")
+            .build();
+        assert!(
+            app.raw(HttpMethod::Get, "ok")
+                .request_option(option.clone())
+                .send::<Value>()
+                .await
+                .is_err()
+        );
+
+        let tenant = pool.tenant("cli_market", "tk").unwrap();
+        let _: Value = tenant
+            .raw(HttpMethod::Get, "ok")
+            .request_option(option)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_and_lifecycle_hooks() {
+        let server = MockServer::start().await;
+        mount_tenant_tokens(&server).await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 0, "data": {}})))
+            .mount(&server)
+            .await;
+
+        let pool = ClientPool::builder()
+            .tenant_rate_limit(RateLimit::new(1, Duration::from_millis(200)))
+            .build();
+        pool.add_app(app_config(&server, "cli_t1")).unwrap();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        pool.on_tenant_event(move |event| sink.lock().unwrap().push(event.kind.clone()));
+
+        let open = json!({"event": {"app_id": "cli_t1", "tenant_key": "tk", "type": "app_open"}});
+        pool.handle_event(open.to_string().as_bytes()).unwrap();
+        assert_eq!(pool.tenants("cli_t1"), vec!["tk"]);
+
+        let tenant = pool.tenant("cli_t1", "tk").unwrap();
+        let started = Instant::now();
+        for _ in 0..2 {
+            let _: Value = tenant.raw(HttpMethod::Get, "ok").send().await.unwrap();
+        }
+        assert!(started.elapsed() >= Duration::from_millis(150));
+        assert_eq!(tenant.stats().throttled, 1);
+
+        let stop = json!({"event": {
+            "app_id": "cli_t1", "tenant_key": "tk",
+            "type": "app_status_change", "status": "stop_by_tenant"
+        }});
+        pool.handle_event(stop.to_string().as_bytes()).unwrap();
+        assert!(pool.tenants("cli_t1").is_empty());
+        assert_eq!(pool.stats()[0].requests, 2);
+        assert_eq!(seen.lock().unwrap().len(), 2);
+    }
+}
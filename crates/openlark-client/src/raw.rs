@@ -0,0 +1,516 @@
+//! 原始 API 调用
+//!
+//! SDK 尚未封装的接口可以通过 [`Client::raw`] 直接调用。请求与类型化接口走同一条
+//! `Transport` 链路：令牌注入、中间件（追踪及自定义的重试、限流等）、录制回放与响应元信息都保持一致。
+//!
+//! ```rust,no_run
+//! use openlark_client::{Client, raw::HttpMethod};
+//! use serde_json::{Value, json};
+//!
+//! # async fn demo(client: &Client) -> openlark_client::Result<()> {
+//! let chat: Value = client
+//!     .raw(HttpMethod::Get, "/open-apis/im/v1/chats/:chat_id")
+//!     .path_param("chat_id", "oc_xxx")
+//!     .query("user_id_type", "open_id")
+//!     .send()
+//!     .await?;
+//!
+//! let file = client
+//!     .raw(HttpMethod::Get, "/open-apis/drive/v1/files/:file_token/download")
+//!     .path_param("file_token", "box_xxx")
+//!     .download()
+//!     .await?;
+//! let bytes = file.bytes().await?;
+//! # let _ = (chat, bytes, json!({}));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use openlark_core::{
+    api::{ApiRequest, ApiResponseTrait, RequestData, Response, ResponseFormat},
+    config::Config,
+    constants::AccessTokenType,
+    error::validation_error,
+    http::Transport,
+    req_option::{RequestOption, RequestOptionBuilder},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::{Client, Result};
+
+pub use openlark_core::api::{ByteStream, HttpMethod, ResponseMeta};
+
+impl Client {
+    /// 构建一个原始 API 请求
+    ///
+    /// `path` 可以带 `:name` 形式的路径参数（由 [`RawRequest::path_param`] 填充），
+    /// 未以 `/open-apis` 开头时会自动补全。
+    pub fn raw(&self, method: HttpMethod, path: impl Into<String>) -> RawRequest {
+        RawRequest::new(self.api_config().clone(), method, path)
+    }
+}
+
+/// 原始 API 请求构建器
+pub struct RawRequest {
+    config: Config,
+    method: HttpMethod,
+    path: String,
+    path_params: Vec<(String, String)>,
+    query: Vec<(String, String)>,
+    body: Option<RequestData>,
+    file: Option<Vec<u8>>,
+    token_types: Option<Vec<AccessTokenType>>,
+    timeout: Option<Duration>,
+    flatten: bool,
+    option: RequestOptionBuilder,
+}
+
+impl std::fmt::Debug for RawRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RawRequest")
+            .field("method", &self.method)
+            .field("path", &self.path)
+            .field("path_params", &self.path_params)
+            .field("query", &self.query)
+            .field("token_types", &self.token_types)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RawRequest {
+    /// 基于 core 配置创建请求
+    pub fn new(config: Config, method: HttpMethod, path: impl Into<String>) -> Self {
+        Self {
+            config,
+            method,
+            path: path.into(),
+            path_params: Vec::new(),
+            query: Vec::new(),
+            body: None,
+            file: None,
+            token_types: None,
+            timeout: None,
+            flatten: false,
+            option: RequestOptionBuilder::default(),
+        }
+    }
+
+    /// 填充 `:name` 路径参数，值会做 URL 编码
+    pub fn path_param(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.path_params.push((name.into(), value.to_string()));
+        self
+    }
+
+    /// 添加查询参数
+    pub fn query(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.query.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// 添加可选查询参数，`None` 时忽略
+    pub fn query_opt(self, key: impl Into<String>, value: Option<impl ToString>) -> Self {
+        match value {
+            Some(value) => self.query(key, value),
+            None => self,
+        }
+    }
+
+    /// 添加请求头
+    pub fn header(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.option = self.option.add_header(key, value);
+        self
+    }
+
+    /// 设置 JSON 请求体
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Result<Self> {
+        self.body = Some(RequestData::Json(serde_json::to_value(body)?));
+        Ok(self)
+    }
+
+    /// 设置 multipart 请求体
+    ///
+    /// `fields` 为表单字段（JSON 对象），文件部分的字段名为 `file`。
+    pub fn multipart(
+        mut self,
+        fields: Value,
+        file_name: impl Into<String>,
+        content: Vec<u8>,
+    ) -> Result<Self> {
+        let Value::Object(mut fields) = fields else {
+            return Err(validation_error(
+                "fields",
+                "multipart 表单字段必须是 JSON 对象",
+            ));
+        };
+        fields.insert("__file_name".to_string(), Value::String(file_name.into()));
+        self.body = Some(RequestData::Json(Value::Object(fields)));
+        self.file = Some(content);
+        self.option = self.option.file_upload(true);
+        Ok(self)
+    }
+
+    /// 设置二进制请求体
+    pub fn binary(mut self, content: Vec<u8>, content_type: impl ToString) -> Self {
+        self.body = Some(RequestData::Binary(content));
+        self.option = self.option.add_header("Content-Type", content_type);
+        self
+    }
+
+    /// 以 `tenant_access_token` 调用（默认），商店应用需提供租户 key
+    pub fn tenant_key(mut self, tenant_key: impl ToString) -> Self {
+        self.option = self.option.tenant_key(tenant_key);
+        self
+    }
+
+    /// 以 `user_access_token` 调用
+    pub fn user_access_token(mut self, token: impl ToString) -> Self {
+        self.token_types = Some(vec![AccessTokenType::User]);
+        self.option = self.option.user_access_token(token);
+        self
+    }
+
+    /// 以 `app_access_token` 调用
+    pub fn app_token(mut self) -> Self {
+        self.token_types = Some(vec![AccessTokenType::App]);
+        self
+    }
+
+    /// 不携带访问令牌
+    pub fn no_token(mut self) -> Self {
+        self.token_types = Some(vec![AccessTokenType::None]);
+        self
+    }
+
+    /// 响应字段与 `code`/`msg` 同级（如获取令牌类接口），按整个响应体反序列化
+    pub fn flatten(mut self) -> Self {
+        self.flatten = true;
+        self
+    }
+
+    /// 设置请求超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 在已有请求选项基础上继续设置（请求 ID、显式令牌等）
+    pub fn request_option(mut self, option: RequestOption) -> Self {
+        self.option = RequestOptionBuilder::from(option);
+        self
+    }
+
+    /// 发送请求并把 `data` 反序列化为 `T`
+    ///
+    /// 接口返回非 0 `code` 时返回错误；`T` 可以是 [`Value`]。
+    pub async fn send<T: DeserializeOwned>(self) -> Result<T> {
+        self.send_with_meta().await.map(|(data, _)| data)
+    }
+
+    /// 发送请求，同时返回响应元信息（`X-Tt-Logid`、限流信息等）
+    pub async fn send_with_meta<T: DeserializeOwned>(self) -> Result<(T, ResponseMeta)> {
+        let flatten = self.flatten;
+        let (request, config, option) = self.into_parts()?;
+        let (data, meta) = if flatten {
+            let response: Response<FlattenValue> =
+                Transport::request(request, &config, Some(option)).await?;
+            let (data, meta) = into_data(response)?;
+            (data.map(|data| data.0), meta)
+        } else {
+            let response: Response<Value> =
+                Transport::request(request, &config, Some(option)).await?;
+            into_data(response)?
+        };
+        Ok((serde_json::from_value(data.unwrap_or(Value::Null))?, meta))
+    }
+
+    /// 发送请求并以流的形式读取响应体，适用于文件下载
+    pub async fn download(self) -> Result<ByteStream> {
+        let (request, config, option) = self.into_parts()?;
+        Transport::<ByteStream>::request_stream(request, &config, Some(option)).await
+    }
+
+    fn into_parts(self) -> Result<(ApiRequest<()>, Config, RequestOption)> {
+        let url = self.resolve_path()?;
+        let mut request: ApiRequest<()> = match self.method {
+            HttpMethod::Get => ApiRequest::get(url),
+            HttpMethod::Post => ApiRequest::post(url),
+            HttpMethod::Put => ApiRequest::put(url),
+            HttpMethod::Patch => ApiRequest::patch(url),
+            HttpMethod::Delete => ApiRequest::delete(url),
+            other => {
+                return Err(validation_error(
+                    "method",
+                    format!("不支持的 HTTP 方法: {other}"),
+                ));
+            }
+        };
+        for (key, value) in self.query {
+            request = request.query(key, value);
+        }
+        if let Some(body) = self.body {
+            request = request.body(body);
+        }
+        if let Some(file) = self.file {
+            request = request.file_content(file);
+        }
+        if let Some(token_types) = self.token_types {
+            request = request.access_token_types(token_types);
+        }
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        Ok((request, self.config, self.option.build()))
+    }
+
+    /// 补全 `/open-apis` 前缀并替换 `:name` 路径参数
+    fn resolve_path(&self) -> Result<String> {
+        let path = self.path.trim_start_matches('/');
+        let path = if path.starts_with("open-apis/") {
+            format!("/{path}")
+        } else {
+            format!("/open-apis/{path}")
+        };
+
+        let mut segments = Vec::new();
+        for segment in path.split('/') {
+            match segment.strip_prefix(':') {
+                Some(name) => {
+                    let value = self
+                        .path_params
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value)
+                        .ok_or_else(|| {
+                            validation_error("path", format!("缺少路径参数 `{name}`: {path}"))
+                        })?;
+                    segments.push(urlencoding::encode(value).into_owned());
+                }
+                None => segments.push(segment.to_string()),
+            }
+        }
+        Ok(segments.join("/"))
+    }
+}
+
+/// 按整个响应体反序列化的 JSON
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+struct FlattenValue(Value);
+
+impl ApiResponseTrait for FlattenValue {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Flatten
+    }
+}
+
+/// 取出 `data`；成功但没有 `data` 字段时返回 `None`
+fn into_data<T: ApiResponseTrait>(response: Response<T>) -> Result<(Option<T>, ResponseMeta)> {
+    if !response.is_success() {
+        return response
+            .into_result_with_meta()
+            .map(|(data, meta)| (Some(data), meta));
+    }
+    Ok((response.data, response.meta))
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_json, header, method, path, query_param},
+    };
+
+    fn client(server: &MockServer) -> Client {
+        let mut config = crate::Config::builder()
+            .app_id("cli_test")
+            .app_secret("secret")
+            .base_url(server.uri())
+            .build_unvalidated();
+        config.allow_custom_base_url = true;
+        Client::with_config(config).unwrap()
+    }
+
+    async fn mount_token(server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/open-apis/auth/v3/tenant_access_token/internal"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "msg": "ok",
+                "tenant_access_token": "t-raw",
+                "expire": 7200
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn test_resolve_path() {
+        let config = Config::builder().app_id("a").app_secret("s").build();
+        let request = RawRequest::new(config.clone(), HttpMethod::Get, "im/v1/chats/:chat_id")
+            .path_param("chat_id", "oc 1/2");
+        assert_eq!(
+            request.resolve_path().unwrap(),
+            "/open-apis/im/v1/chats/oc%201%2F2"
+        );
+
+        let missing = RawRequest::new(config, HttpMethod::Get, "/open-apis/x/:id");
+        assert!(missing.resolve_path().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_typed_with_tenant_token() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/im/v1/messages"))
+            .and(query_param("receive_id_type", "chat_id"))
+            .and(header("Authorization", "Bearer t-raw"))
+            .and(header("X-Custom", "1"))
+            .and(body_json(json!({"receive_id": "oc_1", "msg_type": "text"})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-Tt-Logid", "log-raw")
+                    .set_body_json(json!({"code": 0, "msg": "ok", "data": {"message_id": "om_1"}})),
+            )
+            .mount(&server)
+            .await;
+
+        #[derive(Deserialize)]
+        struct Sent {
+            message_id: String,
+        }
+
+        let (sent, meta): (Sent, _) = client(&server)
+            .raw(HttpMethod::Post, "/open-apis/im/v1/messages")
+            .query("receive_id_type", "chat_id")
+            .header("X-Custom", "1")
+            .json(&json!({"receive_id": "oc_1", "msg_type": "text"}))
+            .unwrap()
+            .send_with_meta()
+            .await
+            .unwrap();
+        assert_eq!(sent.message_id, "om_1");
+        assert_eq!(meta.log_id.as_deref(), Some("log-raw"));
+    }
+
+    #[tokio::test]
+    async fn test_api_error_and_user_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/authen/v1/user_info"))
+            .and(header("Authorization", "Bearer u-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"code": 99991668, "msg": "invalid token"})),
+            )
+            .mount(&server)
+            .await;
+
+        let err = client(&server)
+            .raw(HttpMethod::Get, "authen/v1/user_info")
+            .user_access_token("u-1")
+            .send::<Value>()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid token"));
+    }
+
+    #[tokio::test]
+    async fn test_flatten_without_token() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+
+        let body: Value = client(&server)
+            .raw(HttpMethod::Post, "auth/v3/tenant_access_token/internal")
+            .no_token()
+            .flatten()
+            .json(&json!({"app_id": "cli_test", "app_secret": "secret"}))
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(body["tenant_access_token"], "t-raw");
+    }
+
+    #[tokio::test]
+    async fn test_download_stream() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/drive/v1/files/box_1/download"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Type", "application/pdf")
+                    .set_body_bytes(vec![7_u8; 4096]),
+            )
+            .mount(&server)
+            .await;
+
+        let mut stream = client(&server)
+            .raw(HttpMethod::Get, "drive/v1/files/:file_token/download")
+            .path_param("file_token", "box_1")
+            .download()
+            .await
+            .unwrap();
+        assert_eq!(stream.content_type(), Some("application/pdf"));
+        let mut total = 0;
+        while let Some(chunk) = stream.chunk().await.unwrap() {
+            total += chunk.len();
+        }
+        assert_eq!(total, 4096);
+    }
+
+    #[tokio::test]
+    async fn test_multipart_and_binary() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/im/v1/files"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"code": 0, "msg": "ok", "data": {"file_key": "file_1"}})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/open-apis/upload"))
+            .and(header("Content-Type", "image/png"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 0, "msg": "ok"})))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let uploaded: Value = client
+            .raw(HttpMethod::Post, "im/v1/files")
+            .multipart(json!({"file_type": "stream"}), "a.txt", b"hello".to_vec())
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(uploaded["file_key"], "file_1");
+
+        let received = server.received_requests().await.unwrap();
+        let upload = received
+            .iter()
+            .find(|request| request.url.path() == "/open-apis/im/v1/files")
+            .unwrap();
+        let body = String::from_utf8_lossy(&upload.body);
+        assert!(body.contains("name=\"file_type\""));
+        assert!(body.contains("filename=\"a.txt\""));
+        assert!(!body.contains("__file_name"));
+
+        let empty: Value = client
+            .raw(HttpMethod::Put, "upload")
+            .binary(vec![1, 2, 3], "image/png")
+            .send()
+            .await
+            .unwrap();
+        assert!(empty.is_null());
+    }
+}
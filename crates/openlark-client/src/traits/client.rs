@@ -87,6 +87,7 @@ mod tests {
             enable_log: true,
             headers: std::collections::HashMap::new(),
             max_response_size: 100 * 1024 * 1024,
+            app_ticket_store: None,
             core_config: None,
         };
 
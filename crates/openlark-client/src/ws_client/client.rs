@@ -107,7 +107,11 @@ impl FramePackageBuffer {
 
 #[derive(Debug, Deserialize)]
 struct RawEventEnvelope {
-    header: RawEventHeader,
+    #[serde(default)]
+    header: Option<RawEventHeader>,
+    /// 1.0 结构的事件（例如 `app_ticket`）类型位于 `event.type`
+    #[serde(default)]
+    event: Option<RawEventBody>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -116,6 +120,12 @@ struct RawEventHeader {
     event_type: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct RawEventBody {
+    #[serde(default, rename = "type")]
+    event_type: Option<String>,
+}
+
 /// 原始事件处理器。
 ///
 /// 当调用方希望直接消费 WebSocket 原始事件负载时，可以实现该 trait，
@@ -128,6 +138,26 @@ pub trait EventHandler: Send + Sync + 'static {
     fn handle(&self, payload: &[u8]) -> EventHandlerResult;
 }
 
+/// 捕获长连接推送的 `app_ticket` 并写入票据存储。
+///
+/// ```rust,ignore
+/// let handler = EventDispatcherHandler::builder()
+///     .register_raw("app_ticket", AppTicketHandler::from_config(client.core_config())?)?
+///     .build();
+/// ```
+impl EventHandler for openlark_core::auth::AppTicketHandler {
+    fn handle(&self, payload: &[u8]) -> EventHandlerResult {
+        let handler = self.clone();
+        let payload = payload.to_vec();
+        tokio::spawn(async move {
+            if let Err(err) = handler.handle(&payload).await {
+                error!("保存 app_ticket 失败: {err}");
+            }
+        });
+        Ok(())
+    }
+}
+
 /// WebSocket 事件分发处理器。
 ///
 /// 目前支持两类分发目标：
@@ -201,7 +231,13 @@ impl EventDispatcherHandler {
     fn extract_event_type(payload: &[u8]) -> Option<String> {
         serde_json::from_slice::<RawEventEnvelope>(payload)
             .ok()
-            .map(|event| event.header.event_type)
+            .and_then(|event| {
+                event
+                    .header
+                    .map(|header| header.event_type)
+                    .filter(|event_type| !event_type.trim().is_empty())
+                    .or_else(|| event.event.and_then(|body| body.event_type))
+            })
             .filter(|event_type| !event_type.trim().is_empty())
     }
 
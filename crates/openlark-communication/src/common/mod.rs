@@ -4,4 +4,10 @@
 
 pub mod api_utils;
 pub mod chain;
+#[cfg(feature = "contact")]
+pub mod directory_sync;
+#[cfg(feature = "contact")]
+pub mod identity;
 pub mod models;
+#[cfg(feature = "contact")]
+pub mod org_chart;
@@ -89,11 +89,8 @@ impl BatchGetDepartmentsRequest {
 
         // url: GET:/open-apis/contact/v3/departments/batch
         let mut req: ApiRequest<BatchGetDepartmentsResponse> =
-            ApiRequest::get(CONTACT_V3_DEPARTMENTS_BATCH);
-
-        for department_id in self.department_ids {
-            req = req.query("department_ids", department_id);
-        }
+            ApiRequest::get(CONTACT_V3_DEPARTMENTS_BATCH)
+                .query_repeated("department_ids", self.department_ids);
         if let Some(department_id_type) = self.department_id_type {
             req = req.query("department_id_type", department_id_type.as_str());
         }
@@ -108,11 +108,8 @@ impl BatchGetUsersRequest {
         validate_required_list!(self.user_ids, 50, "user_ids 不能为空");
 
         // url: GET:/open-apis/contact/v3/users/batch
-        let mut req: ApiRequest<BatchGetUsersResponse> = ApiRequest::get(CONTACT_V3_USERS_BATCH);
-
-        for user_id in self.user_ids {
-            req = req.query("user_ids", user_id);
-        }
+        let mut req: ApiRequest<BatchGetUsersResponse> =
+            ApiRequest::get(CONTACT_V3_USERS_BATCH).query_repeated("user_ids", self.user_ids);
         if let Some(user_id_type) = self.user_id_type {
             req = req.query("user_id_type", user_id_type.as_str());
         }
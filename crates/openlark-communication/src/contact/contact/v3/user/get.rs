@@ -3,7 +3,8 @@
 //! docPath: https://open.feishu.cn/document/server-docs/contact-v3/user/get
 
 use openlark_core::{
-    SDKResult, api::ApiRequest, config::Config, http::Transport, validate_required,
+    SDKResult, api::ApiRequest, config::Config, http::Transport, identity::UserRef,
+    validate_required,
 };
 
 use crate::{
@@ -40,6 +41,8 @@ pub struct GetUserRequest {
     user_id_type: Option<UserIdType>,
     /// 部门 ID 类型。
     department_id_type: Option<DepartmentIdType>,
+    /// 通过 [`GetUserRequest::user`] 设置的用户引用。
+    user: Option<UserRef>,
 }
 
 impl GetUserRequest {
@@ -50,6 +53,7 @@ impl GetUserRequest {
             user_id: String::new(),
             user_id_type: None,
             department_id_type: None,
+            user: None,
         }
     }
 
@@ -59,6 +63,16 @@ impl GetUserRequest {
         self
     }
 
+    /// 使用类型化的用户引用同时设置用户 ID 与用户 ID 类型
+    ///
+    /// 邮箱、手机号与工号需先通过 `IdentityResolver` 解析，否则执行时返回校验错误。
+    pub fn user(mut self, user: UserRef) -> Self {
+        self.user_id = user.value().to_string();
+        self.user_id_type = UserIdType::from_kind(user.kind());
+        self.user = Some(user);
+        self
+    }
+
     /// 用户 ID 类型（查询参数，可选）
     pub fn user_id_type(mut self, user_id_type: UserIdType) -> Self {
         self.user_id_type = Some(user_id_type);
@@ -87,6 +101,9 @@ impl GetUserRequest {
     ) -> SDKResult<UserResponse> {
         // === 必填字段验证 ===
         validate_required!(self.user_id, "user_id 不能为空");
+        if let Some(user) = &self.user {
+            user.require_user_id_type()?;
+        }
 
         // url: GET:/open-apis/contact/v3/users/:user_id
         let mut req: ApiRequest<UserResponse> =
@@ -145,6 +162,16 @@ mod tests {
         assert_eq!(request.department_id_type, None);
     }
 
+    #[test]
+    fn test_get_user_request_with_user_ref() {
+        let request = GetUserRequest::new(Config::default()).user(UserRef::union_id("on_1"));
+        assert_eq!(request.user_id, "on_1");
+        assert_eq!(request.user_id_type, Some(UserIdType::UnionId));
+
+        let request = GetUserRequest::new(Config::default()).user(UserRef::email("a@b.com"));
+        assert_eq!(request.user_id_type, None);
+    }
+
     #[test]
     fn test_get_user_request_with_all_options() {
         let config = Config::default();
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 
+use openlark_core::identity::UserIdKind;
 use serde::{Deserialize, Serialize};
 
 /// 用户信息（字段随文档演进，未显式建模字段使用 `extra` 透传）
@@ -86,6 +87,16 @@ impl UserIdType {
             Self::UserId => "user_id",
         }
     }
+
+    /// 由用户标识类型转换；邮箱、手机号与工号返回 `None`。
+    pub fn from_kind(kind: UserIdKind) -> Option<Self> {
+        match kind {
+            UserIdKind::OpenId => Some(Self::OpenId),
+            UserIdKind::UnionId => Some(Self::UnionId),
+            UserIdKind::UserId => Some(Self::UserId),
+            _ => None,
+        }
+    }
 }
 
 /// 部门 ID 类型（查询参数）
@@ -7,3 +7,16 @@
 pub mod contact;
 pub mod contact_search;
 pub mod contact_user;
+
+pub use crate::common::directory_sync::{
+    ContactEvent, ContactEventChange, ContactScopeChange, CustomAttrSchema, CustomAttrValue,
+    DirectoryChange, DirectoryDepartment, DirectorySink, DirectorySnapshot, DirectorySync,
+    DirectoryUser, InMemoryDirectorySink, diff_directory,
+};
+pub use crate::common::identity::{
+    EmployeeNoLookup, IdentityFuture, IdentityResolver, UserIdentity,
+};
+pub use crate::common::org_chart::{
+    ContactOrgSource, OrgChart, OrgChartBuilder, OrgChartChange, OrgFuture, OrgMember, OrgNode,
+    OrgSource, OrgUnit,
+};
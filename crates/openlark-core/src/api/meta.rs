@@ -0,0 +1,127 @@
+//! 响应元信息捕获
+//!
+//! 大多数 Builder 的 `execute()` 只返回业务数据 `T`，HTTP 层信息在返回前就被丢弃。
+//! [`with_meta`] 在任务局部（task-local）范围内执行给定的 Future，记录其中
+//! `Transport` 发出的**最后一个**请求的 [`ResponseMeta`]：
+//!
+//! ```rust,ignore
+//! use openlark_core::api::with_meta;
+//!
+//! let (user, meta) = with_meta(builder.execute()).await?;
+//! // 或者
+//! let (user, meta) = builder.execute().with_meta().await?;
+//! println!("log_id = {:?}, rate_limit = {:?}", meta.log_id, meta.rate_limit);
+//! ```
+//!
+//! 失败时元信息附在错误上下文中，可通过 `CoreError::response_meta()` / `CoreError::log_id()` 读取。
+
+use std::{cell::RefCell, future::Future};
+
+use crate::{SDKResult, api::ResponseMeta};
+
+tokio::task_local! {
+    static LAST_META: RefCell<Option<ResponseMeta>>;
+}
+
+/// 记录当前任务中最近一次响应的元信息（不在 [`with_meta`] 范围内时忽略）
+pub(crate) fn record_meta(meta: &ResponseMeta) {
+    let _ = LAST_META.try_with(|slot| {
+        *slot.borrow_mut() = Some(meta.clone());
+    });
+}
+
+/// 执行 Future 并返回业务数据与最后一次请求的响应元信息
+///
+/// Future 内没有发出请求（例如参数校验失败）时，返回默认的 [`ResponseMeta`]。
+pub async fn with_meta<T, F>(future: F) -> SDKResult<(T, ResponseMeta)>
+where
+    F: Future<Output = SDKResult<T>>,
+{
+    let (result, meta) = LAST_META
+        .scope(RefCell::new(None), async move {
+            let result = future.await;
+            let meta = LAST_META.with(|slot| slot.borrow_mut().take());
+            (result, meta)
+        })
+        .await;
+
+    match result {
+        Ok(data) => Ok((data, meta.unwrap_or_default())),
+        Err(err) => match meta {
+            Some(meta) if err.response_meta().is_none() => Err(err.with_response_meta(meta)),
+            _ => Err(err),
+        },
+    }
+}
+
+/// 为返回 `SDKResult<T>` 的 Future 提供 `.with_meta()`
+pub trait WithMetaExt<T>: Future<Output = SDKResult<T>> + Sized {
+    /// 见 [`with_meta`]
+    fn with_meta(self) -> impl Future<Output = SDKResult<(T, ResponseMeta)>> + Send
+    where
+        Self: Send,
+        T: Send,
+    {
+        with_meta(self)
+    }
+}
+
+impl<T, F> WithMetaExt<T> for F where F: Future<Output = SDKResult<T>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::validation_error;
+
+    fn meta(status: u16) -> ResponseMeta {
+        ResponseMeta {
+            status,
+            log_id: Some(format!("log-{status}")),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_meta_keeps_last_response() {
+        let (value, captured) = with_meta(async {
+            record_meta(&meta(200));
+            record_meta(&meta(201));
+            Ok(7)
+        })
+        .await
+        .unwrap();
+        assert_eq!(value, 7);
+        assert_eq!(captured.status, 201);
+    }
+
+    #[tokio::test]
+    async fn test_with_meta_attaches_meta_to_error() {
+        let err = with_meta(async {
+            record_meta(&meta(500));
+            Err::<(), _>(validation_error("response", "bad"))
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(err.log_id(), Some("log-500"));
+        assert_eq!(err.ctx().request_id(), Some("log-500"));
+    }
+
+    #[tokio::test]
+    async fn test_with_meta_extension() {
+        let (_, captured) = async {
+            record_meta(&meta(204));
+            Ok(())
+        }
+        .with_meta()
+        .await
+        .unwrap();
+        assert_eq!(captured.status, 204);
+    }
+
+    #[tokio::test]
+    async fn test_record_outside_scope_is_ignored() {
+        record_meta(&meta(200));
+        let (_, captured) = with_meta(async { Ok(()) }).await.unwrap();
+        assert!(!captured.has_http());
+    }
+}
@@ -96,7 +96,10 @@ impl From<std::collections::HashMap<String, String>> for RequestData {
 }
 
 // 重新导出响应类型
-pub use responses::{ApiResponseTrait, BaseResponse, ErrorInfo, Response, ResponseFormat};
+pub use responses::{
+    ApiResponseTrait, BaseResponse, ErrorInfo, RateLimitInfo, Response, ResponseFormat,
+    ResponseMeta,
+};
 
 /// 简化的API请求结构
 #[derive(Debug, Clone)]
@@ -105,9 +108,11 @@ pub struct ApiRequest<R> {
     pub(crate) url: String,
     pub(crate) headers: HashMap<String, String>,
     pub(crate) query: HashMap<String, String>,
+    pub(crate) repeated_query: Vec<(String, String)>,
     pub(crate) body: Option<RequestData>,
     pub(crate) file: Option<Vec<u8>>,
     pub(crate) timeout: Option<Duration>,
+    pub(crate) access_token_types: Option<Vec<crate::constants::AccessTokenType>>,
     pub(crate) _phantom: std::marker::PhantomData<R>,
 }
 
@@ -119,9 +124,11 @@ impl<R> ApiRequest<R> {
             url: url.into(),
             headers: HashMap::new(),
             query: HashMap::new(),
+            repeated_query: Vec::new(),
             body: None,
             file: None,
             timeout: None,
+            access_token_types: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -133,9 +140,11 @@ impl<R> ApiRequest<R> {
             url: url.into(),
             headers: HashMap::new(),
             query: HashMap::new(),
+            repeated_query: Vec::new(),
             body: None,
             file: None,
             timeout: None,
+            access_token_types: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -147,9 +156,11 @@ impl<R> ApiRequest<R> {
             url: url.into(),
             headers: HashMap::new(),
             query: HashMap::new(),
+            repeated_query: Vec::new(),
             body: None,
             file: None,
             timeout: None,
+            access_token_types: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -161,9 +172,11 @@ impl<R> ApiRequest<R> {
             url: url.into(),
             headers: HashMap::new(),
             query: HashMap::new(),
+            repeated_query: Vec::new(),
             body: None,
             file: None,
             timeout: None,
+            access_token_types: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -175,9 +188,11 @@ impl<R> ApiRequest<R> {
             url: url.into(),
             headers: HashMap::new(),
             query: HashMap::new(),
+            repeated_query: Vec::new(),
             body: None,
             file: None,
             timeout: None,
+            access_token_types: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -202,6 +217,19 @@ impl<R> ApiRequest<R> {
         self
     }
 
+    /// 添加可重复的查询参数（例如 `user_ids=a&user_ids=b`）
+    pub fn query_repeated<K, I, V>(mut self, key: K, values: I) -> Self
+    where
+        K: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let key = key.into();
+        self.repeated_query
+            .extend(values.into_iter().map(|v| (key.clone(), v.into())));
+        self
+    }
+
     /// 添加可选查询参数，如果值为None则跳过
     pub fn query_opt<K, V>(mut self, key: K, value: Option<V>) -> Self
     where
@@ -249,12 +277,13 @@ impl<R> ApiRequest<R> {
 
     /// 构建完整 URL（包含查询参数）
     pub fn build_url(&self) -> String {
-        if self.query.is_empty() {
+        if self.query.is_empty() && self.repeated_query.is_empty() {
             self.url.clone()
         } else {
             let query_string = self
                 .query
                 .iter()
+                .chain(self.repeated_query.iter().map(|(k, v)| (k, v)))
                 .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
                 .collect::<Vec<_>>()
                 .join("&");
@@ -280,6 +309,9 @@ impl<R> ApiRequest<R> {
 
     /// 获取支持的访问令牌类型
     pub fn supported_access_token_types(&self) -> Vec<crate::constants::AccessTokenType> {
+        if let Some(types) = &self.access_token_types {
+            return types.clone();
+        }
         // 默认返回用户和租户令牌类型
         vec![
             crate::constants::AccessTokenType::User,
@@ -287,6 +319,18 @@ impl<R> ApiRequest<R> {
         ]
     }
 
+    /// 指定接口支持的访问令牌类型
+    ///
+    /// 未指定时为 `[User, Tenant]`（默认使用 tenant_access_token，传入 user_access_token 时使用用户身份）；
+    /// 只支持 app_access_token 的接口传 `[App]`，无需鉴权的接口传 `[None]`。
+    pub fn access_token_types(
+        mut self,
+        types: impl IntoIterator<Item = crate::constants::AccessTokenType>,
+    ) -> Self {
+        self.access_token_types = Some(types.into_iter().collect());
+        self
+    }
+
     /// 将请求体转换为字节
     pub fn to_bytes(&self) -> Vec<u8> {
         match &self.body {
@@ -358,9 +402,11 @@ impl<R> Default for ApiRequest<R> {
             url: String::default(),
             headers: HashMap::new(),
             query: HashMap::new(),
+            repeated_query: Vec::new(),
             body: None,
             file: None,
             timeout: None,
+            access_token_types: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -372,12 +418,16 @@ pub type ApiResponse<R> = Response<R>;
 
 // 子模块
 
+pub mod meta;
 pub mod prelude;
 pub mod responses;
+pub mod stream;
 pub mod traits;
 
 // 重新导出
 
+pub use meta::{WithMetaExt, with_meta};
+pub use stream::ByteStream;
 pub use traits::{AsyncApiClient, SyncApiClient};
 
 // 测试
@@ -3,6 +3,6 @@
 // 从新api模块重新导出
 pub use super::{
     ApiRequest, ApiResponse, ApiResponseTrait, BaseResponse, ErrorInfo, HttpMethod, RawResponse,
-    RequestData, Response, ResponseFormat,
+    RequestData, Response, ResponseFormat, ResponseMeta, WithMetaExt,
     traits::{AsyncApiClient, SyncApiClient},
 };
@@ -3,7 +3,14 @@
 //! 独立的响应处理系统，替代api_resp模块
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use crate::constants::{
+    HTTP_HEADER_KEY_LOG_ID, HTTP_HEADER_RATELIMIT_LIMIT, HTTP_HEADER_RATELIMIT_RESET,
+};
 
 /// 原始响应数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +90,82 @@ impl RawResponse {
     }
 }
 
+/// 网关限流信息
+///
+/// 来自 `x-ogw-ratelimit-limit` / `x-ogw-ratelimit-reset` 响应头。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitInfo {
+    /// 当前接口的限流配额
+    pub limit: Option<u32>,
+    /// 距离配额重置的时间
+    pub reset: Option<Duration>,
+}
+
+/// 响应元信息
+///
+/// 记录 HTTP 层面的信息：状态码、响应头、`X-Tt-Logid`（飞书技术支持排查问题所需）、
+/// 网关限流信息、请求耗时与重试次数。由 `Transport` 在收到响应时填充；
+/// 中间件短路返回的响应没有 HTTP 信息，`status` 为 0。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResponseMeta {
+    /// HTTP 状态码
+    pub status: u16,
+    /// 响应头（名称统一为小写）
+    pub headers: BTreeMap<String, String>,
+    /// `X-Tt-Logid` 响应头
+    pub log_id: Option<String>,
+    /// 网关限流信息
+    pub rate_limit: Option<RateLimitInfo>,
+    /// 请求耗时（发送到读取完响应体）
+    pub latency: Duration,
+    /// 重试次数（首次请求不计入）
+    pub retry_count: u32,
+}
+
+impl ResponseMeta {
+    /// 从 HTTP 状态码与响应头构建
+    pub fn from_http(status: u16, headers: &reqwest::header::HeaderMap) -> Self {
+        let headers: BTreeMap<String, String> = headers
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.as_str().to_ascii_lowercase(), value.to_string()))
+            })
+            .collect();
+        let mut meta = Self {
+            status,
+            headers,
+            ..Default::default()
+        };
+        meta.log_id = meta.header(HTTP_HEADER_KEY_LOG_ID).map(str::to_string);
+        let limit = meta
+            .header(HTTP_HEADER_RATELIMIT_LIMIT)
+            .and_then(|value| value.trim().parse().ok());
+        let reset = meta
+            .header(HTTP_HEADER_RATELIMIT_RESET)
+            .and_then(|value| value.trim().parse().ok())
+            .map(Duration::from_secs);
+        if limit.is_some() || reset.is_some() {
+            meta.rate_limit = Some(RateLimitInfo { limit, reset });
+        }
+        meta
+    }
+
+    /// 按名称获取响应头（大小写不敏感）
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// 是否携带了 HTTP 信息（中间件短路的响应没有）
+    pub fn has_http(&self) -> bool {
+        self.status != 0
+    }
+}
+
 /// 响应格式枚举
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ResponseFormat {
@@ -118,12 +201,19 @@ pub struct Response<T> {
     pub data: Option<T>,
     /// 原始响应
     pub raw_response: RawResponse,
+    /// 响应元信息
+    #[serde(default)]
+    pub meta: ResponseMeta,
 }
 
 impl<T> Response<T> {
     /// 创建新响应
     pub fn new(data: Option<T>, raw_response: RawResponse) -> Self {
-        Self { data, raw_response }
+        Self {
+            data,
+            raw_response,
+            meta: ResponseMeta::default(),
+        }
     }
 
     /// 创建成功响应
@@ -131,6 +221,8 @@ impl<T> Response<T> {
         Self {
             data: Some(data),
             raw_response: RawResponse::success(),
+
+            meta: ResponseMeta::default(),
         }
     }
 
@@ -139,6 +231,8 @@ impl<T> Response<T> {
         Self {
             data: None,
             raw_response: RawResponse::success(),
+
+            meta: ResponseMeta::default(),
         }
     }
 
@@ -147,6 +241,8 @@ impl<T> Response<T> {
         Self {
             data: None,
             raw_response: RawResponse::error(code, msg),
+
+            meta: ResponseMeta::default(),
         }
     }
 
@@ -180,8 +276,35 @@ impl<T> Response<T> {
         &self.raw_response
     }
 
+    /// 获取响应元信息
+    pub fn meta(&self) -> &ResponseMeta {
+        &self.meta
+    }
+
+    /// `X-Tt-Logid`，向飞书技术支持反馈问题时使用
+    pub fn log_id(&self) -> Option<&str> {
+        self.meta.log_id.as_deref()
+    }
+
     /// 转换为结果类型
     pub fn into_result(self) -> Result<T, crate::error::CoreError> {
+        let meta = self.meta.clone();
+        self.into_result_inner().map_err(|err| {
+            if meta.has_http() {
+                err.with_response_meta(meta)
+            } else {
+                err
+            }
+        })
+    }
+
+    /// 转换为数据与元信息
+    pub fn into_result_with_meta(self) -> Result<(T, ResponseMeta), crate::error::CoreError> {
+        let meta = self.meta.clone();
+        self.into_result().map(|data| (data, meta))
+    }
+
+    fn into_result_inner(self) -> Result<T, crate::error::CoreError> {
         let is_success = self.is_success();
         let code = self.raw_response.code;
         let request_id = self.raw_response.request_id.clone();
@@ -225,6 +348,30 @@ pub type BaseResponse<T> = Response<T>;
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_response_meta_from_http() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("X-Tt-Logid", "abc".parse().unwrap());
+        headers.insert("x-ogw-ratelimit-limit", "100".parse().unwrap());
+        let meta = ResponseMeta::from_http(429, &headers);
+        assert_eq!(meta.status, 429);
+        assert_eq!(meta.log_id.as_deref(), Some("abc"));
+        assert_eq!(meta.header("x-tt-logid"), Some("abc"));
+        assert_eq!(
+            meta.rate_limit,
+            Some(RateLimitInfo {
+                limit: Some(100),
+                reset: None
+            })
+        );
+        assert!(meta.has_http());
+        assert!(
+            ResponseMeta::from_http(200, &Default::default())
+                .rate_limit
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_raw_response_default() {
         let response = RawResponse::default();
@@ -0,0 +1,305 @@
+//! 流式响应
+//!
+//! 下载类接口的响应体可能很大，[`ByteStream`] 按块读取响应体，
+//! 不受 `max_response_size` 限制，也不会一次性载入内存。
+
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::{
+    SDKResult,
+    api::{RawResponse, Response, ResponseMeta},
+    content_disposition,
+    error::{api_error, network_error},
+    response_handler::read_body_with_limit,
+};
+
+/// 流式响应体
+pub struct ByteStream {
+    body: Body,
+    meta: ResponseMeta,
+    raw: RawResponse,
+}
+
+enum Body {
+    /// 尚未读取的 HTTP 响应体
+    Http(reqwest::Response),
+    /// 已读入内存的响应体（JSON 内容、中间件短路）
+    Buffered(Option<Bytes>),
+}
+
+impl std::fmt::Debug for ByteStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ByteStream")
+            .field("meta", &self.meta)
+            .field("content_length", &self.content_length())
+            .finish()
+    }
+}
+
+impl ByteStream {
+    /// 由 HTTP 响应构建
+    ///
+    /// 非 2xx 状态或 JSON 响应体会先读入内存（受 `max_response_size` 限制）：
+    /// 飞书下载接口出错时返回 `{"code": ..., "msg": ...}`，这里统一转为 `CoreError`。
+    pub(crate) async fn from_http(
+        response: reqwest::Response,
+        start_time: std::time::Instant,
+        api_path: &str,
+        max_response_size: u64,
+    ) -> SDKResult<Self> {
+        let status = response.status();
+        let mut meta = ResponseMeta::from_http(status.as_u16(), response.headers());
+        meta.latency = start_time.elapsed();
+        let raw = RawResponse {
+            request_id: meta.log_id.clone(),
+            ..RawResponse::success()
+        };
+
+        let is_json = meta
+            .header(crate::constants::CONTENT_TYPE_HEADER)
+            .is_some_and(|value| value.contains("json"));
+        if status.is_success() && !is_json {
+            return Ok(Self {
+                body: Body::Http(response),
+                meta,
+                raw,
+            });
+        }
+
+        let body = read_body_with_limit(response, max_response_size)
+            .await
+            .map_err(|err| err.with_response_meta(meta.clone()))?;
+        match serde_json::from_slice::<RawResponse>(&body) {
+            Ok(parsed) if !parsed.is_success() => {
+                let mut failed = Response::<serde_json::Value>::new(None, parsed);
+                if failed.raw_response.request_id.is_none() {
+                    failed.raw_response.request_id = meta.log_id.clone();
+                }
+                failed.meta = meta;
+                Err(failed
+                    .into_result()
+                    .expect_err("non-zero code is always an error"))
+            }
+            _ if !status.is_success() => Err(api_error(
+                status.as_u16(),
+                api_path,
+                format!("HTTP {status}"),
+                meta.log_id.clone(),
+            )
+            .with_response_meta(meta)),
+            _ => Ok(Self {
+                body: Body::Buffered(Some(Bytes::from(body))),
+                meta,
+                raw,
+            }),
+        }
+    }
+
+    /// 由中间件短路返回的原始响应构建，`data` 以 JSON 形式作为响应体
+    pub(crate) fn from_raw(raw: RawResponse) -> SDKResult<Self> {
+        if !raw.is_success() {
+            return Err(Response::<serde_json::Value>::new(None, raw)
+                .into_result()
+                .expect_err("non-zero code is always an error"));
+        }
+        let body = raw
+            .data
+            .as_ref()
+            .map(|data| Bytes::from(data.to_string()))
+            .unwrap_or_default();
+        Ok(Self {
+            body: Body::Buffered(Some(body)),
+            meta: ResponseMeta::default(),
+            raw,
+        })
+    }
+
+    /// 响应元信息（状态码、响应头、`X-Tt-Logid`、限流信息）
+    pub fn meta(&self) -> &ResponseMeta {
+        &self.meta
+    }
+
+    pub(crate) fn meta_mut(&mut self) -> &mut ResponseMeta {
+        &mut self.meta
+    }
+
+    /// 供中间件 `after_response` 使用的原始响应（下载成功时 `code` 为 0）
+    pub fn raw_response(&self) -> &RawResponse {
+        &self.raw
+    }
+
+    /// `Content-Disposition` 中的文件名
+    pub fn file_name(&self) -> Option<String> {
+        self.meta
+            .header("content-disposition")
+            .and_then(content_disposition::extract_filename)
+    }
+
+    /// `Content-Type` 响应头
+    pub fn content_type(&self) -> Option<&str> {
+        self.meta.header(crate::constants::CONTENT_TYPE_HEADER)
+    }
+
+    /// 响应体长度（服务端未声明时为 `None`）
+    pub fn content_length(&self) -> Option<u64> {
+        match &self.body {
+            Body::Http(response) => response.content_length(),
+            Body::Buffered(bytes) => Some(bytes.as_ref().map_or(0, |b| b.len() as u64)),
+        }
+    }
+
+    /// 读取下一块数据，读完返回 `None`
+    pub async fn chunk(&mut self) -> SDKResult<Option<Bytes>> {
+        match &mut self.body {
+            Body::Http(response) => response
+                .chunk()
+                .await
+                .map_err(|err| network_error(format!("读取响应体失败: {err}"))),
+            Body::Buffered(bytes) => Ok(bytes.take().filter(|b| !b.is_empty())),
+        }
+    }
+
+    /// 读取剩余的全部数据
+    pub async fn bytes(mut self) -> SDKResult<Bytes> {
+        let mut buffer = Vec::new();
+        while let Some(chunk) = self.chunk().await? {
+            buffer.extend_from_slice(&chunk);
+        }
+        Ok(Bytes::from(buffer))
+    }
+
+    /// 转换为 `Stream`，便于配合 `StreamExt` 等组合子使用
+    pub fn into_stream(self) -> Pin<Box<dyn Stream<Item = SDKResult<Bytes>> + Send>> {
+        stream::unfold(Some(self), |state| async move {
+            let mut body = state?;
+            match body.chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk), Some(body))),
+                Ok(None) => None,
+                Err(err) => Some((Err(err), None)),
+            }
+        })
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use futures_util::StreamExt;
+    use serde_json::{Value, json};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    use crate::{
+        api::{ApiRequest, ByteStream},
+        config::Config,
+        constants::AccessTokenType,
+        http::Transport,
+        req_option::RequestOption,
+    };
+
+    fn config(server: &MockServer) -> Config {
+        Config::builder()
+            .app_id("app")
+            .app_secret("secret")
+            .base_url(server.uri())
+            .enable_token_cache(false)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_download_streams_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/drive/v1/files/f1/download"))
+            .and(header("Authorization", "Bearer t-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-Tt-Logid", "log-dl")
+                    .insert_header("Content-Type", "application/octet-stream")
+                    .insert_header("Content-Disposition", "attachment; filename=\"report.csv\"")
+                    .set_body_bytes(b"a,b\n1,2\n".to_vec()),
+            )
+            .mount(&server)
+            .await;
+
+        let req: ApiRequest<()> = ApiRequest::get("/open-apis/drive/v1/files/f1/download");
+        let option = RequestOption::builder().tenant_access_token("t-1").build();
+        let stream = Transport::<ByteStream>::request_stream(req, &config(&server), Some(option))
+            .await
+            .unwrap();
+        assert_eq!(stream.meta().log_id.as_deref(), Some("log-dl"));
+        assert_eq!(stream.file_name().as_deref(), Some("report.csv"));
+        assert_eq!(stream.content_length(), Some(8));
+
+        let chunks: Vec<_> = stream.into_stream().collect().await;
+        let body: Vec<u8> = chunks
+            .into_iter()
+            .flat_map(|chunk| chunk.unwrap().to_vec())
+            .collect();
+        assert_eq!(body, b"a,b\n1,2\n");
+    }
+
+    #[tokio::test]
+    async fn test_json_error_becomes_core_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .insert_header("X-Tt-Logid", "log-missing")
+                    .set_body_json(json!({"code": 1061004, "msg": "forbidden"})),
+            )
+            .mount(&server)
+            .await;
+
+        let req: ApiRequest<()> = ApiRequest::get("/open-apis/drive/v1/files/f1/download");
+        let option = RequestOption::builder().tenant_access_token("t-1").build();
+        let err = Transport::<ByteStream>::request_stream(req, &config(&server), Some(option))
+            .await
+            .unwrap_err();
+        assert_eq!(err.log_id(), Some("log-missing"));
+        assert_eq!(err.response_meta().unwrap().status, 404);
+    }
+
+    #[tokio::test]
+    async fn test_json_success_is_buffered() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"code": 0, "data": {"v": 1}})),
+            )
+            .mount(&server)
+            .await;
+
+        let req: ApiRequest<()> =
+            ApiRequest::get("/open-apis/export").access_token_types([AccessTokenType::None]);
+        let stream = Transport::<ByteStream>::request_stream(req, &config(&server), None)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&stream.bytes().await.unwrap()).unwrap();
+        assert_eq!(body["data"]["v"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_app_token_type() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("Authorization", "Bearer a-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"ok".to_vec()))
+            .mount(&server)
+            .await;
+
+        let req: ApiRequest<()> =
+            ApiRequest::get("/open-apis/app").access_token_types([AccessTokenType::App]);
+        let option = RequestOption::builder().app_access_token("a-1").build();
+        let stream = Transport::<ByteStream>::request_stream(req, &config(&server), Some(option))
+            .await
+            .unwrap();
+        assert_eq!(stream.bytes().await.unwrap().as_ref(), b"ok");
+    }
+}
@@ -1,10 +1,36 @@
 //! 应用票据（app_ticket）相关逻辑
 //!
-//! 仅保留在请求遇到 app_ticket 失效时触发的“重新推送”能力。
+//! 商店应用换取 `app_access_token` 需要飞书每小时推送一次的 `app_ticket`：
+//!
+//! - [`AppTicketStore`]：票据存储抽象，内置 [`MemoryAppTicketStore`] 与 [`FileAppTicketStore`]
+//! - [`AppTicketHandler`]：从长连接或 HTTP 回调的事件负载中捕获票据并写入存储
+//! - [`ensure_app_ticket`] / [`resend_and_wait`]：启动时触发重新推送并等待新票据
+//! - [`apply_app_ticket`]：请求遇到 app_ticket 失效时触发重新推送
+//!
+//! 商店应用的 [`Config`] 默认挂载一个内存存储，`TokenProvider` 会从中读取票据。
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant, SystemTime},
+};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::{SDKResult, config::Config, constants::APPLY_APP_TICKET_PATH};
+use crate::{
+    SDKResult,
+    config::Config,
+    constants::APPLY_APP_TICKET_PATH,
+    error::{configuration_error, timeout_error},
+};
+
+/// 轮询存储等待新票据的间隔
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(200);
 
 /// 触发“重新推送 app_ticket”
 ///
@@ -26,3 +52,379 @@ struct ResendAppTicketReq {
     app_id: String,
     app_secret: String,
 }
+
+/// 已收到的应用票据
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppTicket {
+    /// 票据值
+    pub ticket: String,
+    /// 收到票据的时间
+    pub received_at: SystemTime,
+}
+
+impl AppTicket {
+    /// 以当前时间创建票据
+    pub fn new(ticket: impl Into<String>) -> Self {
+        Self {
+            ticket: ticket.into(),
+            received_at: SystemTime::now(),
+        }
+    }
+}
+
+impl fmt::Debug for AppTicket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppTicket")
+            .field("ticket", &"***")
+            .field("received_at", &self.received_at)
+            .finish()
+    }
+}
+
+/// 票据存储返回的 Future
+pub type AppTicketFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 应用票据存储
+///
+/// 多实例部署时可基于 Redis 等共享存储实现，保证收到推送的实例与换取令牌的实例读到同一张票据。
+pub trait AppTicketStore: Send + Sync + fmt::Debug {
+    /// 读取应用当前的票据
+    fn get<'a>(&'a self, app_id: &'a str) -> AppTicketFuture<'a, SDKResult<Option<AppTicket>>>;
+
+    /// 保存应用的最新票据
+    fn set<'a>(&'a self, app_id: &'a str, ticket: AppTicket) -> AppTicketFuture<'a, SDKResult<()>>;
+}
+
+impl<T: AppTicketStore + ?Sized> AppTicketStore for Arc<T> {
+    fn get<'a>(&'a self, app_id: &'a str) -> AppTicketFuture<'a, SDKResult<Option<AppTicket>>> {
+        (**self).get(app_id)
+    }
+
+    fn set<'a>(&'a self, app_id: &'a str, ticket: AppTicket) -> AppTicketFuture<'a, SDKResult<()>> {
+        (**self).set(app_id, ticket)
+    }
+}
+
+/// 内存票据存储（单进程）
+#[derive(Debug, Default)]
+pub struct MemoryAppTicketStore {
+    tickets: RwLock<HashMap<String, AppTicket>>,
+}
+
+impl MemoryAppTicketStore {
+    /// 创建空存储
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AppTicketStore for MemoryAppTicketStore {
+    fn get<'a>(&'a self, app_id: &'a str) -> AppTicketFuture<'a, SDKResult<Option<AppTicket>>> {
+        let ticket = self
+            .tickets
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(app_id)
+            .cloned();
+        Box::pin(async move { Ok(ticket) })
+    }
+
+    fn set<'a>(&'a self, app_id: &'a str, ticket: AppTicket) -> AppTicketFuture<'a, SDKResult<()>> {
+        self.tickets
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(app_id.to_string(), ticket);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// 文件票据存储
+///
+/// 以 JSON 保存 `app_id → 票据`，进程重启后无需等待下一次推送；写入时先写临时文件再重命名。
+#[derive(Debug)]
+pub struct FileAppTicketStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileAppTicketStore {
+    /// 使用指定文件路径，文件不存在时在首次写入时创建
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// 存储文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn load(&self) -> SDKResult<HashMap<String, AppTicket>> {
+        match std::fs::read(&self.path) {
+            Ok(content) if content.is_empty() => Ok(HashMap::new()),
+            Ok(content) => Ok(serde_json::from_slice(&content)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(err) => Err(configuration_error(format!(
+                "读取 app_ticket 文件 {} 失败: {err}",
+                self.path.display()
+            ))),
+        }
+    }
+
+    fn save(&self, tickets: &HashMap<String, AppTicket>) -> SDKResult<()> {
+        let io_error = |err: std::io::Error| {
+            configuration_error(format!(
+                "写入 app_ticket 文件 {} 失败: {err}",
+                self.path.display()
+            ))
+        };
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        std::fs::write(&tmp, serde_json::to_vec_pretty(tickets)?).map_err(io_error)?;
+        restrict_permissions(&tmp);
+        std::fs::rename(&tmp, &self.path).map_err(io_error)
+    }
+}
+
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600));
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) {}
+
+impl AppTicketStore for FileAppTicketStore {
+    fn get<'a>(&'a self, app_id: &'a str) -> AppTicketFuture<'a, SDKResult<Option<AppTicket>>> {
+        Box::pin(async move {
+            let _guard = self.lock.lock().unwrap_or_else(|p| p.into_inner());
+            Ok(self.load()?.remove(app_id))
+        })
+    }
+
+    fn set<'a>(&'a self, app_id: &'a str, ticket: AppTicket) -> AppTicketFuture<'a, SDKResult<()>> {
+        Box::pin(async move {
+            let _guard = self.lock.lock().unwrap_or_else(|p| p.into_inner());
+            let mut tickets = self.load()?;
+            tickets.insert(app_id.to_string(), ticket);
+            self.save(&tickets)
+        })
+    }
+}
+
+/// 从事件负载中解析 `app_ticket` 事件，返回 `(app_id, app_ticket)`
+///
+/// 兼容 1.0（`event.type`）与 2.0（`header.event_type`）结构；HTTP 回调开启加密时需先解密。
+pub fn parse_app_ticket_event(payload: &[u8]) -> Option<(String, String)> {
+    let value: Value = serde_json::from_slice(payload).ok()?;
+    let event = value.get("event")?;
+    let header = value.get("header");
+    let event_type = header
+        .and_then(|header| header.get("event_type"))
+        .or_else(|| event.get("type"))
+        .and_then(Value::as_str)?;
+    if event_type != "app_ticket" {
+        return None;
+    }
+    let app_id = event
+        .get("app_id")
+        .or_else(|| header.and_then(|header| header.get("app_id")))
+        .and_then(Value::as_str)?;
+    let ticket = event.get("app_ticket").and_then(Value::as_str)?;
+    Some((app_id.to_string(), ticket.to_string()))
+}
+
+/// `app_ticket` 事件处理器
+///
+/// 把长连接或 HTTP 回调收到的事件负载交给 [`AppTicketHandler::handle`]，
+/// 识别到 `app_ticket` 事件时写入存储，其他事件直接忽略。
+#[derive(Debug, Clone)]
+pub struct AppTicketHandler {
+    store: Arc<dyn AppTicketStore>,
+}
+
+impl AppTicketHandler {
+    /// 写入指定存储
+    pub fn new(store: Arc<dyn AppTicketStore>) -> Self {
+        Self { store }
+    }
+
+    /// 写入 `Config` 挂载的存储
+    pub fn from_config(config: &Config) -> SDKResult<Self> {
+        config
+            .app_ticket_store()
+            .cloned()
+            .map(Self::new)
+            .ok_or_else(|| configuration_error("Config 未挂载 app_ticket 存储"))
+    }
+
+    /// 处理事件负载，返回是否捕获到票据
+    pub async fn handle(&self, payload: &[u8]) -> SDKResult<bool> {
+        let Some((app_id, ticket)) = parse_app_ticket_event(payload) else {
+            return Ok(false);
+        };
+        self.store.set(&app_id, AppTicket::new(ticket)).await?;
+        tracing::debug!(app_id = %app_id, "app_ticket 已更新");
+        Ok(true)
+    }
+}
+
+/// 确保存储中已有票据：已有时直接返回，否则触发重新推送并等待
+///
+/// 适合在商店应用启动时调用。
+pub async fn ensure_app_ticket(config: &Config, timeout: Duration) -> SDKResult<String> {
+    let store = store_of(config)?;
+    if let Some(ticket) = store.get(config.app_id()).await? {
+        return Ok(ticket.ticket);
+    }
+    resend_and_wait(config, timeout).await
+}
+
+/// 触发重新推送，并等待存储中出现本次调用之后收到的票据
+pub async fn resend_and_wait(config: &Config, timeout: Duration) -> SDKResult<String> {
+    let store = store_of(config)?;
+    let requested_at = SystemTime::now();
+    apply_app_ticket(config).await?;
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(ticket) = store.get(config.app_id()).await?
+            && ticket.received_at >= requested_at
+        {
+            return Ok(ticket.ticket);
+        }
+        if Instant::now() >= deadline {
+            return Err(timeout_error(
+                timeout,
+                Some("等待 app_ticket 推送".to_string()),
+            ));
+        }
+        tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+    }
+}
+
+fn store_of(config: &Config) -> SDKResult<&Arc<dyn AppTicketStore>> {
+    config
+        .app_ticket_store()
+        .ok_or_else(|| configuration_error("Config 未挂载 app_ticket 存储"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    fn ticket_event(app_id: &str, ticket: &str) -> Vec<u8> {
+        json!({
+            "uuid": "u1",
+            "type": "event_callback",
+            "event": {"app_id": app_id, "app_ticket": ticket, "type": "app_ticket"}
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_parse_app_ticket_event() {
+        assert_eq!(
+            parse_app_ticket_event(&ticket_event("cli_a", "t1")),
+            Some(("cli_a".to_string(), "t1".to_string()))
+        );
+        let v2 = json!({
+            "schema": "2.0",
+            "header": {"event_type": "app_ticket", "app_id": "cli_b"},
+            "event": {"app_ticket": "t2"}
+        });
+        assert_eq!(
+            parse_app_ticket_event(v2.to_string().as_bytes()),
+            Some(("cli_b".to_string(), "t2".to_string()))
+        );
+        let other = json!({"header": {"event_type": "im.message.receive_v1"}, "event": {}});
+        assert!(parse_app_ticket_event(other.to_string().as_bytes()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_file_store_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileAppTicketStore::new(dir.path().join("nested/tickets.json"));
+        assert!(store.get("cli_a").await.unwrap().is_none());
+        store.set("cli_a", AppTicket::new("t1")).await.unwrap();
+        store.set("cli_b", AppTicket::new("t2")).await.unwrap();
+
+        let reopened = FileAppTicketStore::new(store.path());
+        assert_eq!(reopened.get("cli_a").await.unwrap().unwrap().ticket, "t1");
+        assert_eq!(reopened.get("cli_b").await.unwrap().unwrap().ticket, "t2");
+    }
+
+    #[tokio::test]
+    async fn test_handler_and_resend_and_wait() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(APPLY_APP_TICKET_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 0})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = Config::builder()
+            .app_id("cli_m")
+            .app_secret("secret")
+            .base_url(server.uri())
+            .app_type(crate::constants::AppType::Marketplace)
+            .build();
+        let handler = AppTicketHandler::from_config(&config).unwrap();
+        assert!(!handler.handle(b"{}").await.unwrap());
+
+        let pusher = handler.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            pusher
+                .handle(&ticket_event("cli_m", "fresh"))
+                .await
+                .unwrap();
+        });
+        let ticket = ensure_app_ticket(&config, Duration::from_secs(2))
+            .await
+            .unwrap();
+        assert_eq!(ticket, "fresh");
+
+        // 已有票据时不再触发重新推送
+        assert_eq!(
+            ensure_app_ticket(&config, Duration::from_millis(10))
+                .await
+                .unwrap(),
+            "fresh"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resend_and_wait_times_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"code": 0})))
+            .mount(&server)
+            .await;
+        let config = Config::builder()
+            .app_id("cli_m")
+            .app_secret("secret")
+            .base_url(server.uri())
+            .app_ticket_store(MemoryAppTicketStore::new())
+            .build();
+        assert!(
+            resend_and_wait(&config, Duration::from_millis(50))
+                .await
+                .is_err()
+        );
+    }
+}
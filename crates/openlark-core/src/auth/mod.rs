@@ -6,4 +6,7 @@ pub mod app_ticket;
 pub mod token_provider;
 
 // Re-export commonly used types
+pub use app_ticket::{
+    AppTicket, AppTicketHandler, AppTicketStore, FileAppTicketStore, MemoryAppTicketStore,
+};
 pub use token_provider::{NoOpTokenProvider, TokenProvider, TokenRequest};
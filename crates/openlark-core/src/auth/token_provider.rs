@@ -95,6 +95,15 @@ pub trait TokenProvider: Send + Sync + std::fmt::Debug {
     fn get_user_token(&self) -> Pin<Box<dyn Future<Output = SDKResult<String>> + Send + '_>> {
         Box::pin(async move { self.get_token(TokenRequest::user()).await })
     }
+
+    /// 作废缓存中与 `request` 对应的 token
+    ///
+    /// 服务端判定 token 无效或已过期（如 `99991663`、`99991671`）时由 `Transport` 调用，
+    /// 之后的 `get_token` 应重新获取。默认不做任何事。
+    fn invalidate(&self, request: TokenRequest) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        let _ = request;
+        Box::pin(async {})
+    }
 }
 
 /// Default implementation that does not cache tokens
@@ -0,0 +1,794 @@
+//! HTTP 流量录制与回放（cassette）
+//!
+//! 在 `Transport` 层拦截真实的 HTTP 交互：录制模式下照常发送请求，并把请求（方法、路径、
+//! 查询参数、请求头、请求体）与响应（状态码、响应头、响应体）写入磁带文件；回放模式下不访问网络，
+//! 按匹配规则从磁带中取出响应。所有基于 `Transport` 的业务 crate 都无需改动即可使用。
+//!
+//! ```rust,ignore
+//! use openlark_core::cassette::{Cassette, CassetteMode};
+//!
+//! // 本地录制一次
+//! let cassette = Cassette::open("tests/cassettes/send_message.json", CassetteMode::Record)?;
+//! // CI 中回放（`.yaml` / `.yml` 需启用 `cassette-yaml` feature）
+//! let cassette = Cassette::open("tests/cassettes/send_message.json", CassetteMode::Replay)?;
+//!
+//! let config = Config::builder()
+//!     .app_id("cli_xxx")
+//!     .app_secret("xxx")
+//!     .cassette(cassette)
+//!     .build();
+//! ```
+//!
+//! 写入磁带前会自动脱敏：`Authorization` 等请求头，以及请求/响应 JSON 与查询参数中的
+//! `app_secret`、各类 access token、`refresh_token`、`app_ticket` 等字段都会替换为 [`REDACTED`]。
+//! 回放模式下 `Transport` 不再向 `TokenProvider` 换取 token，直接使用占位值。
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+};
+
+use base64::Engine;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    SDKResult,
+    error::{configuration_error, network_error},
+};
+
+/// 脱敏后的占位值
+pub const REDACTED: &str = "[REDACTED]";
+
+/// 默认脱敏的请求/响应头（小写）
+const DEFAULT_SCRUBBED_HEADERS: &[&str] = &["authorization", "cookie", "set-cookie"];
+
+/// 默认脱敏的 JSON 字段与查询参数
+const DEFAULT_SCRUBBED_FIELDS: &[&str] = &[
+    "app_secret",
+    "app_access_token",
+    "tenant_access_token",
+    "user_access_token",
+    "access_token",
+    "refresh_token",
+    "app_ticket",
+    "code_verifier",
+];
+
+/// 录制时丢弃的响应头：响应体可能因脱敏而改变长度
+const DROPPED_RESPONSE_HEADERS: &[&str] = &["content-length", "transfer-encoding"];
+
+/// 磁带文件格式版本
+const CASSETTE_VERSION: u32 = 1;
+
+/// 磁带模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// 录制：发送真实请求并追加写入磁带（已有内容会被覆盖）
+    Record,
+    /// 回放：只从磁带返回响应，没有匹配时报错，不访问网络
+    Replay,
+    /// 磁带文件存在时回放，否则录制
+    Auto,
+}
+
+/// 磁带文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteFormat {
+    /// JSON
+    Json,
+    /// YAML（需启用 `cassette-yaml` feature）
+    Yaml,
+}
+
+impl CassetteFormat {
+    /// 按文件扩展名推断，`.yaml` / `.yml` 为 YAML，其余为 JSON
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => {
+                Self::Yaml
+            }
+            _ => Self::Json,
+        }
+    }
+}
+
+/// 请求匹配规则
+///
+/// 默认按方法、路径与查询参数匹配；请求体与请求头默认不参与匹配。
+#[derive(Debug, Clone)]
+pub struct MatchRules {
+    method: bool,
+    path: bool,
+    query: bool,
+    body: bool,
+    headers: Vec<String>,
+    ignored_query: Vec<String>,
+}
+
+impl Default for MatchRules {
+    fn default() -> Self {
+        Self {
+            method: true,
+            path: true,
+            query: true,
+            body: false,
+            headers: Vec::new(),
+            ignored_query: Vec::new(),
+        }
+    }
+}
+
+impl MatchRules {
+    /// 是否比较 HTTP 方法
+    pub fn method(mut self, enabled: bool) -> Self {
+        self.method = enabled;
+        self
+    }
+
+    /// 是否比较路径
+    pub fn path(mut self, enabled: bool) -> Self {
+        self.path = enabled;
+        self
+    }
+
+    /// 是否比较查询参数
+    pub fn query(mut self, enabled: bool) -> Self {
+        self.query = enabled;
+        self
+    }
+
+    /// 是否比较请求体（JSON 按语义比较，multipart 请求体不参与比较）
+    pub fn body(mut self, enabled: bool) -> Self {
+        self.body = enabled;
+        self
+    }
+
+    /// 额外比较指定请求头
+    pub fn header(mut self, name: impl Into<String>) -> Self {
+        self.headers.push(name.into().to_ascii_lowercase());
+        self
+    }
+
+    /// 比较查询参数时忽略指定参数（例如随机的 `uuid`）
+    pub fn ignore_query(mut self, name: impl Into<String>) -> Self {
+        self.ignored_query.push(name.into());
+        self
+    }
+
+    fn matches(&self, recorded: &RecordedRequest, actual: &RecordedRequest) -> bool {
+        if self.method && !recorded.method.eq_ignore_ascii_case(&actual.method) {
+            return false;
+        }
+        if self.path && recorded.path != actual.path {
+            return false;
+        }
+        if self.query {
+            let filter = |query: &BTreeMap<String, String>| -> BTreeMap<String, String> {
+                query
+                    .iter()
+                    .filter(|(key, _)| !self.ignored_query.contains(key))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect()
+            };
+            if filter(&recorded.query) != filter(&actual.query) {
+                return false;
+            }
+        }
+        if self.body && !recorded.multipart && recorded.body != actual.body {
+            return false;
+        }
+        self.headers
+            .iter()
+            .all(|name| recorded.headers.get(name) == actual.headers.get(name))
+    }
+}
+
+/// 录制的消息体
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+    /// JSON
+    Json(Value),
+    /// UTF-8 文本
+    Text(String),
+    /// 二进制内容（base64）
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn from_bytes(bytes: &[u8], scrubber: &Scrubber) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+        if let Ok(mut json) = serde_json::from_slice::<Value>(bytes) {
+            scrubber.scrub_json(&mut json);
+            return Some(Self::Json(json));
+        }
+        Some(match std::str::from_utf8(bytes) {
+            Ok(text) => Self::Text(text.to_string()),
+            Err(_) => Self::Base64(base64::engine::general_purpose::STANDARD.encode(bytes)),
+        })
+    }
+
+    fn to_bytes(&self) -> SDKResult<Vec<u8>> {
+        match self {
+            Self::Json(json) => Ok(serde_json::to_vec(json)?),
+            Self::Text(text) => Ok(text.clone().into_bytes()),
+            Self::Base64(data) => base64::engine::general_purpose::STANDARD
+                .decode(data)
+                .map_err(|err| configuration_error(format!("cassette: base64 响应体无效: {err}"))),
+        }
+    }
+}
+
+/// 录制的请求
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP 方法
+    pub method: String,
+    /// 路径（不含域名）
+    pub path: String,
+    /// 查询参数
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub query: BTreeMap<String, String>,
+    /// 请求头（小写）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// 请求体
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<RecordedBody>,
+    /// 是否为 multipart 请求（请求体不录制）
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multipart: bool,
+}
+
+/// 录制的响应
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP 状态码
+    pub status: u16,
+    /// 响应头（小写）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// 响应体
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<RecordedBody>,
+}
+
+/// 一次请求/响应交互
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// 请求
+    pub request: RecordedRequest,
+    /// 响应
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    version: u32,
+    interactions: Vec<Interaction>,
+}
+
+/// 脱敏规则
+#[derive(Debug, Clone)]
+struct Scrubber {
+    headers: Vec<String>,
+    fields: Vec<String>,
+}
+
+impl Default for Scrubber {
+    fn default() -> Self {
+        Self {
+            headers: DEFAULT_SCRUBBED_HEADERS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            fields: DEFAULT_SCRUBBED_FIELDS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl Scrubber {
+    fn headers(&self, headers: &HeaderMap, dropped: &[&str]) -> BTreeMap<String, String> {
+        headers
+            .iter()
+            .filter(|(name, _)| !dropped.contains(&name.as_str()))
+            .map(|(name, value)| {
+                let name = name.as_str().to_ascii_lowercase();
+                let value = if self.headers.contains(&name) {
+                    REDACTED.to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name, value)
+            })
+            .collect()
+    }
+
+    fn query(&self, url: &url::Url) -> BTreeMap<String, String> {
+        url.query_pairs()
+            .map(|(key, value)| {
+                let value = if self.fields.iter().any(|field| field == key.as_ref()) {
+                    REDACTED.to_string()
+                } else {
+                    value.into_owned()
+                };
+                (key.into_owned(), value)
+            })
+            .collect()
+    }
+
+    fn scrub_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.fields.contains(key) && !value.is_null() {
+                        *value = Value::String(REDACTED.to_string());
+                    } else {
+                        self.scrub_json(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.scrub_json(item)),
+            _ => {}
+        }
+    }
+}
+
+/// 录制/回放磁带
+///
+/// 通过 `ConfigBuilder::cassette` 或 `Config::with_cassette` 挂到配置上。
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    format: CassetteFormat,
+    mode: CassetteMode,
+    rules: MatchRules,
+    scrubber: Scrubber,
+    allow_repeats: bool,
+    state: Mutex<CassetteState>,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+}
+
+impl Cassette {
+    /// 打开磁带
+    ///
+    /// 回放模式下文件必须存在；录制模式会在首次写入时创建文件（含父目录）。
+    pub fn open(path: impl Into<PathBuf>, mode: CassetteMode) -> SDKResult<Self> {
+        let path = path.into();
+        let format = CassetteFormat::from_path(&path);
+        let mode = match mode {
+            CassetteMode::Auto if path.exists() => CassetteMode::Replay,
+            CassetteMode::Auto => CassetteMode::Record,
+            mode => mode,
+        };
+        let interactions = match mode {
+            CassetteMode::Replay => {
+                let content = std::fs::read_to_string(&path).map_err(|err| {
+                    configuration_error(format!(
+                        "cassette: 读取磁带 {} 失败: {err}",
+                        path.display()
+                    ))
+                })?;
+                decode(&content, format)?.interactions
+            }
+            _ => {
+                // 提前校验格式，避免录完才发现无法写入
+                encode(&CassetteFile::default(), format)?;
+                Vec::new()
+            }
+        };
+        let used = vec![false; interactions.len()];
+        Ok(Self {
+            path,
+            format,
+            mode,
+            rules: MatchRules::default(),
+            scrubber: Scrubber::default(),
+            allow_repeats: true,
+            state: Mutex::new(CassetteState { interactions, used }),
+        })
+    }
+
+    /// 录制模式
+    pub fn record(path: impl Into<PathBuf>) -> SDKResult<Self> {
+        Self::open(path, CassetteMode::Record)
+    }
+
+    /// 回放模式
+    pub fn replay(path: impl Into<PathBuf>) -> SDKResult<Self> {
+        Self::open(path, CassetteMode::Replay)
+    }
+
+    /// 设置匹配规则
+    pub fn match_rules(mut self, rules: MatchRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// 额外脱敏的请求/响应头
+    pub fn scrub_header(mut self, name: impl Into<String>) -> Self {
+        self.scrubber.headers.push(name.into().to_ascii_lowercase());
+        self
+    }
+
+    /// 额外脱敏的 JSON 字段与查询参数
+    pub fn scrub_field(mut self, name: impl Into<String>) -> Self {
+        self.scrubber.fields.push(name.into());
+        self
+    }
+
+    /// 回放时所有匹配的交互都已使用过，是否允许重复使用最后一条（默认允许）
+    pub fn allow_repeats(mut self, allow: bool) -> Self {
+        self.allow_repeats = allow;
+        self
+    }
+
+    /// 实际生效的模式（`Auto` 已按文件是否存在解析）
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// 是否处于回放模式
+    pub fn is_replaying(&self) -> bool {
+        self.mode == CassetteMode::Replay
+    }
+
+    /// 磁带文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 当前磁带中的交互
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state().interactions.clone()
+    }
+
+    /// 回放模式下尚未被使用的交互数
+    pub fn unused(&self) -> usize {
+        self.state().used.iter().filter(|used| !**used).count()
+    }
+
+    fn state(&self) -> MutexGuard<'_, CassetteState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// 发送（或回放）一次请求，返回可交给响应处理器的 HTTP 响应
+    pub(crate) async fn send(
+        &self,
+        client: reqwest::Client,
+        request: reqwest::Request,
+        body: Vec<u8>,
+        multipart: bool,
+    ) -> SDKResult<reqwest::Response> {
+        let recorded = self.recorded_request(&request, &body, multipart);
+        if self.is_replaying() {
+            let response = self.find(&recorded).ok_or_else(|| {
+                network_error(format!(
+                    "cassette: {} 中没有匹配的交互: {} {}",
+                    self.path.display(),
+                    recorded.method,
+                    recorded.path
+                ))
+            })?;
+            return rebuild(&response);
+        }
+
+        let builder = reqwest::RequestBuilder::from_parts(client, request);
+        let builder = if multipart {
+            builder
+        } else {
+            builder.body(body)
+        };
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        let headers = self
+            .scrubber
+            .headers(response.headers(), DROPPED_RESPONSE_HEADERS);
+        let bytes = response.bytes().await?;
+        let recorded_response = RecordedResponse {
+            status,
+            headers,
+            body: RecordedBody::from_bytes(&bytes, &self.scrubber),
+        };
+        self.push(Interaction {
+            request: recorded,
+            response: recorded_response.clone(),
+        })?;
+
+        // 返回原始字节而非脱敏内容，录制时的调用方仍能拿到真实 token 等数据
+        let mut live = recorded_response;
+        live.body = (!bytes.is_empty()).then(|| {
+            RecordedBody::Base64(base64::engine::general_purpose::STANDARD.encode(&bytes))
+        });
+        rebuild(&live)
+    }
+
+    fn recorded_request(
+        &self,
+        request: &reqwest::Request,
+        body: &[u8],
+        multipart: bool,
+    ) -> RecordedRequest {
+        RecordedRequest {
+            method: request.method().as_str().to_string(),
+            path: request.url().path().to_string(),
+            query: self.scrubber.query(request.url()),
+            headers: self.scrubber.headers(request.headers(), &[]),
+            body: if multipart {
+                None
+            } else {
+                RecordedBody::from_bytes(body, &self.scrubber)
+            },
+            multipart,
+        }
+    }
+
+    fn find(&self, actual: &RecordedRequest) -> Option<RecordedResponse> {
+        let mut state = self.state();
+        let matching: Vec<usize> = state
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| self.rules.matches(&interaction.request, actual))
+            .map(|(index, _)| index)
+            .collect();
+        let index = match matching.iter().find(|index| !state.used[**index]) {
+            Some(index) => *index,
+            None if self.allow_repeats => *matching.last()?,
+            None => return None,
+        };
+        state.used[index] = true;
+        Some(state.interactions[index].response.clone())
+    }
+
+    fn push(&self, interaction: Interaction) -> SDKResult<()> {
+        let file = {
+            let mut state = self.state();
+            state.interactions.push(interaction);
+            state.used.push(true);
+            CassetteFile {
+                version: CASSETTE_VERSION,
+                interactions: state.interactions.clone(),
+            }
+        };
+        let content = encode(&file, self.format)?;
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)
+                .map_err(|err| configuration_error(format!("cassette: 创建目录失败: {err}")))?;
+        }
+        std::fs::write(&self.path, content).map_err(|err| {
+            configuration_error(format!(
+                "cassette: 写入磁带 {} 失败: {err}",
+                self.path.display()
+            ))
+        })
+    }
+}
+
+fn rebuild(recorded: &RecordedResponse) -> SDKResult<reqwest::Response> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    let body = match &recorded.body {
+        Some(body) => body.to_bytes()?,
+        None => Vec::new(),
+    };
+    let response = builder
+        .body(body)
+        .map_err(|err| configuration_error(format!("cassette: 无法还原响应: {err}")))?;
+    Ok(reqwest::Response::from(response))
+}
+
+fn encode(file: &CassetteFile, format: CassetteFormat) -> SDKResult<String> {
+    match format {
+        CassetteFormat::Json => Ok(serde_json::to_string_pretty(file)?),
+        #[cfg(feature = "cassette-yaml")]
+        CassetteFormat::Yaml => serde_yaml::to_string(file)
+            .map_err(|err| configuration_error(format!("cassette: YAML 序列化失败: {err}"))),
+        #[cfg(not(feature = "cassette-yaml"))]
+        CassetteFormat::Yaml => Err(yaml_disabled()),
+    }
+}
+
+fn decode(content: &str, format: CassetteFormat) -> SDKResult<CassetteFile> {
+    match format {
+        CassetteFormat::Json => Ok(serde_json::from_str(content)?),
+        #[cfg(feature = "cassette-yaml")]
+        CassetteFormat::Yaml => serde_yaml::from_str(content)
+            .map_err(|err| configuration_error(format!("cassette: YAML 解析失败: {err}"))),
+        #[cfg(not(feature = "cassette-yaml"))]
+        CassetteFormat::Yaml => Err(yaml_disabled()),
+    }
+}
+
+#[cfg(not(feature = "cassette-yaml"))]
+fn yaml_disabled() -> crate::error::CoreError {
+    configuration_error("cassette: YAML 磁带需要启用 openlark-core 的 `cassette-yaml` feature")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::ApiRequest, config::Config, http::Transport, req_option::RequestOption};
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("openlark-cassette-{}", uuid::Uuid::new_v4()))
+            .join(name)
+    }
+
+    fn config(base_url: &str, cassette: Cassette) -> Config {
+        Config::builder()
+            .app_id("cli_test")
+            .app_secret("super_secret")
+            .base_url(base_url)
+            .cassette(cassette)
+            .build()
+    }
+
+    fn option() -> RequestOption {
+        RequestOption::builder()
+            .tenant_access_token("t-live-token")
+            .build()
+    }
+
+    async fn send(config: &Config, text: &str) -> SDKResult<crate::api::Response<Value>> {
+        let req: ApiRequest<Value> = ApiRequest::post("/open-apis/im/v1/messages")
+            .query("receive_id_type", "chat_id")
+            .json_body(&json!({"receive_id": "oc_1", "content": text, "app_secret": "leak"}));
+        Transport::request(req, config, Some(option())).await
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_without_network() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/im/v1/messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "msg": "success",
+                "data": {"message_id": "om_1", "refresh_token": "r-secret"},
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let file = cassette_path("messages.json");
+        let recording = config(&server.uri(), Cassette::record(&file).unwrap());
+        let live = send(&recording, "hi").await.unwrap();
+        // 录制时调用方拿到的是真实响应
+        assert_eq!(live.data.unwrap()["refresh_token"], "r-secret");
+
+        let content = std::fs::read_to_string(&file).unwrap();
+        assert!(!content.contains("t-live-token"), "{content}");
+        assert!(!content.contains("leak"));
+        assert!(!content.contains("r-secret"));
+        assert!(content.contains(REDACTED));
+
+        // 回放时指向一个不存在的地址，确保没有网络访问
+        let replaying = config("http://127.0.0.1:9", Cassette::replay(&file).unwrap());
+        let replayed = send(&replaying, "hi").await.unwrap();
+        assert!(replayed.is_success());
+        assert_eq!(replayed.data.unwrap()["message_id"], "om_1");
+        assert_eq!(replayed.meta.status, 200);
+    }
+
+    #[tokio::test]
+    async fn test_replay_uses_interactions_in_order_and_reports_misses() {
+        let interaction = |message_id: &str| Interaction {
+            request: RecordedRequest {
+                method: "GET".to_string(),
+                path: "/open-apis/im/v1/messages/om_1".to_string(),
+                query: BTreeMap::new(),
+                headers: BTreeMap::new(),
+                body: None,
+                multipart: false,
+            },
+            response: RecordedResponse {
+                status: 200,
+                headers: BTreeMap::new(),
+                body: Some(RecordedBody::Json(
+                    json!({"code": 0, "msg": "ok", "data": {"message_id": message_id}}),
+                )),
+            },
+        };
+        let file = cassette_path("ordered.json");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        let content = serde_json::to_string(&CassetteFile {
+            version: CASSETTE_VERSION,
+            interactions: vec![interaction("first"), interaction("second")],
+        })
+        .unwrap();
+        std::fs::write(&file, content).unwrap();
+
+        let cassette = Cassette::replay(&file).unwrap().allow_repeats(false);
+        let config = config("http://127.0.0.1:9", cassette);
+        let get = || async {
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/im/v1/messages/om_1");
+            Transport::<Value>::request(req, &config, Some(option())).await
+        };
+        assert_eq!(get().await.unwrap().data.unwrap()["message_id"], "first");
+        assert_eq!(get().await.unwrap().data.unwrap()["message_id"], "second");
+        assert!(get().await.unwrap_err().to_string().contains("没有匹配"));
+    }
+
+    #[test]
+    fn test_match_rules() {
+        let request = |query: &[(&str, &str)], body: Value| RecordedRequest {
+            method: "POST".to_string(),
+            path: "/open-apis/x".to_string(),
+            query: query
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            headers: BTreeMap::new(),
+            body: Some(RecordedBody::Json(body)),
+            multipart: false,
+        };
+        let a = request(&[("page_size", "10"), ("uuid", "1")], json!({"a": 1}));
+        let b = request(&[("page_size", "10"), ("uuid", "2")], json!({"a": 2}));
+        assert!(!MatchRules::default().matches(&a, &b));
+        assert!(MatchRules::default().ignore_query("uuid").matches(&a, &b));
+        assert!(
+            !MatchRules::default()
+                .ignore_query("uuid")
+                .body(true)
+                .matches(&a, &b)
+        );
+    }
+
+    #[test]
+    fn test_scrub_nested_json() {
+        let mut value = json!({"data": [{"access_token": "x", "name": "n"}], "app_ticket": null});
+        Scrubber::default().scrub_json(&mut value);
+        assert_eq!(value["data"][0]["access_token"], REDACTED);
+        assert_eq!(value["data"][0]["name"], "n");
+        assert!(value["app_ticket"].is_null());
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            CassetteFormat::from_path(Path::new("a.yml")),
+            CassetteFormat::Yaml
+        );
+        assert_eq!(
+            CassetteFormat::from_path(Path::new("a.json")),
+            CassetteFormat::Json
+        );
+    }
+
+    #[cfg(feature = "cassette-yaml")]
+    #[test]
+    fn test_yaml_roundtrip() {
+        let file = CassetteFile {
+            version: CASSETTE_VERSION,
+            interactions: Vec::new(),
+        };
+        let content = encode(&file, CassetteFormat::Yaml).unwrap();
+        assert_eq!(decode(&content, CassetteFormat::Yaml).unwrap().version, 1);
+    }
+}
@@ -1,8 +1,13 @@
 use std::{collections::HashMap, ops::Deref, sync::Arc, time::Duration};
 
 use crate::{
-    auth::token_provider::{NoOpTokenProvider, TokenProvider},
+    auth::{
+        app_ticket::{AppTicketStore, MemoryAppTicketStore},
+        token_provider::{NoOpTokenProvider, TokenProvider},
+    },
+    cassette::Cassette,
     constants::{AppType, FEISHU_BASE_URL},
+    middleware::{Middleware, MiddlewareChain},
     performance::OptimizedHttpConfig,
 };
 
@@ -62,6 +67,14 @@ pub struct ConfigInner {
     pub(crate) token_provider: Arc<dyn TokenProvider>,
     /// 响应体最大大小（字节），超过返回 ResponseTooLarge 错误，默认 100MB
     pub(crate) max_response_size: u64,
+    /// 请求/响应中间件链，默认包含追踪中间件
+    pub(crate) middlewares: MiddlewareChain,
+    /// 录制/回放磁带，设置后由 Transport 接管 HTTP 收发
+    pub(crate) cassette: Option<Arc<Cassette>>,
+    /// 默认租户 key，请求未指定 `RequestOption::tenant_key` 时使用（商店应用）
+    pub(crate) tenant_key: Option<String>,
+    /// 应用票据存储（商店应用换取 app_access_token 时读取）
+    pub(crate) app_ticket_store: Option<Arc<dyn AppTicketStore>>,
 }
 
 impl Default for ConfigInner {
@@ -77,6 +90,10 @@ impl Default for ConfigInner {
             header: Default::default(),
             token_provider: Arc::new(NoOpTokenProvider),
             max_response_size: 100 * 1024 * 1024, // 100MB
+            middlewares: MiddlewareChain::with_defaults(),
+            cassette: None,
+            tenant_key: None,
+            app_ticket_store: None,
         }
     }
 }
@@ -92,6 +109,13 @@ impl std::fmt::Debug for ConfigInner {
             .field("req_timeout", &self.req_timeout)
             .field("max_response_size", &self.max_response_size)
             .field("header", &format!("{} headers", self.header.len()))
+            .field("middlewares", &self.middlewares)
+            .field(
+                "cassette",
+                &self.cassette.as_ref().map(|c| c.path().to_path_buf()),
+            )
+            .field("tenant_key", &self.tenant_key)
+            .field("app_ticket_store", &self.app_ticket_store)
             .finish()
     }
 }
@@ -143,6 +167,77 @@ impl Config {
             header: self.header.clone(),
             token_provider: Arc::new(provider),
             max_response_size: self.max_response_size,
+            middlewares: self.middlewares.clone(),
+            cassette: self.cassette.clone(),
+            tenant_key: self.tenant_key.clone(),
+            app_ticket_store: self.app_ticket_store.clone(),
+        })
+    }
+
+    /// 基于当前配置生成一个“追加中间件”的新配置
+    ///
+    /// 与 [`Config::with_token_provider`] 一样是纯拷贝操作，新中间件追加在链尾。
+    pub fn with_middleware(&self, middleware: impl Middleware + 'static) -> Self {
+        let mut middlewares = self.middlewares.clone();
+        middlewares.push(Arc::new(middleware));
+        Config::new(ConfigInner {
+            app_id: self.app_id.clone(),
+            app_secret: self.app_secret.clone(),
+            base_url: self.base_url.clone(),
+            enable_token_cache: self.enable_token_cache,
+            app_type: self.app_type,
+            http_client: self.http_client.clone(),
+            req_timeout: self.req_timeout,
+            header: self.header.clone(),
+            token_provider: self.token_provider.clone(),
+            max_response_size: self.max_response_size,
+            middlewares,
+            cassette: self.cassette.clone(),
+            tenant_key: self.tenant_key.clone(),
+            app_ticket_store: self.app_ticket_store.clone(),
+        })
+    }
+
+    /// 基于当前配置生成一个挂载录制/回放磁带的新配置
+    pub fn with_cassette(&self, cassette: Cassette) -> Self {
+        Config::new(ConfigInner {
+            app_id: self.app_id.clone(),
+            app_secret: self.app_secret.clone(),
+            base_url: self.base_url.clone(),
+            enable_token_cache: self.enable_token_cache,
+            app_type: self.app_type,
+            http_client: self.http_client.clone(),
+            req_timeout: self.req_timeout,
+            header: self.header.clone(),
+            token_provider: self.token_provider.clone(),
+            max_response_size: self.max_response_size,
+            middlewares: self.middlewares.clone(),
+            cassette: Some(Arc::new(cassette)),
+            tenant_key: self.tenant_key.clone(),
+            app_ticket_store: self.app_ticket_store.clone(),
+        })
+    }
+
+    /// 基于当前配置生成一个绑定默认租户 key 的新配置
+    ///
+    /// 商店应用以 `tenant_access_token` 调用接口时需要 `tenant_key`；绑定后无需在每次请求的
+    /// `RequestOption` 中传入，显式传入的 `tenant_key` 仍然优先。
+    pub fn with_tenant_key(&self, tenant_key: impl Into<String>) -> Self {
+        Config::new(ConfigInner {
+            app_id: self.app_id.clone(),
+            app_secret: self.app_secret.clone(),
+            base_url: self.base_url.clone(),
+            enable_token_cache: self.enable_token_cache,
+            app_type: self.app_type,
+            http_client: self.http_client.clone(),
+            req_timeout: self.req_timeout,
+            header: self.header.clone(),
+            token_provider: self.token_provider.clone(),
+            max_response_size: self.max_response_size,
+            middlewares: self.middlewares.clone(),
+            cassette: self.cassette.clone(),
+            tenant_key: Some(tenant_key.into()),
+            app_ticket_store: self.app_ticket_store.clone(),
         })
     }
 
@@ -200,6 +295,26 @@ impl Config {
     pub fn max_response_size(&self) -> u64 {
         self.inner.max_response_size
     }
+
+    /// 获取中间件链引用
+    pub fn middlewares(&self) -> &MiddlewareChain {
+        &self.inner.middlewares
+    }
+
+    /// 获取默认租户 key
+    pub fn tenant_key(&self) -> Option<&str> {
+        self.inner.tenant_key.as_deref()
+    }
+
+    /// 获取应用票据存储
+    pub fn app_ticket_store(&self) -> Option<&Arc<dyn AppTicketStore>> {
+        self.inner.app_ticket_store.as_ref()
+    }
+
+    /// 获取录制/回放磁带
+    pub fn cassette(&self) -> Option<&Arc<Cassette>> {
+        self.inner.cassette.as_ref()
+    }
 }
 
 /// 配置构建器
@@ -215,6 +330,11 @@ pub struct ConfigBuilder {
     header: Option<HashMap<String, String>>,
     token_provider: Option<Arc<dyn TokenProvider>>,
     max_response_size: Option<u64>,
+    default_middlewares: Option<bool>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    cassette: Option<Arc<Cassette>>,
+    tenant_key: Option<String>,
+    app_ticket_store: Option<Arc<dyn AppTicketStore>>,
 }
 
 impl ConfigBuilder {
@@ -306,9 +426,40 @@ impl ConfigBuilder {
         self
     }
 
+    /// 注册中间件，按注册顺序执行 `before_request`，逆序执行 `after_response`
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// 挂载录制/回放磁带，见 [`crate::cassette`]
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(Arc::new(cassette));
+        self
+    }
+
+    /// 设置默认租户 key
+    pub fn tenant_key(mut self, tenant_key: impl Into<String>) -> Self {
+        self.tenant_key = Some(tenant_key.into());
+        self
+    }
+
+    /// 设置应用票据存储，商店应用未设置时默认使用内存存储
+    pub fn app_ticket_store(mut self, store: impl AppTicketStore + 'static) -> Self {
+        self.app_ticket_store = Some(Arc::new(store));
+        self
+    }
+
+    /// 是否启用默认中间件（追踪中间件），默认启用
+    pub fn default_middlewares(mut self, enable: bool) -> Self {
+        self.default_middlewares = Some(enable);
+        self
+    }
+
     /// 构建 Config 实例
     pub fn build(self) -> Config {
         let default = ConfigInner::default();
+        let app_type = self.app_type.unwrap_or(default.app_type);
         Config::new(ConfigInner {
             app_id: self.app_id.unwrap_or(default.app_id),
             app_secret: self.app_secret.unwrap_or(default.app_secret),
@@ -316,12 +467,33 @@ impl ConfigBuilder {
             enable_token_cache: self
                 .enable_token_cache
                 .unwrap_or(default.enable_token_cache),
-            app_type: self.app_type.unwrap_or(default.app_type),
+            app_type,
             http_client: self.http_client.unwrap_or(default.http_client),
             req_timeout: self.req_timeout.or(default.req_timeout),
             header: self.header.unwrap_or(default.header),
             token_provider: self.token_provider.unwrap_or(default.token_provider),
             max_response_size: self.max_response_size.unwrap_or(default.max_response_size),
+            middlewares: {
+                let mut chain = if self.default_middlewares.unwrap_or(true) {
+                    default.middlewares
+                } else {
+                    MiddlewareChain::new()
+                };
+                for middleware in self.middlewares {
+                    chain.push(middleware);
+                }
+                chain
+            },
+            cassette: self.cassette,
+            tenant_key: self.tenant_key,
+            app_ticket_store: match self.app_ticket_store {
+                Some(store) => Some(store),
+                // 商店应用默认挂载内存存储，便于事件处理器直接写入
+                None if app_type == AppType::Marketplace => {
+                    Some(Arc::new(MemoryAppTicketStore::new()))
+                }
+                None => None,
+            },
         })
     }
 }
@@ -349,6 +521,10 @@ mod tests {
             header: HashMap::new(),
             token_provider: Arc::new(NoOpTokenProvider),
             max_response_size: 100 * 1024 * 1024,
+            middlewares: MiddlewareChain::new(),
+            cassette: None,
+            tenant_key: None,
+            app_ticket_store: None,
         });
 
         assert_eq!(config.app_id, "test_app_id");
@@ -388,6 +564,10 @@ mod tests {
             },
             token_provider: Arc::new(NoOpTokenProvider),
             max_response_size: 100 * 1024 * 1024,
+            middlewares: MiddlewareChain::new(),
+            cassette: None,
+            tenant_key: None,
+            app_ticket_store: None,
         });
 
         let cloned_config = config.clone();
@@ -98,6 +98,10 @@ pub const HTTP_HEADER_REQUEST_ID: &str = "Request-Id";
 
 /// X-Tt-Logid HTTP 请求头键名
 pub const HTTP_HEADER_KEY_LOG_ID: &str = "X-Tt-Logid";
+/// 网关限流配额响应头键名
+pub const HTTP_HEADER_RATELIMIT_LIMIT: &str = "x-ogw-ratelimit-limit";
+/// 网关限流重置时间（秒）响应头键名
+pub const HTTP_HEADER_RATELIMIT_RESET: &str = "x-ogw-ratelimit-reset";
 /// Content-Type HTTP 头名称
 pub const CONTENT_TYPE_HEADER: &str = "Content-Type";
 /// JSON Content-Type 值
@@ -4,6 +4,8 @@
 
 use std::collections::HashMap;
 
+use crate::api::ResponseMeta;
+
 /// 现代化错误上下文
 ///
 /// 提供丰富的错误上下文信息，支持结构化数据和链式错误追踪
@@ -21,6 +23,8 @@ pub struct ErrorContext {
     operation: Option<String>,
     /// 组件名称
     component: Option<String>,
+    /// 响应元信息（HTTP 状态码、响应头、日志 ID 等）
+    response_meta: Option<Box<ResponseMeta>>,
 }
 
 impl ErrorContext {
@@ -33,6 +37,7 @@ impl ErrorContext {
             request_id: None,
             operation: None,
             component: None,
+            response_meta: None,
         }
     }
 
@@ -45,6 +50,7 @@ impl ErrorContext {
             request_id: None,
             operation: None,
             component: None,
+            response_meta: None,
         }
     }
 
@@ -57,6 +63,7 @@ impl ErrorContext {
             request_id: None,
             operation: Some(operation.into()),
             component: None,
+            response_meta: None,
         }
     }
 
@@ -136,6 +143,31 @@ impl ErrorContext {
         self.component.as_deref()
     }
 
+    /// 设置响应元信息
+    ///
+    /// 未设置请求 ID 时，使用响应中的 `X-Tt-Logid` 作为请求 ID。
+    pub fn set_response_meta(&mut self, meta: ResponseMeta) -> &mut Self {
+        if self.request_id.is_none()
+            && let Some(log_id) = &meta.log_id
+        {
+            self.request_id = Some(log_id.clone());
+        }
+        self.response_meta = Some(Box::new(meta));
+        self
+    }
+
+    /// 获取响应元信息
+    pub fn response_meta(&self) -> Option<&ResponseMeta> {
+        self.response_meta.as_deref()
+    }
+
+    /// 获取 `X-Tt-Logid`
+    pub fn log_id(&self) -> Option<&str> {
+        self.response_meta
+            .as_ref()
+            .and_then(|meta| meta.log_id.as_deref())
+    }
+
     /// 获取时间戳
     pub fn timestamp(&self) -> Option<std::time::SystemTime> {
         self.timestamp
@@ -159,6 +191,7 @@ impl ErrorContext {
             && self.request_id.is_none()
             && self.operation.is_none()
             && self.component.is_none()
+            && self.response_meta.is_none()
     }
 
     /// 转换为调试格式
@@ -191,6 +224,13 @@ impl ErrorContext {
             parts.push(format!("请求ID: {request_id}"));
         }
 
+        if let Some(meta) = &self.response_meta {
+            parts.push(format!("HTTP状态: {}", meta.status));
+            if let Some(log_id) = &meta.log_id {
+                parts.push(format!("日志ID: {log_id}"));
+            }
+        }
+
         if !self.context.is_empty() {
             parts.push("上下文:".to_string());
             for (key, value) in &self.context {
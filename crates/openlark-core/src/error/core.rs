@@ -987,6 +987,23 @@ impl CoreError {
         })
     }
 
+    /// 附加响应元信息（HTTP 状态码、响应头、`X-Tt-Logid`、限流信息）。
+    pub fn with_response_meta(self, meta: crate::api::ResponseMeta) -> Self {
+        self.map_context(|ctx| {
+            ctx.set_response_meta(meta);
+        })
+    }
+
+    /// 获取响应元信息
+    pub fn response_meta(&self) -> Option<&crate::api::ResponseMeta> {
+        self.ctx().response_meta()
+    }
+
+    /// 获取 `X-Tt-Logid`，向飞书技术支持反馈问题时使用
+    pub fn log_id(&self) -> Option<&str> {
+        self.ctx().log_id()
+    }
+
     /// 附加请求 ID，上下游都使用统一字段传播链路标识。
     pub fn with_request_id(self, request_id: impl Into<String>) -> Self {
         let request_id = request_id.into();
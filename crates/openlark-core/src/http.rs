@@ -6,14 +6,16 @@ use tracing::{Instrument, info_span};
 
 use crate::{
     SDKResult,
-    api::ApiResponseTrait,
-    api::{ApiRequest, Response},
+    api::{ApiRequest, RawResponse, Response, ResponseMeta, meta::record_meta},
+    api::{ApiResponseTrait, ByteStream},
     auth::app_ticket::apply_app_ticket,
     config::Config,
     constants::*,
-    error::CoreError,
+    error::{CoreError, ErrorCode, network_error},
+    middleware::RequestContext,
     req_option::RequestOption,
     req_translator::ReqTranslator,
+    request_builder::AuthHandler,
     response_handler::ImprovedResponseHandler,
 };
 
@@ -65,46 +67,9 @@ impl<T: ApiResponseTrait + std::fmt::Debug + for<'de> serde::Deserialize<'de>> T
         );
 
         async move {
-            let start_time = std::time::Instant::now();
-            let option = option.unwrap_or_default();
-
-            let mut token_types = req.supported_access_token_types();
-            if token_types.is_empty() {
-                token_types = vec![AccessTokenType::None];
-            }
-
-            let result: Result<_, _> = async {
-                validate_token_type(&token_types, &option)?;
-                let access_token_type =
-                    determine_token_type(&token_types, &option, config.enable_token_cache);
-                validate(config, &option, access_token_type)?;
-
-                Self::do_request(req, access_token_type, config, option).await
-            }
-            .await;
-
-            // Record metrics in current span
-            let current_span = tracing::Span::current();
-            let duration_ms = start_time.elapsed().as_millis() as u64;
-            current_span.record("duration_ms", duration_ms);
-
-            match &result {
-                Ok(response) => {
-                    current_span.record(
-                        "status",
-                        if response.is_success() {
-                            "success"
-                        } else {
-                            "api_error"
-                        },
-                    );
-                }
-                Err(_) => {
-                    current_span.record("status", "error");
-                }
-            }
-
-            result
+            let option = effective_option(config, option);
+            let access_token_type = resolve_token_type(&req, config, &option)?;
+            Self::do_request(req, access_token_type, config, option).await
         }
         .instrument(span)
         .await
@@ -116,27 +81,23 @@ impl<T: ApiResponseTrait + std::fmt::Debug + for<'de> serde::Deserialize<'de>> T
         config: &Config,
         option: RequestOption,
     ) -> SDKResult<Response<T>> {
-        let req =
-            ReqTranslator::translate(&mut http_req, access_token_type, config, &option).await?;
-        debug!(
-            method = %http_req.method(),
-            path = %http_req.api_path(),
-            "Sending request"
-        );
-        let resp = Self::do_send(
-            req,
-            http_req.to_bytes(),
-            !http_req.file().is_empty(),
-            config.max_response_size(),
-        )
-        .await?;
-        debug!(
-            success = resp.is_success(),
-            code = resp.raw_response.code,
-            msg = %resp.raw_response.msg,
-            "Received response"
-        );
+        let mut result = Self::send_once(&mut http_req, access_token_type, config, &option).await;
+        let token_rejected =
+            matches!(&result, Ok(resp) if is_token_invalid(resp.raw_response.code));
+        if token_rejected
+            && let Some(token_request) =
+                AuthHandler::provider_request(access_token_type, config, &option)
+        {
+            debug!("token 被服务端判定无效，作废缓存后重试一次");
+            config.token_provider().invalidate(token_request).await;
+            result = Self::send_once(&mut http_req, access_token_type, config, &option).await;
+            match &mut result {
+                Ok(resp) => resp.meta.retry_count = 1,
+                Err(err) => mark_retried(err),
+            }
+        }
 
+        let resp = result?;
         if !resp.is_success() && resp.raw_response.code == ERR_CODE_APP_TICKET_INVALID {
             apply_app_ticket(config).await?;
         }
@@ -144,6 +105,40 @@ impl<T: ApiResponseTrait + std::fmt::Debug + for<'de> serde::Deserialize<'de>> T
         Ok(resp)
     }
 
+    /// 发出一次请求（含中间件）并记录响应元信息
+    async fn send_once<R: Send>(
+        http_req: &mut ApiRequest<R>,
+        access_token_type: AccessTokenType,
+        config: &Config,
+        option: &RequestOption,
+    ) -> SDKResult<Response<T>> {
+        let (ctx, entered, dispatched) =
+            dispatch(http_req, access_token_type, config, option).await?;
+        let mut result = match dispatched {
+            Dispatched::ShortCircuit(raw) => raw.map(response_from_raw),
+            Dispatched::Http(sent) => match sent {
+                Ok((response, start_time)) => {
+                    Self::handle_http(response, start_time, config.max_response_size()).await
+                }
+                Err(err) => Err(err),
+            },
+        };
+        after_response(config, &ctx, entered, &mut result, |resp| {
+            &resp.raw_response
+        })
+        .await;
+
+        match &result {
+            Ok(resp) => record_meta(&resp.meta),
+            Err(err) => {
+                if let Some(meta) = err.response_meta() {
+                    record_meta(meta);
+                }
+            }
+        }
+        result
+    }
+
     /// 执行 HTTP 请求
     pub async fn do_send(
         raw_request: RequestBuilder,
@@ -151,40 +146,299 @@ impl<T: ApiResponseTrait + std::fmt::Debug + for<'de> serde::Deserialize<'de>> T
         multi_part: bool,
         max_response_size: u64,
     ) -> SDKResult<Response<T>> {
-        // Create span for network request tracing
+        let (response, start_time) = send_builder(raw_request, body, multi_part).await?;
+        Self::handle_http(response, start_time, max_response_size).await
+    }
+
+    /// 处理 HTTP 响应：采集响应元信息并按目标格式解析
+    async fn handle_http(
+        response: reqwest::Response,
+        start_time: std::time::Instant,
+        max_response_size: u64,
+    ) -> SDKResult<Response<T>> {
+        let status_code = response.status();
+        tracing::Span::current().record("response_code", status_code.as_u16());
+
+        let mut meta = ResponseMeta::from_http(status_code.as_u16(), response.headers());
+
+        // 使用改进的响应处理器，单次解析而非双重解析
+        let result = ImprovedResponseHandler::handle_response(response, max_response_size).await;
+        meta.latency = start_time.elapsed();
+        match result {
+            Ok(mut resp) => {
+                if resp.raw_response.request_id.is_none() {
+                    resp.raw_response.request_id = meta.log_id.clone();
+                }
+                resp.meta = meta;
+                Ok(resp)
+            }
+            Err(err) => Err(err.with_response_meta(meta)),
+        }
+    }
+}
+
+impl Transport<ByteStream> {
+    /// 发送 API 请求并以流的形式返回响应体
+    ///
+    /// 适用于文件下载等大响应体接口：与 [`Transport::request`] 共享令牌注入、
+    /// 中间件、录制回放与链路追踪，但响应体不会一次性读入内存。
+    /// 接口返回 `{"code": ..., "msg": ...}` 错误时转为 `CoreError`。
+    pub async fn request_stream<R: Send>(
+        mut req: ApiRequest<R>,
+        config: &Config,
+        option: Option<RequestOption>,
+    ) -> SDKResult<ByteStream> {
         let span = info_span!(
-            "http_send",
-            multi_part = multi_part,
-            body_size = body.len(),
-            response_code = tracing::field::Empty,
-            response_size = tracing::field::Empty,
+            "http_request",
+            method = %req.method(),
+            path = %req.api_path(),
+            app_id = %config.app_id,
+            duration_ms = tracing::field::Empty,
+            status = tracing::field::Empty,
         );
 
         async move {
-            let future = if multi_part {
-                raw_request.send()
-            } else {
-                raw_request.body(body).send()
-            };
-
-            match future.await {
-                Ok(response) => {
-                    let status_code = response.status();
-                    tracing::Span::current().record("response_code", status_code.as_u16());
-
-                    // 使用改进的响应处理器，单次解析而非双重解析
-                    ImprovedResponseHandler::handle_response(response, max_response_size).await
-                }
-                Err(err) => {
-                    debug!("Request error: {err:?}");
-                    tracing::Span::current().record("response_code", 0_u16); // Indicate network error
-                    Err(err.into())
+            let option = effective_option(config, option);
+            let access_token_type = resolve_token_type(&req, config, &option)?;
+            let mut result = Self::stream_once(&mut req, access_token_type, config, &option).await;
+            let token_rejected = matches!(&result, Err(err) if is_token_error(err));
+            if token_rejected
+                && let Some(token_request) =
+                    AuthHandler::provider_request(access_token_type, config, &option)
+            {
+                debug!("token 被服务端判定无效，作废缓存后重试一次");
+                config.token_provider().invalidate(token_request).await;
+                result = Self::stream_once(&mut req, access_token_type, config, &option).await;
+                match &mut result {
+                    Ok(stream) => stream.meta_mut().retry_count = 1,
+                    Err(err) => mark_retried(err),
                 }
             }
+            result
         }
         .instrument(span)
         .await
     }
+
+    async fn stream_once<R: Send>(
+        req: &mut ApiRequest<R>,
+        access_token_type: AccessTokenType,
+        config: &Config,
+        option: &RequestOption,
+    ) -> SDKResult<ByteStream> {
+        let (ctx, entered, dispatched) = dispatch(req, access_token_type, config, option).await?;
+        let mut result = match dispatched {
+            Dispatched::ShortCircuit(raw) => raw.and_then(ByteStream::from_raw),
+            Dispatched::Http(sent) => match sent {
+                Ok((response, start_time)) => {
+                    ByteStream::from_http(
+                        response,
+                        start_time,
+                        req.api_path(),
+                        config.max_response_size(),
+                    )
+                    .await
+                }
+                Err(err) => Err(err),
+            },
+        };
+        after_response(config, &ctx, entered, &mut result, ByteStream::raw_response).await;
+
+        match &result {
+            Ok(stream) => record_meta(stream.meta()),
+            Err(err) => {
+                if let Some(meta) = err.response_meta() {
+                    record_meta(meta);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// 服务端判定 access token 无效或已过期的错误码
+fn is_token_invalid(code: i32) -> bool {
+    matches!(
+        code,
+        ERR_CODE_ACCESS_TOKEN_INVALID
+            | ERR_CODE_TENANT_ACCESS_TOKEN_INVALID
+            | ERR_CODE_APP_ACCESS_TOKEN_INVALID
+    )
+}
+
+fn is_token_error(err: &CoreError) -> bool {
+    matches!(
+        err.code(),
+        ErrorCode::AccessTokenInvalid
+            | ErrorCode::TenantAccessTokenInvalid
+            | ErrorCode::AppAccessTokenInvalid
+    )
+}
+
+/// 在重试后的错误上记录重试次数
+fn mark_retried(err: &mut CoreError) {
+    if let Some(mut meta) = err.response_meta().cloned() {
+        meta.retry_count = 1;
+        let taken = std::mem::replace(err, network_error(""));
+        *err = taken.with_response_meta(meta);
+    }
+}
+
+/// 中间件 `before_request` 之后的分发结果
+enum Dispatched {
+    /// 中间件短路返回
+    ShortCircuit(SDKResult<RawResponse>),
+    /// 已发出的 HTTP 请求及其发送时间
+    Http(SDKResult<(reqwest::Response, std::time::Instant)>),
+}
+
+/// 合并配置级默认值：未显式指定 `tenant_key` 时使用 `Config` 绑定的租户
+fn effective_option(config: &Config, option: Option<RequestOption>) -> RequestOption {
+    let mut option = option.unwrap_or_default();
+    if option.tenant_key.is_none() {
+        option.tenant_key = config.tenant_key().map(str::to_string);
+    }
+    option
+}
+
+/// 校验并确定本次请求使用的令牌类型
+fn resolve_token_type<R>(
+    req: &ApiRequest<R>,
+    config: &Config,
+    option: &RequestOption,
+) -> SDKResult<AccessTokenType> {
+    let mut token_types = req.supported_access_token_types();
+    if token_types.is_empty() {
+        token_types = vec![AccessTokenType::None];
+    }
+
+    validate_token_type(&token_types, option)?;
+    let access_token_type = determine_token_type(&token_types, option, config.enable_token_cache);
+    validate(config, option, access_token_type)?;
+    Ok(access_token_type)
+}
+
+/// 构建请求并依次执行中间件 `before_request`，未被短路时发出 HTTP 请求
+///
+/// 返回请求上下文、已进入的中间件数量与分发结果。
+async fn dispatch<R: Send>(
+    http_req: &mut ApiRequest<R>,
+    access_token_type: AccessTokenType,
+    config: &Config,
+    option: &RequestOption,
+) -> SDKResult<(RequestContext, usize, Dispatched)> {
+    let req = ReqTranslator::translate(http_req, access_token_type, config, option).await?;
+    let (client, request) = req.build_split();
+    let mut ctx = RequestContext::new(
+        *http_req.method(),
+        http_req.api_path(),
+        access_token_type,
+        config.app_id(),
+        request?,
+        http_req.to_bytes(),
+        !http_req.file().is_empty(),
+    );
+
+    // before_request 按注册顺序执行，任一中间件可短路
+    let middlewares = config.middlewares();
+    let mut entered = 0;
+    for (index, middleware) in middlewares.iter().enumerate() {
+        entered = index + 1;
+        match middleware.before_request(&mut ctx).await {
+            Ok(None) => {}
+            Ok(Some(raw)) => return Ok((ctx, entered, Dispatched::ShortCircuit(Ok(raw)))),
+            Err(err) => return Ok((ctx, entered, Dispatched::ShortCircuit(Err(err)))),
+        }
+    }
+
+    let sent = match ctx.take_request() {
+        Some((request, body, multi_part)) => match config.cassette() {
+            Some(cassette) => {
+                let start_time = std::time::Instant::now();
+                cassette
+                    .send(client, request, body, multi_part)
+                    .await
+                    .map(|response| (response, start_time))
+            }
+            None => {
+                send_builder(
+                    RequestBuilder::from_parts(client, request),
+                    body,
+                    multi_part,
+                )
+                .await
+            }
+        },
+        None => Err(network_error("请求已被中间件消费")),
+    };
+    Ok((ctx, entered, Dispatched::Http(sent)))
+}
+
+/// after_response 逆序执行，仅覆盖已进入的中间件
+async fn after_response<X>(
+    config: &Config,
+    ctx: &RequestContext,
+    entered: usize,
+    result: &mut SDKResult<X>,
+    raw_of: fn(&X) -> &RawResponse,
+) {
+    for middleware in config.middlewares().iter().take(entered).rev() {
+        let outcome = result.as_ref().map(raw_of);
+        if let Err(err) = middleware.after_response(ctx, outcome).await {
+            *result = Err(err);
+        }
+    }
+}
+
+/// 发出 HTTP 请求，返回响应及发送时间
+async fn send_builder(
+    raw_request: RequestBuilder,
+    body: Vec<u8>,
+    multi_part: bool,
+) -> SDKResult<(reqwest::Response, std::time::Instant)> {
+    // Create span for network request tracing
+    let span = info_span!(
+        "http_send",
+        multi_part = multi_part,
+        body_size = body.len(),
+        response_code = tracing::field::Empty,
+        response_size = tracing::field::Empty,
+    );
+
+    async move {
+        let start_time = std::time::Instant::now();
+        let future = if multi_part {
+            raw_request.send()
+        } else {
+            raw_request.body(body).send()
+        };
+
+        match future.await {
+            Ok(response) => {
+                tracing::Span::current().record("response_code", response.status().as_u16());
+                Ok((response, start_time))
+            }
+            Err(err) => {
+                debug!("Request error: {err:?}");
+                tracing::Span::current().record("response_code", 0_u16); // Indicate network error
+                Err(err.into())
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+/// 将中间件短路返回的原始响应转换为类型化响应
+fn response_from_raw<T: for<'de> serde::Deserialize<'de>>(raw: RawResponse) -> Response<T> {
+    let data = if raw.is_success() {
+        raw.data
+            .clone()
+            .and_then(|value| serde_json::from_value(value).ok())
+    } else {
+        None
+    };
+    Response::new(data, raw)
 }
 
 fn validate_token_type(
@@ -344,7 +598,7 @@ mod test {
     use crate::{
         config::Config,
         constants::{AccessTokenType, AppType, HTTP_HEADER_KEY_REQUEST_ID, HTTP_HEADER_REQUEST_ID},
-        http::{determine_token_type, validate, validate_token_type},
+        http::{determine_token_type, effective_option, validate, validate_token_type},
         req_option::RequestOption,
     };
 
@@ -1030,4 +1284,416 @@ mod test {
             panic!("Expected IllegalParamError for empty app_secret");
         }
     }
+
+    #[test]
+    fn test_effective_option_uses_config_tenant_key() {
+        let config = create_test_config_marketplace().with_tenant_key("tenant_a");
+        let option = effective_option(&config, None);
+        assert_eq!(option.tenant_key.as_deref(), Some("tenant_a"));
+        assert!(validate(&config, &option, AccessTokenType::Tenant).is_ok());
+
+        let explicit = RequestOption::builder().tenant_key("tenant_b").build();
+        let option = effective_option(&config, Some(explicit));
+        assert_eq!(option.tenant_key.as_deref(), Some("tenant_b"));
+    }
+
+    mod middleware_chain {
+        use std::sync::{Arc, Mutex};
+
+        use serde_json::{Value, json};
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{header, method, path},
+        };
+
+        use crate::{
+            SDKResult,
+            api::{ApiRequest, RawResponse, Response},
+            config::Config,
+            error::CoreError,
+            http::Transport,
+            middleware::{HeaderMiddleware, Middleware, MiddlewareFuture, RequestContext},
+            req_option::RequestOption,
+        };
+
+        /// 记录调用顺序，并可按需短路
+        #[derive(Debug)]
+        struct Recorder {
+            name: &'static str,
+            log: Arc<Mutex<Vec<String>>>,
+            short_circuit: Option<RawResponse>,
+            fail_after: bool,
+        }
+
+        impl Recorder {
+            fn new(name: &'static str, log: &Arc<Mutex<Vec<String>>>) -> Self {
+                Self {
+                    name,
+                    log: log.clone(),
+                    short_circuit: None,
+                    fail_after: false,
+                }
+            }
+        }
+
+        impl Middleware for Recorder {
+            fn before_request<'a>(
+                &'a self,
+                ctx: &'a mut RequestContext,
+            ) -> MiddlewareFuture<'a, SDKResult<Option<RawResponse>>> {
+                Box::pin(async move {
+                    self.log.lock().unwrap().push(format!(
+                        "before:{}:{}",
+                        self.name,
+                        ctx.access_token_type()
+                    ));
+                    Ok(self.short_circuit.clone())
+                })
+            }
+
+            fn after_response<'a>(
+                &'a self,
+                _ctx: &'a RequestContext,
+                result: Result<&'a RawResponse, &'a CoreError>,
+            ) -> MiddlewareFuture<'a, SDKResult<()>> {
+                Box::pin(async move {
+                    let outcome = match result {
+                        Ok(raw) => raw.code.to_string(),
+                        Err(_) => "err".to_string(),
+                    };
+                    self.log
+                        .lock()
+                        .unwrap()
+                        .push(format!("after:{}:{outcome}", self.name));
+                    if self.fail_after {
+                        return Err(CoreError::validation_msg("rejected by middleware"));
+                    }
+                    Ok(())
+                })
+            }
+        }
+
+        fn user_option() -> RequestOption {
+            RequestOption::builder()
+                .user_access_token("test_token")
+                .build()
+        }
+
+        #[tokio::test]
+        async fn test_middlewares_wrap_request_in_onion_order() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/open-apis/test"))
+                .and(header("X-Source", "middleware"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json!({"code": 0, "msg": "ok", "data": {"v": 1}})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let config = Config::builder()
+                .app_id("app")
+                .app_secret("secret")
+                .base_url(server.uri())
+                .enable_token_cache(false)
+                .middleware(Recorder::new("a", &log))
+                .middleware(HeaderMiddleware::new().header("X-Source", "middleware"))
+                .middleware(Recorder::new("b", &log))
+                .build();
+            assert_eq!(config.middlewares().len(), 4);
+
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let resp: Response<Value> = Transport::request(req, &config, Some(user_option()))
+                .await
+                .unwrap();
+            assert_eq!(resp.data.unwrap()["v"], 1);
+            assert_eq!(
+                *log.lock().unwrap(),
+                vec![
+                    "before:a:user_access_token",
+                    "before:b:user_access_token",
+                    "after:b:0",
+                    "after:a:0",
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_middleware_short_circuit_skips_network() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(0)
+                .mount(&server)
+                .await;
+
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let mut cached = Recorder::new("cache", &log);
+            cached.short_circuit = Some(RawResponse::success_with_data(json!({"v": 2})));
+            let config = Config::builder()
+                .app_id("app")
+                .app_secret("secret")
+                .base_url(server.uri())
+                .enable_token_cache(false)
+                .default_middlewares(false)
+                .middleware(cached)
+                .middleware(Recorder::new("never", &log))
+                .build();
+
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let resp: Response<Value> = Transport::request(req, &config, Some(user_option()))
+                .await
+                .unwrap();
+            assert_eq!(resp.data.unwrap()["v"], 2);
+            assert_eq!(
+                *log.lock().unwrap(),
+                vec!["before:cache:user_access_token", "after:cache:0"]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_after_response_error_replaces_result() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(json!({"code": 0, "msg": "ok"})),
+                )
+                .mount(&server)
+                .await;
+
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let mut rejecting = Recorder::new("reject", &log);
+            rejecting.fail_after = true;
+            let config = Config::builder()
+                .app_id("app")
+                .app_secret("secret")
+                .base_url(server.uri())
+                .enable_token_cache(false)
+                .middleware(Recorder::new("outer", &log))
+                .middleware(rejecting)
+                .build();
+
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let result: SDKResult<Response<Value>> =
+                Transport::request(req, &config, Some(user_option())).await;
+            assert!(result.is_err());
+            // 外层中间件观察到的是被替换后的错误
+            assert_eq!(log.lock().unwrap().last().unwrap(), "after:outer:err");
+        }
+    }
+
+    mod response_meta {
+        use serde_json::{Value, json};
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{header, method, path},
+        };
+
+        use crate::{
+            SDKResult,
+            api::{ApiRequest, Response, WithMetaExt},
+            config::Config,
+            http::Transport,
+            req_option::RequestOption,
+        };
+
+        fn config(server: &MockServer) -> Config {
+            Config::builder()
+                .app_id("app")
+                .app_secret("secret")
+                .base_url(server.uri())
+                .enable_token_cache(false)
+                .build()
+        }
+
+        fn option() -> RequestOption {
+            RequestOption::builder()
+                .user_access_token("test_token")
+                .build()
+        }
+
+        #[tokio::test]
+        async fn test_response_carries_meta() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/open-apis/test"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("X-Tt-Logid", "20261018abc")
+                        .insert_header("x-ogw-ratelimit-limit", "50")
+                        .insert_header("x-ogw-ratelimit-reset", "3")
+                        .set_body_json(json!({"code": 0, "msg": "ok", "data": {}})),
+                )
+                .mount(&server)
+                .await;
+
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let resp: Response<Value> = Transport::request(req, &config(&server), Some(option()))
+                .await
+                .unwrap();
+            assert_eq!(resp.meta.status, 200);
+            assert_eq!(resp.log_id(), Some("20261018abc"));
+            assert_eq!(resp.raw_response.request_id.as_deref(), Some("20261018abc"));
+            let rate_limit = resp.meta.rate_limit.unwrap();
+            assert_eq!(rate_limit.limit, Some(50));
+            assert_eq!(rate_limit.reset, Some(std::time::Duration::from_secs(3)));
+            assert_eq!(resp.meta.retry_count, 0);
+        }
+
+        #[tokio::test]
+        async fn test_api_error_carries_log_id() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .respond_with(
+                    ResponseTemplate::new(400)
+                        .insert_header("X-Tt-Logid", "log-bad")
+                        .set_body_json(json!({"code": 99991400, "msg": "limited"})),
+                )
+                .mount(&server)
+                .await;
+
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let resp: Response<Value> = Transport::request(req, &config(&server), Some(option()))
+                .await
+                .unwrap();
+            let err = resp.into_result().unwrap_err();
+            assert_eq!(err.log_id(), Some("log-bad"));
+            assert_eq!(err.response_meta().unwrap().status, 400);
+        }
+
+        #[tokio::test]
+        async fn test_unparseable_body_error_carries_meta() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .respond_with(
+                    ResponseTemplate::new(502)
+                        .insert_header("X-Tt-Logid", "log-gateway")
+                        .set_body_string("<html>bad gateway</html>"),
+                )
+                .mount(&server)
+                .await;
+
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let result: SDKResult<Response<Value>> =
+                Transport::request(req, &config(&server), Some(option())).await;
+            let err = result.unwrap_err();
+            assert_eq!(err.response_meta().unwrap().status, 502);
+            assert_eq!(err.ctx().request_id(), Some("log-gateway"));
+        }
+
+        #[tokio::test]
+        async fn test_with_meta_over_transport() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("X-Tt-Logid", "log-ok")
+                        .set_body_json(json!({"code": 0, "msg": "ok", "data": {"v": 1}})),
+                )
+                .mount(&server)
+                .await;
+
+            let config = config(&server);
+            let (data, meta) = async {
+                let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+                let resp: Response<Value> =
+                    Transport::request(req, &config, Some(option())).await?;
+                resp.into_result()
+            }
+            .with_meta()
+            .await
+            .unwrap();
+            assert_eq!(data["v"], 1);
+            assert_eq!(meta.log_id.as_deref(), Some("log-ok"));
+        }
+
+        #[derive(Debug, Default)]
+        struct CountingProvider {
+            fetched: std::sync::atomic::AtomicUsize,
+            invalidated: std::sync::Mutex<Vec<crate::auth::TokenRequest>>,
+        }
+
+        impl crate::auth::TokenProvider for std::sync::Arc<CountingProvider> {
+            fn get_token(
+                &self,
+                _request: crate::auth::TokenRequest,
+            ) -> std::pin::Pin<Box<dyn std::future::Future<Output = SDKResult<String>> + Send + '_>>
+            {
+                let n = self
+                    .fetched
+                    .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+                    + 1;
+                Box::pin(async move { Ok(format!("t-{n}")) })
+            }
+
+            fn invalidate(
+                &self,
+                request: crate::auth::TokenRequest,
+            ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>> {
+                self.invalidated.lock().unwrap().push(request);
+                Box::pin(async {})
+            }
+        }
+
+        async fn mount_token_rejection(server: &MockServer) {
+            Mock::given(method("GET"))
+                .and(header("Authorization", "Bearer t-1"))
+                .respond_with(ResponseTemplate::new(400).set_body_json(
+                    json!({"code": 99991663, "msg": "Invalid access token for authorization"}),
+                ))
+                .expect(1)
+                .mount(server)
+                .await;
+            Mock::given(method("GET"))
+                .and(header("Authorization", "Bearer t-2"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json!({"code": 0, "msg": "ok", "data": {"v": 2}})),
+                )
+                .mount(server)
+                .await;
+        }
+
+        #[tokio::test]
+        async fn test_token_rejection_invalidates_and_retries_once() {
+            let server = MockServer::start().await;
+            mount_token_rejection(&server).await;
+
+            let provider = std::sync::Arc::new(CountingProvider::default());
+            let config = Config::builder()
+                .app_id("app")
+                .app_secret("secret")
+                .base_url(server.uri())
+                .token_provider(provider.clone())
+                .build();
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let option = RequestOption::builder().tenant_key("tk").build();
+            let resp: Response<Value> = Transport::request(req, &config, Some(option))
+                .await
+                .unwrap();
+
+            assert!(resp.is_success());
+            assert_eq!(resp.meta.retry_count, 1);
+            let invalidated = provider.invalidated.lock().unwrap();
+            assert_eq!(invalidated.len(), 1);
+            assert_eq!(invalidated[0].tenant_key.as_deref(), Some("tk"));
+        }
+
+        #[tokio::test]
+        async fn test_explicit_token_is_not_retried() {
+            let server = MockServer::start().await;
+            mount_token_rejection(&server).await;
+
+            let req: ApiRequest<Value> = ApiRequest::get("/open-apis/test");
+            let option = RequestOption::builder().tenant_access_token("t-1").build();
+            let resp: Response<Value> = Transport::request(req, &config(&server), Some(option))
+                .await
+                .unwrap();
+            assert_eq!(resp.raw_response.code, 99991663);
+            assert_eq!(resp.meta.retry_count, 0);
+        }
+    }
 }
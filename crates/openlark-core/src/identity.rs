@@ -0,0 +1,223 @@
+//! 用户身份标识
+//!
+//! 开放平台接口使用多种方式标识同一个用户：`open_id`、`union_id`、`user_id`，
+//! 以及邮箱、手机号、工号等业务标识。[`UserRef`] 把"值 + 类型"绑定在一起，
+//! 请求构建器可直接接收并据此设置 `user_id_type` 等查询参数。
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SDKResult, error::validation_error};
+
+/// 用户标识类型。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserIdKind {
+    /// 应用内用户 ID（open_id）。
+    OpenId,
+    /// 同一开发者下跨应用的用户 ID（union_id）。
+    UnionId,
+    /// 租户内用户 ID（user_id）。
+    UserId,
+    /// 邮箱。
+    Email,
+    /// 手机号。
+    Mobile,
+    /// 工号。
+    EmployeeNo,
+}
+
+impl UserIdKind {
+    /// 全部标识类型。
+    pub const ALL: [UserIdKind; 6] = [
+        Self::OpenId,
+        Self::UnionId,
+        Self::UserId,
+        Self::Email,
+        Self::Mobile,
+        Self::EmployeeNo,
+    ];
+
+    /// 返回字符串表示，ID 类型与 `user_id_type` 查询参数取值一致。
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OpenId => "open_id",
+            Self::UnionId => "union_id",
+            Self::UserId => "user_id",
+            Self::Email => "email",
+            Self::Mobile => "mobile",
+            Self::EmployeeNo => "employee_no",
+        }
+    }
+
+    /// 是否可直接作为 `user_id_type` 使用。
+    pub fn is_user_id_type(self) -> bool {
+        matches!(self, Self::OpenId | Self::UnionId | Self::UserId)
+    }
+}
+
+impl fmt::Display for UserIdKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 类型化的用户引用。
+///
+/// ```rust
+/// use openlark_core::identity::{UserIdKind, UserRef};
+///
+/// let user = UserRef::email(" Alice@Example.com ");
+/// assert_eq!(user.kind(), UserIdKind::Email);
+/// assert_eq!(user.value(), "alice@example.com");
+/// assert!(user.user_id_type().is_none());
+///
+/// let user = UserRef::guess("ou_123");
+/// assert_eq!(user.user_id_type(), Some("open_id"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct UserRef {
+    kind: UserIdKind,
+    value: String,
+}
+
+impl UserRef {
+    /// 创建用户引用；邮箱会转为小写，所有值都会去掉首尾空白。
+    pub fn new(kind: UserIdKind, value: impl Into<String>) -> Self {
+        let value = value.into();
+        let value = match kind {
+            UserIdKind::Email => value.trim().to_lowercase(),
+            _ => value.trim().to_string(),
+        };
+        Self { kind, value }
+    }
+
+    /// open_id 引用。
+    pub fn open_id(value: impl Into<String>) -> Self {
+        Self::new(UserIdKind::OpenId, value)
+    }
+
+    /// union_id 引用。
+    pub fn union_id(value: impl Into<String>) -> Self {
+        Self::new(UserIdKind::UnionId, value)
+    }
+
+    /// user_id 引用。
+    pub fn user_id(value: impl Into<String>) -> Self {
+        Self::new(UserIdKind::UserId, value)
+    }
+
+    /// 邮箱引用。
+    pub fn email(value: impl Into<String>) -> Self {
+        Self::new(UserIdKind::Email, value)
+    }
+
+    /// 手机号引用。
+    pub fn mobile(value: impl Into<String>) -> Self {
+        Self::new(UserIdKind::Mobile, value)
+    }
+
+    /// 工号引用。
+    pub fn employee_no(value: impl Into<String>) -> Self {
+        Self::new(UserIdKind::EmployeeNo, value)
+    }
+
+    /// 根据取值格式推断类型：`ou_` 前缀为 open_id，`on_` 前缀为 union_id，
+    /// 含 `@` 为邮箱，`+` 开头为手机号，其余视为 user_id。
+    pub fn guess(value: impl Into<String>) -> Self {
+        let value = value.into();
+        let trimmed = value.trim();
+        let kind = if trimmed.starts_with("ou_") {
+            UserIdKind::OpenId
+        } else if trimmed.starts_with("on_") {
+            UserIdKind::UnionId
+        } else if trimmed.contains('@') {
+            UserIdKind::Email
+        } else if trimmed.starts_with('+') {
+            UserIdKind::Mobile
+        } else {
+            UserIdKind::UserId
+        };
+        Self::new(kind, value)
+    }
+
+    /// 标识类型。
+    pub fn kind(&self) -> UserIdKind {
+        self.kind
+    }
+
+    /// 标识值。
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// 对应的 `user_id_type` 取值；邮箱、手机号与工号返回 `None`。
+    pub fn user_id_type(&self) -> Option<&'static str> {
+        self.kind.is_user_id_type().then(|| self.kind.as_str())
+    }
+
+    /// 要求引用为 open_id / union_id / user_id，否则返回校验错误。
+    pub fn require_user_id_type(&self) -> SDKResult<&'static str> {
+        self.user_id_type().ok_or_else(|| {
+            validation_error(
+                "user",
+                format!("{} 需先解析为 open_id / union_id / user_id", self.kind),
+            )
+        })
+    }
+}
+
+/// 一批用户引用共同的 `user_id_type`；为空时返回 `None`。
+///
+/// 批量接口只接受一种 ID 类型，因此要求全部引用为同一种 open_id / union_id / user_id。
+pub fn common_user_id_type(users: &[UserRef]) -> SDKResult<Option<&'static str>> {
+    let mut common = None;
+    for user in users {
+        let id_type = user.require_user_id_type()?;
+        match common {
+            Some(existing) if existing != id_type => {
+                return Err(validation_error(
+                    "users",
+                    format!("同一请求中的用户 ID 类型必须一致：{existing} 与 {id_type}"),
+                ));
+            }
+            _ => common = Some(id_type),
+        }
+    }
+    Ok(common)
+}
+
+impl fmt::Display for UserRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.kind, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_and_require_user_id_type() {
+        assert_eq!(UserRef::guess("on_1").kind(), UserIdKind::UnionId);
+        assert_eq!(UserRef::guess("+8613800000000").kind(), UserIdKind::Mobile);
+        assert_eq!(UserRef::guess("a1b2c3").kind(), UserIdKind::UserId);
+        assert_eq!(UserRef::guess("ou_1").to_string(), "open_id:ou_1");
+
+        assert_eq!(
+            UserRef::user_id("u1").require_user_id_type().unwrap(),
+            "user_id"
+        );
+        assert!(UserRef::employee_no("E001").require_user_id_type().is_err());
+    }
+
+    #[test]
+    fn test_common_user_id_type() {
+        assert_eq!(common_user_id_type(&[]).unwrap(), None);
+        let same = [UserRef::open_id("ou_1"), UserRef::open_id("ou_2")];
+        assert_eq!(common_user_id_type(&same).unwrap(), Some("open_id"));
+        let mixed = [UserRef::open_id("ou_1"), UserRef::user_id("u_2")];
+        assert!(common_user_id_type(&mixed).is_err());
+    }
+}
@@ -6,6 +6,8 @@
 // 对外稳定导出：尽量保持"少而清晰"的公共 API（KISS）
 pub mod api;
 pub mod auth;
+/// HTTP 流量录制与回放模块（Cassette、MatchRules 等）
+pub mod cassette;
 /// 客户端配置模块（Config、ConfigBuilder 等）
 pub mod config;
 /// 全局常量定义（URL、错误码前缀、超时配置等）
@@ -14,6 +16,10 @@ pub mod constants;
 pub mod error;
 /// HTTP 客户端模块（Transport、请求构建等）
 pub mod http;
+/// 用户身份标识模块（UserRef、UserIdKind）
+pub mod identity;
+/// 请求/响应中间件模块（Middleware、MiddlewareChain、内置中间件）
+pub mod middleware;
 pub(crate) mod observability;
 pub(crate) mod query_params;
 /// 请求选项模块（RequestOption、自定义头部、租户键等）
@@ -91,6 +97,7 @@ pub mod prelude {
     pub use crate::constants::*;
     pub use crate::error::{CoreError, SDKResult, validation_error};
     pub use crate::http::Transport;
+    pub use crate::identity::{UserIdKind, UserRef};
     pub use crate::req_option::*;
     pub use crate::validate_required;
     pub use crate::validate_required_list;
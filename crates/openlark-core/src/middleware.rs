@@ -0,0 +1,428 @@
+//! 请求/响应中间件
+//!
+//! 在 `ReqTranslator::translate` 与 `Transport::do_send` 之间提供扩展点：
+//! - `before_request`：按注册顺序执行，可读取/修改请求头与请求体，也可以直接返回响应或错误（短路）
+//! - `after_response`：按注册的逆序执行（洋葱模型），可观察最终的响应或错误，并可将结果替换为错误
+//!
+//! 中间件通过 [`ConfigBuilder::middleware`](crate::config::ConfigBuilder::middleware) 注册。
+//! 默认链中包含内置的 [`TracingMiddleware`]，负责记录请求日志与 `http_request` span 的耗时/状态。
+//!
+//! ```rust,ignore
+//! use openlark_core::middleware::{HeaderMiddleware, Middleware, MiddlewareFuture, RequestContext};
+//!
+//! #[derive(Debug)]
+//! struct ReadOnlyGuard;
+//!
+//! impl Middleware for ReadOnlyGuard {
+//!     fn before_request<'a>(
+//!         &'a self,
+//!         ctx: &'a mut RequestContext,
+//!     ) -> MiddlewareFuture<'a, SDKResult<Option<RawResponse>>> {
+//!         Box::pin(async move {
+//!             if ctx.method() != HttpMethod::Get {
+//!                 return Err(CoreError::validation_msg("只读模式下禁止写操作"));
+//!             }
+//!             Ok(None)
+//!         })
+//!     }
+//! }
+//!
+//! let config = Config::builder()
+//!     .app_id("app_id")
+//!     .app_secret("app_secret")
+//!     .middleware(HeaderMiddleware::new().header("X-Request-Source", "batch-job"))
+//!     .middleware(ReadOnlyGuard)
+//!     .build();
+//! ```
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tracing::debug;
+
+use crate::{
+    SDKResult,
+    api::{HttpMethod, RawResponse},
+    constants::AccessTokenType,
+    error::CoreError,
+};
+
+/// 中间件返回的 Future
+pub type MiddlewareFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 请求上下文
+///
+/// 由 `Transport` 在请求翻译完成后创建，贯穿整条中间件链。
+pub struct RequestContext {
+    method: HttpMethod,
+    api_path: String,
+    access_token_type: AccessTokenType,
+    app_id: String,
+    url: reqwest::Url,
+    headers: HeaderMap,
+    body: Vec<u8>,
+    multipart: bool,
+    started_at: Instant,
+    request: Option<reqwest::Request>,
+}
+
+impl RequestContext {
+    pub(crate) fn new(
+        method: HttpMethod,
+        api_path: impl Into<String>,
+        access_token_type: AccessTokenType,
+        app_id: impl Into<String>,
+        request: reqwest::Request,
+        body: Vec<u8>,
+        multipart: bool,
+    ) -> Self {
+        Self {
+            method,
+            api_path: api_path.into(),
+            access_token_type,
+            app_id: app_id.into(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body,
+            multipart,
+            started_at: Instant::now(),
+            request: Some(request),
+        }
+    }
+
+    /// HTTP 方法
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    /// API 路径（不含域名）
+    pub fn api_path(&self) -> &str {
+        &self.api_path
+    }
+
+    /// 本次请求解析出的令牌类型
+    pub fn access_token_type(&self) -> AccessTokenType {
+        self.access_token_type
+    }
+
+    /// 应用 ID
+    pub fn app_id(&self) -> &str {
+        &self.app_id
+    }
+
+    /// 完整请求 URL（含查询参数）
+    pub fn url(&self) -> &reqwest::Url {
+        &self.url
+    }
+
+    /// 请求头（已包含鉴权头与 `Config`/`RequestOption` 中的自定义头）
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// 可变请求头，修改会作用于实际发送的请求
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    /// 设置请求头（覆盖同名头）
+    pub fn insert_header(&mut self, name: &str, value: &str) -> SDKResult<()> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| CoreError::validation_msg(format!("无效的请求头名称 {name}: {e}")))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|e| CoreError::validation_msg(format!("无效的请求头值: {e}")))?;
+        self.headers.insert(name, value);
+        Ok(())
+    }
+
+    /// 请求体
+    ///
+    /// multipart 请求的请求体在翻译阶段已写入，这里为空。
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// 替换请求体（对 multipart 请求无效）
+    pub fn set_body(&mut self, body: Vec<u8>) {
+        self.body = body;
+    }
+
+    /// 是否为 multipart 请求
+    pub fn is_multipart(&self) -> bool {
+        self.multipart
+    }
+
+    /// 自进入中间件链以来的耗时
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// 取出待发送的请求，并应用中间件对请求头的修改
+    pub(crate) fn take_request(&mut self) -> Option<(reqwest::Request, Vec<u8>, bool)> {
+        let mut request = self.request.take()?;
+        *request.headers_mut() = self.headers.clone();
+        Some((request, std::mem::take(&mut self.body), self.multipart))
+    }
+}
+
+impl fmt::Debug for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestContext")
+            .field("method", &self.method)
+            .field("api_path", &self.api_path)
+            .field("access_token_type", &self.access_token_type)
+            .field("app_id", &self.app_id)
+            .field("headers", &format!("{} headers", self.headers.len()))
+            .field("body_size", &self.body.len())
+            .field("multipart", &self.multipart)
+            .finish()
+    }
+}
+
+/// 请求/响应中间件
+///
+/// 两个钩子都有默认实现（直接放行），按需覆盖即可。
+pub trait Middleware: Send + Sync + fmt::Debug {
+    /// 中间件名称，用于调试输出
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// 请求发送前调用
+    ///
+    /// - `Ok(None)`：继续执行后续中间件并发送请求
+    /// - `Ok(Some(raw))`：短路，不再发送请求，`raw` 作为响应返回（`data` 按目标类型反序列化）
+    /// - `Err(e)`：短路，直接返回错误
+    fn before_request<'a>(
+        &'a self,
+        ctx: &'a mut RequestContext,
+    ) -> MiddlewareFuture<'a, SDKResult<Option<RawResponse>>> {
+        let _ = ctx;
+        Box::pin(async { Ok(None) })
+    }
+
+    /// 得到响应或错误后调用
+    ///
+    /// 仅对 `before_request` 已执行过的中间件调用；返回 `Err` 会替换最终结果。
+    fn after_response<'a>(
+        &'a self,
+        ctx: &'a RequestContext,
+        result: Result<&'a RawResponse, &'a CoreError>,
+    ) -> MiddlewareFuture<'a, SDKResult<()>> {
+        let _ = (ctx, result);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// 中间件链
+#[derive(Clone, Default)]
+pub struct MiddlewareChain {
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl MiddlewareChain {
+    /// 创建空链
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 默认链：仅包含 [`TracingMiddleware`]
+    pub fn with_defaults() -> Self {
+        let mut chain = Self::new();
+        chain.push(Arc::new(TracingMiddleware));
+        chain
+    }
+
+    /// 追加中间件
+    pub fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    /// 按注册顺序遍历
+    pub fn iter(&self) -> std::slice::Iter<'_, Arc<dyn Middleware>> {
+        self.middlewares.iter()
+    }
+
+    /// 中间件数量
+    pub fn len(&self) -> usize {
+        self.middlewares.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.middlewares.is_empty()
+    }
+
+    /// 中间件名称列表
+    pub fn names(&self) -> Vec<&str> {
+        self.middlewares.iter().map(|m| m.name()).collect()
+    }
+}
+
+impl fmt::Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+/// 内置追踪中间件
+///
+/// 输出请求/响应的 debug 日志，并在 `http_request` span 上记录 `duration_ms` 与 `status`。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingMiddleware;
+
+impl Middleware for TracingMiddleware {
+    fn name(&self) -> &str {
+        "tracing"
+    }
+
+    fn before_request<'a>(
+        &'a self,
+        ctx: &'a mut RequestContext,
+    ) -> MiddlewareFuture<'a, SDKResult<Option<RawResponse>>> {
+        Box::pin(async move {
+            debug!(
+                method = %ctx.method(),
+                path = %ctx.api_path(),
+                token_type = %ctx.access_token_type(),
+                "Sending request"
+            );
+            Ok(None)
+        })
+    }
+
+    fn after_response<'a>(
+        &'a self,
+        ctx: &'a RequestContext,
+        result: Result<&'a RawResponse, &'a CoreError>,
+    ) -> MiddlewareFuture<'a, SDKResult<()>> {
+        Box::pin(async move {
+            let span = tracing::Span::current();
+            span.record("duration_ms", ctx.elapsed().as_millis() as u64);
+            match result {
+                Ok(raw) => {
+                    debug!(
+                        success = raw.is_success(),
+                        code = raw.code,
+                        msg = %raw.msg,
+                        "Received response"
+                    );
+                    span.record(
+                        "status",
+                        if raw.is_success() {
+                            "success"
+                        } else {
+                            "api_error"
+                        },
+                    );
+                }
+                Err(err) => {
+                    debug!(error = %err, "Request failed");
+                    span.record("status", "error");
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// 内置请求头中间件：为每个请求设置固定请求头
+#[derive(Debug, Clone, Default)]
+pub struct HeaderMiddleware {
+    headers: Vec<(String, String)>,
+}
+
+impl HeaderMiddleware {
+    /// 创建空的请求头中间件
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加请求头
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Middleware for HeaderMiddleware {
+    fn name(&self) -> &str {
+        "header"
+    }
+
+    fn before_request<'a>(
+        &'a self,
+        ctx: &'a mut RequestContext,
+    ) -> MiddlewareFuture<'a, SDKResult<Option<RawResponse>>> {
+        Box::pin(async move {
+            for (name, value) in &self.headers {
+                ctx.insert_header(name, value)?;
+            }
+            Ok(None)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> RequestContext {
+        let request = reqwest::Client::new()
+            .post("https://open.feishu.cn/open-apis/test?x=1")
+            .header("Authorization", "Bearer t")
+            .build()
+            .unwrap();
+        RequestContext::new(
+            HttpMethod::Post,
+            "/open-apis/test",
+            AccessTokenType::Tenant,
+            "cli_test",
+            request,
+            b"{}".to_vec(),
+            false,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_header_middleware_inserts_headers() {
+        let mut ctx = context();
+        let middleware = HeaderMiddleware::new().header("X-Trace", "abc");
+        assert!(middleware.before_request(&mut ctx).await.unwrap().is_none());
+        assert_eq!(ctx.headers()["X-Trace"], "abc");
+        assert_eq!(ctx.headers()["Authorization"], "Bearer t");
+    }
+
+    #[test]
+    fn test_insert_header_rejects_invalid_name() {
+        let mut ctx = context();
+        assert!(ctx.insert_header("bad header", "v").is_err());
+    }
+
+    #[test]
+    fn test_take_request_applies_header_changes() {
+        let mut ctx = context();
+        ctx.headers_mut().remove("Authorization");
+        ctx.set_body(b"{\"a\":1}".to_vec());
+        let (request, body, multipart) = ctx.take_request().unwrap();
+        assert!(request.headers().get("Authorization").is_none());
+        assert_eq!(body, b"{\"a\":1}");
+        assert!(!multipart);
+        assert!(ctx.take_request().is_none());
+        assert_eq!(ctx.url().query(), Some("x=1"));
+    }
+
+    #[test]
+    fn test_chain_names() {
+        let mut chain = MiddlewareChain::with_defaults();
+        chain.push(Arc::new(HeaderMiddleware::new()));
+        assert_eq!(chain.names(), vec!["tracing", "header"]);
+        assert_eq!(format!("{chain:?}"), "[\"tracing\", \"header\"]");
+    }
+}
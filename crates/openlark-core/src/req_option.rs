@@ -107,6 +107,13 @@ impl RequestOptionBuilder {
     }
 }
 
+impl From<RequestOption> for RequestOptionBuilder {
+    /// 在已有选项的基础上继续构建
+    fn from(option: RequestOption) -> Self {
+        Self { option }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,4 +497,17 @@ mod tests {
         assert_eq!(option.file_download, default_option.file_download);
         assert_eq!(option.header.len(), default_option.header.len());
     }
+
+    #[test]
+    fn test_builder_from_existing_option() {
+        let base = RequestOption::builder()
+            .tenant_key("t1")
+            .add_header("X-A", "1")
+            .build();
+        let option = RequestOptionBuilder::from(base)
+            .add_header("X-B", "2")
+            .build();
+        assert_eq!(option.tenant_key.as_deref(), Some("t1"));
+        assert_eq!(option.header.len(), 2);
+    }
 }
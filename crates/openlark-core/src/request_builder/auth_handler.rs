@@ -34,6 +34,8 @@ impl AuthHandler {
     ) -> Result<RequestBuilder, CoreError> {
         let app_access_token = if let Some(ref token) = option.app_access_token {
             token.clone()
+        } else if Self::is_replaying(config) {
+            crate::cassette::REDACTED.to_string()
         } else if config.enable_token_cache() {
             let mut request = TokenRequest::app();
             if let Some(ref ticket) = option.app_ticket {
@@ -55,6 +57,8 @@ impl AuthHandler {
     ) -> Result<RequestBuilder, CoreError> {
         let tenant_access_token = if let Some(ref token) = option.tenant_access_token {
             token.clone()
+        } else if Self::is_replaying(config) {
+            crate::cassette::REDACTED.to_string()
         } else if config.enable_token_cache() {
             let mut request = TokenRequest::tenant();
             if let Some(ref key) = option.tenant_key {
@@ -79,6 +83,41 @@ impl AuthHandler {
         )
     }
 
+    /// 本次请求由 `TokenProvider` 提供 token 时，返回向其请求的上下文
+    ///
+    /// 调用方显式传入 token、回放磁带或关闭自动获取时返回 `None`。
+    pub(crate) fn provider_request(
+        access_token_type: AccessTokenType,
+        config: &Config,
+        option: &RequestOption,
+    ) -> Option<TokenRequest> {
+        if !config.enable_token_cache() || Self::is_replaying(config) {
+            return None;
+        }
+        let mut request = match access_token_type {
+            AccessTokenType::App if option.app_access_token.is_none() => TokenRequest::app(),
+            AccessTokenType::Tenant if option.tenant_access_token.is_none() => {
+                let mut request = TokenRequest::tenant();
+                if let Some(ref key) = option.tenant_key {
+                    request = request.tenant_key(key.clone());
+                }
+                request
+            }
+            _ => return None,
+        };
+        if let Some(ref ticket) = option.app_ticket {
+            request = request.app_ticket(ticket.clone());
+        }
+        Some(request)
+    }
+
+    /// 回放磁带时不再换取 token，录制的请求头本就已脱敏
+    fn is_replaying(config: &Config) -> bool {
+        config
+            .cassette()
+            .is_some_and(|cassette| cassette.is_replaying())
+    }
+
     /// 添加 Authorization 头
     fn add_auth_header(req_builder: RequestBuilder, token: &str) -> RequestBuilder {
         req_builder.header("Authorization", format!("Bearer {token}"))
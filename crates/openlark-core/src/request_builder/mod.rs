@@ -62,10 +62,16 @@ impl UnifiedRequestBuilder {
                 match body_data {
                     RequestData::Binary(data) if !data.is_empty() => {
                         req_builder = req_builder.body(data.clone());
-                        req_builder = req_builder.header(
-                            crate::constants::CONTENT_TYPE_HEADER,
-                            crate::constants::DEFAULT_CONTENT_TYPE,
-                        );
+                        // 调用方已通过 RequestOption 指定 Content-Type 时不再追加默认值
+                        let has_content_type = option.header.keys().any(|key| {
+                            key.eq_ignore_ascii_case(crate::constants::CONTENT_TYPE_HEADER)
+                        });
+                        if !has_content_type {
+                            req_builder = req_builder.header(
+                                crate::constants::CONTENT_TYPE_HEADER,
+                                crate::constants::DEFAULT_CONTENT_TYPE,
+                            );
+                        }
                     }
                     RequestData::Json(json) => {
                         let json_bytes = serde_json::to_vec(json).unwrap_or_else(|e| {
@@ -105,7 +111,11 @@ impl UnifiedRequestBuilder {
             }
         }
 
-        for (k, v) in &req.query {
+        for (k, v) in req
+            .query
+            .iter()
+            .chain(req.repeated_query.iter().map(|(k, v)| (k, v)))
+        {
             url.query_pairs_mut().append_pair(k, v);
         }
 
@@ -337,6 +347,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_build_url_with_repeated_query() -> Result<(), CoreError> {
+        let config = create_test_config();
+        let api_req = create_test_api_request().query_repeated("user_ids", ["u1", "u2"]);
+
+        let url = UnifiedRequestBuilder::build_url(&config, &api_req)?;
+        assert_eq!(url.query(), Some("user_ids=u1&user_ids=u2"));
+        Ok(())
+    }
+
     #[test]
     fn test_build_url_with_special_characters() -> Result<(), CoreError> {
         let config = create_test_config();
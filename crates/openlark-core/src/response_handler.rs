@@ -14,7 +14,7 @@ use serde::Deserialize;
 use std::any::Any;
 
 /// 读取响应体，带大小限制保护
-async fn read_body_with_limit(
+pub(crate) async fn read_body_with_limit(
     response: reqwest::Response,
     max_size: u64,
 ) -> Result<Vec<u8>, crate::error::CoreError> {
@@ -170,16 +170,16 @@ impl ImprovedResponseHandler {
                         tracker.validation_complete();
                         tracker.success();
 
-                        Ok(BaseResponse {
-                            raw_response: RawResponse {
+                        Ok(BaseResponse::new(
+                            data,
+                            RawResponse {
                                 code,
                                 msg,
                                 request_id: None,
                                 data: None,
                                 error: None,
                             },
-                            data,
-                        })
+                        ))
                     }
                     Err(fallback_err) => {
                         let error_msg = format!(
@@ -247,7 +247,7 @@ impl ImprovedResponseHandler {
         };
 
         tracker.success();
-        Ok(BaseResponse { raw_response, data })
+        Ok(BaseResponse::new(data, raw_response))
     }
 
     /// 处理二进制响应，包含可观测性支持
@@ -299,16 +299,16 @@ impl ImprovedResponseHandler {
         };
 
         tracker.success();
-        Ok(BaseResponse {
-            raw_response: RawResponse {
+        Ok(BaseResponse::new(
+            data,
+            RawResponse {
                 code: 0,
                 msg: "success".to_string(),
                 request_id: None,
                 data: None,
                 error: None,
             },
-            data,
-        })
+        ))
     }
 }
 
@@ -0,0 +1,182 @@
+//! 鉴权接口与仿真 TokenProvider
+
+use std::{future::Future, pin::Pin};
+
+use serde_json::{Value, json};
+use wiremock::ResponseTemplate;
+
+use super::{FakeRequest, FakeState, TokenKind, fail};
+use crate::{
+    SDKResult,
+    auth::{TokenProvider, TokenRequest},
+    constants::{
+        APP_ACCESS_TOKEN_INTERNAL_URL_PATH, AccessTokenType, TENANT_ACCESS_TOKEN_INTERNAL_URL_PATH,
+    },
+    error::{api_error, authentication_error, configuration_error, network_error},
+};
+
+/// 应用密钥错误时返回的错误码
+const ERR_CODE_APP_SECRET_INVALID: i32 = 10014;
+
+/// 处理 `/open-apis/auth/...`
+pub(super) fn handle(
+    state: &mut FakeState,
+    req: &FakeRequest,
+    segments: &[&str],
+) -> Option<ResponseTemplate> {
+    if req.method != "POST" {
+        return None;
+    }
+    let body = req.json();
+    match segments {
+        ["v3", "tenant_access_token", "internal"] => Some(issue(
+            state,
+            &body,
+            TokenKind::Tenant,
+            "tenant_access_token",
+        )),
+        ["v3", "app_access_token", "internal"] => {
+            Some(issue(state, &body, TokenKind::App, "app_access_token"))
+        }
+        // 商店应用：用 app_access_token 换取 tenant_access_token
+        ["v3", "tenant_access_token"] => {
+            let valid = body["app_access_token"]
+                .as_str()
+                .and_then(|token| state.tokens.get(token))
+                .is_some_and(|issued| issued.kind == TokenKind::App && !issued.expired);
+            if !valid {
+                return Some(fail(
+                    400,
+                    crate::constants::ERR_CODE_APP_ACCESS_TOKEN_INVALID,
+                    "invalid app_access_token",
+                ));
+            }
+            let token = state.issue_token(TokenKind::Tenant);
+            Some(token_response("tenant_access_token", token, state))
+        }
+        _ => None,
+    }
+}
+
+fn issue(state: &mut FakeState, body: &Value, kind: TokenKind, field: &str) -> ResponseTemplate {
+    if body["app_id"].as_str() != Some(state.app_id.as_str())
+        || body["app_secret"].as_str() != Some(state.app_secret.as_str())
+    {
+        return fail(400, ERR_CODE_APP_SECRET_INVALID, "app secret invalid");
+    }
+    let token = state.issue_token(kind);
+    token_response(field, token, state)
+}
+
+/// 鉴权接口的响应是扁平结构，不包裹在 `data` 中
+fn token_response(field: &str, token: String, state: &FakeState) -> ResponseTemplate {
+    let mut body = json!({
+        "code": 0,
+        "msg": "ok",
+        "expire": state.token_ttl.as_secs(),
+    });
+    body[field] = Value::String(token);
+    ResponseTemplate::new(200).set_body_json(body)
+}
+
+/// 向仿真服务换取 token 的 [`TokenProvider`]
+///
+/// 每次调用都会请求鉴权接口、不做缓存，方便测试 token 过期与故障注入的行为；
+/// `user_access_token` 需通过 [`FakeFeishu::issue_user_token`](super::FakeFeishu::issue_user_token)
+/// 签发后放入 `RequestOption`。
+#[derive(Debug, Clone)]
+pub struct FakeTokenProvider {
+    base_url: String,
+    app_id: String,
+    app_secret: String,
+    client: reqwest::Client,
+}
+
+impl FakeTokenProvider {
+    /// 创建指向仿真服务的 provider
+    pub fn new(
+        base_url: impl Into<String>,
+        app_id: impl Into<String>,
+        app_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            base_url: base_url.into(),
+            app_id: app_id.into(),
+            app_secret: app_secret.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn fetch(&self, path: &str, field: &str) -> SDKResult<String> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.base_url, path))
+            .json(&json!({"app_id": self.app_id, "app_secret": self.app_secret}))
+            .send()
+            .await
+            .map_err(|err| network_error(err.to_string()))?;
+        let status = response.status().as_u16();
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|err| network_error(err.to_string()))?;
+        match body[field].as_str() {
+            Some(token) if body["code"].as_i64() == Some(0) => Ok(token.to_string()),
+            _ => Err(api_error(
+                status,
+                path,
+                body["msg"].as_str().unwrap_or("token request failed"),
+                None,
+            )),
+        }
+    }
+}
+
+impl TokenProvider for FakeTokenProvider {
+    fn get_token(
+        &self,
+        request: TokenRequest,
+    ) -> Pin<Box<dyn Future<Output = SDKResult<String>> + Send + '_>> {
+        Box::pin(async move {
+            match request.token_type {
+                AccessTokenType::Tenant => {
+                    self.fetch(TENANT_ACCESS_TOKEN_INTERNAL_URL_PATH, "tenant_access_token")
+                        .await
+                }
+                AccessTokenType::App => {
+                    self.fetch(APP_ACCESS_TOKEN_INTERNAL_URL_PATH, "app_access_token")
+                        .await
+                }
+                AccessTokenType::User => Err(configuration_error(
+                    "FakeTokenProvider 不签发 user_access_token，请使用 FakeFeishu::issue_user_token",
+                )),
+                AccessTokenType::None => Err(authentication_error("未指定 token 类型")),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FakeFeishu;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_provider_fetches_tokens_from_fake() {
+        let fake = FakeFeishu::start().await;
+        let provider = FakeTokenProvider::new(fake.uri(), fake.app_id(), fake.app_secret());
+        let tenant = provider.get_tenant_token(None).await.unwrap();
+        let app = provider.get_app_token().await.unwrap();
+        assert!(tenant.starts_with("t-"));
+        assert!(app.starts_with("a-"));
+        assert!(provider.get_user_token().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_wrong_secret_is_rejected() {
+        let fake = FakeFeishu::start().await;
+        let provider = FakeTokenProvider::new(fake.uri(), fake.app_id(), "wrong");
+        let err = provider.get_tenant_token(None).await.unwrap_err();
+        assert!(err.to_string().contains("app secret invalid"), "{err}");
+    }
+}
@@ -0,0 +1,806 @@
+//! 多维表格（`/open-apis/bitable/v1/...`）
+//!
+//! 记录搜索（`records/search`）实现了与开放平台一致的筛选语义：`conjunction`、`conditions`
+//! 与 `children` 嵌套条件组，以及 `is` / `isNot` / `contains` / `doesNotContain` / `isEmpty` /
+//! `isNotEmpty` / `isGreater` / `isGreaterEqual` / `isLess` / `isLessEqual` 运算符；
+//! 日期条件支持 `ExactDate` / `Today` / `Yesterday` / `Tomorrow`，按自然日（UTC+8）比较。
+
+use std::cmp::Ordering;
+
+use serde_json::{Map, Value, json};
+use wiremock::ResponseTemplate;
+
+use super::{FakeRequest, FakeState, fail, ok, paginate};
+
+/// 多维表格不存在
+const ERR_CODE_APP_NOT_FOUND: i32 = 1254040;
+/// 数据表不存在
+const ERR_CODE_TABLE_NOT_FOUND: i32 = 1254041;
+/// 记录不存在
+const ERR_CODE_RECORD_NOT_FOUND: i32 = 1254043;
+/// 筛选条件非法
+const ERR_CODE_INVALID_FILTER: i32 = 1254018;
+
+const DAY_MS: i64 = 86_400_000;
+const UTC8_OFFSET_MS: i64 = 8 * 3_600_000;
+
+/// 多维表格状态
+#[derive(Debug, Default)]
+pub(crate) struct BitableState {
+    apps: Vec<App>,
+}
+
+#[derive(Debug)]
+struct App {
+    app_token: String,
+    name: String,
+    tables: Vec<Table>,
+}
+
+#[derive(Debug)]
+struct Table {
+    table_id: String,
+    name: String,
+    fields: Vec<Value>,
+    records: Vec<Record>,
+}
+
+#[derive(Debug, Clone)]
+struct Record {
+    record_id: String,
+    fields: Map<String, Value>,
+    created_time: i64,
+    last_modified_time: i64,
+}
+
+impl Record {
+    fn to_json(&self, field_names: Option<&[String]>, automatic_fields: bool) -> Value {
+        let fields: Map<String, Value> = match field_names {
+            Some(names) => self
+                .fields
+                .iter()
+                .filter(|(name, _)| names.contains(name))
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            None => self.fields.clone(),
+        };
+        let mut record = json!({
+            "record_id": self.record_id,
+            "id": self.record_id,
+            "fields": fields,
+        });
+        if automatic_fields {
+            record["created_time"] = json!(self.created_time);
+            record["last_modified_time"] = json!(self.last_modified_time);
+        }
+        record
+    }
+}
+
+impl BitableState {
+    #[allow(clippy::result_large_err)]
+    fn table_mut(
+        &mut self,
+        app_token: &str,
+        table_id: &str,
+    ) -> Result<&mut Table, ResponseTemplate> {
+        let app = self
+            .apps
+            .iter_mut()
+            .find(|app| app.app_token == app_token)
+            .ok_or_else(|| fail(400, ERR_CODE_APP_NOT_FOUND, "app not found"))?;
+        app.tables
+            .iter_mut()
+            .find(|table| table.table_id == table_id)
+            .ok_or_else(|| fail(400, ERR_CODE_TABLE_NOT_FOUND, "table not found"))
+    }
+}
+
+/// 处理 `/open-apis/bitable/...`
+pub(super) fn handle(
+    state: &mut FakeState,
+    req: &FakeRequest,
+    segments: &[&str],
+) -> Option<ResponseTemplate> {
+    let response = match (req.method.as_str(), segments) {
+        ("POST", ["v1", "apps"]) => {
+            let body = req.json();
+            let name = body["name"].as_str().unwrap_or_default();
+            let app_token = create_app(state, name);
+            ok(json!({"app": {"app_token": app_token, "name": name}}))
+        }
+        ("GET", ["v1", "apps", app_token]) => {
+            match state
+                .bitable
+                .apps
+                .iter()
+                .find(|app| app.app_token == *app_token)
+            {
+                Some(app) => ok(json!({"app": {"app_token": app.app_token, "name": app.name}})),
+                None => fail(400, ERR_CODE_APP_NOT_FOUND, "app not found"),
+            }
+        }
+        ("POST", ["v1", "apps", app_token, "tables"]) => create_table(state, app_token, req),
+        ("GET", ["v1", "apps", app_token, "tables"]) => {
+            match state
+                .bitable
+                .apps
+                .iter()
+                .find(|app| app.app_token == *app_token)
+            {
+                Some(app) => {
+                    let items = app
+                        .tables
+                        .iter()
+                        .map(|table| json!({"table_id": table.table_id, "name": table.name}))
+                        .collect();
+                    ok(paginate(items, req, 20))
+                }
+                None => fail(400, ERR_CODE_APP_NOT_FOUND, "app not found"),
+            }
+        }
+        ("DELETE", ["v1", "apps", app_token, "tables", table_id]) => {
+            match state
+                .bitable
+                .apps
+                .iter_mut()
+                .find(|app| app.app_token == *app_token)
+            {
+                Some(app) => {
+                    let before = app.tables.len();
+                    app.tables.retain(|table| table.table_id != *table_id);
+                    if app.tables.len() == before {
+                        fail(400, ERR_CODE_TABLE_NOT_FOUND, "table not found")
+                    } else {
+                        ok(json!({}))
+                    }
+                }
+                None => fail(400, ERR_CODE_APP_NOT_FOUND, "app not found"),
+            }
+        }
+        ("GET", ["v1", "apps", app_token, "tables", table_id, "fields"]) => {
+            match state.bitable.table_mut(app_token, table_id) {
+                Ok(table) => ok(paginate(table.fields.clone(), req, 20)),
+                Err(resp) => resp,
+            }
+        }
+        (
+            method,
+            [
+                "v1",
+                "apps",
+                app_token,
+                "tables",
+                table_id,
+                "records",
+                rest @ ..,
+            ],
+        ) => records(state, req, method, app_token, table_id, rest),
+        _ => return None,
+    };
+    Some(response)
+}
+
+fn records(
+    state: &mut FakeState,
+    req: &FakeRequest,
+    method: &str,
+    app_token: &str,
+    table_id: &str,
+    rest: &[&str],
+) -> ResponseTemplate {
+    let body = req.json();
+    let now = chrono::Utc::now().timestamp_millis();
+    let mut ids = Vec::new();
+    for _ in 0..record_id_demand(method, rest, &body) {
+        ids.push(state.next_id("rec"));
+    }
+    let table = match state.bitable.table_mut(app_token, table_id) {
+        Ok(table) => table,
+        Err(resp) => return resp,
+    };
+
+    let mut actions = Vec::new();
+    let response = match (method, rest) {
+        ("POST", []) => {
+            let record = insert(table, ids.remove(0), &body["fields"], now);
+            actions.push(action("record_added", None, Some(&record)));
+            ok(json!({ "record": record.to_json(None, false) }))
+        }
+        ("GET", []) => {
+            let items = table
+                .records
+                .iter()
+                .map(|record| record.to_json(None, automatic(req, &body)))
+                .collect();
+            ok(paginate(items, req, 20))
+        }
+        ("POST", ["batch_create"]) => {
+            let mut created = Vec::new();
+            for (item, record_id) in iter_array(&body["records"]).zip(ids) {
+                let record = insert(table, record_id, &item["fields"], now);
+                actions.push(action("record_added", None, Some(&record)));
+                created.push(record.to_json(None, false));
+            }
+            ok(json!({ "records": created }))
+        }
+        ("POST", ["batch_update"]) => {
+            let mut updated = Vec::new();
+            for item in iter_array(&body["records"]) {
+                let record_id = item["record_id"].as_str().unwrap_or_default();
+                match update(table, record_id, &item["fields"], now) {
+                    Some((before, after)) => {
+                        actions.push(action("record_edited", Some(&before), Some(&after)));
+                        updated.push(after.to_json(None, false));
+                    }
+                    None => return record_not_found(),
+                }
+            }
+            ok(json!({ "records": updated }))
+        }
+        ("POST", ["batch_delete"]) => {
+            let mut deleted = Vec::new();
+            for record_id in iter_array(&body["records"]).filter_map(Value::as_str) {
+                match remove(table, record_id) {
+                    Some(record) => {
+                        actions.push(action("record_deleted", Some(&record), None));
+                        deleted.push(json!({"deleted": true, "record_id": record_id}));
+                    }
+                    None => return record_not_found(),
+                }
+            }
+            ok(json!({ "records": deleted }))
+        }
+        ("POST", ["search"]) => match search(table, &body) {
+            Ok(items) => ok(paginate(items, req, 20)),
+            Err(message) => fail(400, ERR_CODE_INVALID_FILTER, &message),
+        },
+        ("GET", [record_id]) => match table.records.iter().find(|r| r.record_id == *record_id) {
+            Some(record) => ok(json!({ "record": record.to_json(None, automatic(req, &body)) })),
+            None => record_not_found(),
+        },
+        ("PUT", [record_id]) => match update(table, record_id, &body["fields"], now) {
+            Some((before, after)) => {
+                actions.push(action("record_edited", Some(&before), Some(&after)));
+                ok(json!({ "record": after.to_json(None, false) }))
+            }
+            None => record_not_found(),
+        },
+        ("DELETE", [record_id]) => match remove(table, record_id) {
+            Some(record) => {
+                actions.push(action("record_deleted", Some(&record), None));
+                ok(json!({"deleted": true, "record_id": record_id}))
+            }
+            None => record_not_found(),
+        },
+        _ => fail(404, super::ERR_CODE_NOT_FOUND, "not found"),
+    };
+
+    if !actions.is_empty() {
+        state.emit(
+            "drive.file.bitable_record_changed_v1",
+            json!({
+                "file_token": app_token,
+                "file_type": "bitable",
+                "table_id": table_id,
+                "action_list": actions,
+            }),
+        );
+    }
+    response
+}
+
+/// 本次请求需要新生成的记录 ID 数量
+fn record_id_demand(method: &str, rest: &[&str], body: &Value) -> usize {
+    match (method, rest) {
+        ("POST", []) => 1,
+        ("POST", ["batch_create"]) => iter_array(&body["records"]).count(),
+        _ => 0,
+    }
+}
+
+fn iter_array(value: &Value) -> impl Iterator<Item = &Value> {
+    value.as_array().into_iter().flatten()
+}
+
+fn automatic(req: &FakeRequest, body: &Value) -> bool {
+    req.query("automatic_fields") == Some("true") || body["automatic_fields"] == true
+}
+
+fn record_not_found() -> ResponseTemplate {
+    fail(400, ERR_CODE_RECORD_NOT_FOUND, "record not found")
+}
+
+fn insert(table: &mut Table, record_id: String, fields: &Value, now: i64) -> Record {
+    let fields = fields.as_object().cloned().unwrap_or_default();
+    register_fields(table, &fields);
+    let record = Record {
+        record_id,
+        fields,
+        created_time: now,
+        last_modified_time: now,
+    };
+    table.records.push(record.clone());
+    record
+}
+
+fn update(
+    table: &mut Table,
+    record_id: &str,
+    fields: &Value,
+    now: i64,
+) -> Option<(Record, Record)> {
+    let patch = fields.as_object().cloned().unwrap_or_default();
+    register_fields(table, &patch);
+    let record = table
+        .records
+        .iter_mut()
+        .find(|record| record.record_id == record_id)?;
+    let before = record.clone();
+    for (name, value) in patch {
+        if value.is_null() {
+            record.fields.remove(&name);
+        } else {
+            record.fields.insert(name, value);
+        }
+    }
+    record.last_modified_time = now;
+    Some((before, record.clone()))
+}
+
+fn remove(table: &mut Table, record_id: &str) -> Option<Record> {
+    let index = table
+        .records
+        .iter()
+        .position(|record| record.record_id == record_id)?;
+    Some(table.records.remove(index))
+}
+
+/// 记录里出现的新字段自动加入字段列表
+fn register_fields(table: &mut Table, fields: &Map<String, Value>) {
+    for (name, value) in fields {
+        if table
+            .fields
+            .iter()
+            .any(|field| field["field_name"] == *name)
+        {
+            continue;
+        }
+        let field_type = match value {
+            Value::Number(_) => 2,
+            Value::Bool(_) => 7,
+            Value::Array(_) => 4,
+            _ => 1,
+        };
+        let field_id = format!("fld{:08}", table.fields.len() + 1);
+        table.fields.push(json!({
+            "field_id": field_id,
+            "field_name": name,
+            "type": field_type,
+        }));
+    }
+}
+
+fn action(kind: &str, before: Option<&Record>, after: Option<&Record>) -> Value {
+    let values = |record: Option<&Record>| -> Value {
+        record
+            .map(|record| {
+                record
+                    .fields
+                    .iter()
+                    .map(
+                        |(name, value)| json!({"field_id": name, "field_value": value.to_string()}),
+                    )
+                    .collect()
+            })
+            .unwrap_or_else(|| json!([]))
+    };
+    let record_id = before.or(after).map(|record| record.record_id.clone());
+    json!({
+        "record_id": record_id,
+        "action": kind,
+        "before_value": values(before),
+        "after_value": values(after),
+    })
+}
+
+/// 创建多维表格，返回 `app_token`
+pub(super) fn create_app(state: &mut FakeState, name: &str) -> String {
+    let app_token = state.next_id("bascn");
+    state.bitable.apps.push(App {
+        app_token: app_token.clone(),
+        name: name.to_string(),
+        tables: Vec::new(),
+    });
+    app_token
+}
+
+fn create_table(state: &mut FakeState, app_token: &str, req: &FakeRequest) -> ResponseTemplate {
+    let body = req.json();
+    let table_id = state.next_id("tbl");
+    let view_id = state.next_id("vew");
+    let Some(app) = state
+        .bitable
+        .apps
+        .iter_mut()
+        .find(|app| app.app_token == app_token)
+    else {
+        return fail(400, ERR_CODE_APP_NOT_FOUND, "app not found");
+    };
+    let table_body = &body["table"];
+    let fields: Vec<Value> = iter_array(&table_body["fields"])
+        .enumerate()
+        .map(|(index, field)| {
+            json!({
+                "field_id": format!("fld{:08}", index + 1),
+                "field_name": field["field_name"],
+                "type": field["type"].as_i64().unwrap_or(1),
+            })
+        })
+        .collect();
+    let field_id_list: Vec<Value> = fields
+        .iter()
+        .map(|field| field["field_id"].clone())
+        .collect();
+    app.tables.push(Table {
+        table_id: table_id.clone(),
+        name: table_body["name"].as_str().unwrap_or_default().to_string(),
+        fields,
+        records: Vec::new(),
+    });
+    ok(json!({
+        "table_id": table_id,
+        "default_view_id": view_id,
+        "field_id_list": field_id_list,
+    }))
+}
+
+/// 数据表中的全部记录（不存在时返回空）
+pub(super) fn records_of(state: &FakeState, app_token: &str, table_id: &str) -> Vec<Value> {
+    state
+        .bitable
+        .apps
+        .iter()
+        .find(|app| app.app_token == app_token)
+        .and_then(|app| app.tables.iter().find(|table| table.table_id == table_id))
+        .map(|table| {
+            table
+                .records
+                .iter()
+                .map(|record| record.to_json(None, false))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn search(table: &Table, body: &Value) -> Result<Vec<Value>, String> {
+    let mut matched = Vec::new();
+    for record in &table.records {
+        let keep = match body.get("filter") {
+            Some(filter) if !filter.is_null() => matches_group(record, filter)?,
+            _ => true,
+        };
+        if keep {
+            matched.push(record);
+        }
+    }
+
+    for sort in iter_array(&body["sort"])
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        let field = sort["field_name"]
+            .as_str()
+            .ok_or_else(|| "sort.field_name is required".to_string())?;
+        let desc = sort["desc"].as_bool().unwrap_or(false);
+        // 稳定排序，从最后一个排序键开始排即可得到多键排序
+        matched.sort_by(|a, b| {
+            let ordering = compare_cells(a.fields.get(field), b.fields.get(field));
+            if desc { ordering.reverse() } else { ordering }
+        });
+    }
+
+    let field_names: Option<Vec<String>> = body["field_names"].as_array().map(|names| {
+        names
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect()
+    });
+    let automatic_fields = body["automatic_fields"] == true;
+    Ok(matched
+        .into_iter()
+        .map(|record| record.to_json(field_names.as_deref(), automatic_fields))
+        .collect())
+}
+
+fn matches_group(record: &Record, group: &Value) -> Result<bool, String> {
+    let conjunction = group["conjunction"].as_str().unwrap_or("and");
+    let mut results = Vec::new();
+    for condition in iter_array(&group["conditions"]) {
+        results.push(matches_condition(record, condition)?);
+    }
+    for child in iter_array(&group["children"]) {
+        results.push(matches_group(record, child)?);
+    }
+    match conjunction {
+        "and" => Ok(results.iter().all(|matched| *matched)),
+        "or" => Ok(results.is_empty() || results.iter().any(|matched| *matched)),
+        other => Err(format!("unsupported conjunction: {other}")),
+    }
+}
+
+fn matches_condition(record: &Record, condition: &Value) -> Result<bool, String> {
+    let field = condition["field_name"]
+        .as_str()
+        .ok_or_else(|| "condition.field_name is required".to_string())?;
+    let operator = condition["operator"]
+        .as_str()
+        .ok_or_else(|| "condition.operator is required".to_string())?;
+    let expected: Vec<String> = iter_array(&condition["value"]).map(scalar_text).collect();
+    let cell = record.fields.get(field);
+    let texts = cell_texts(cell);
+
+    let result = match operator {
+        "isEmpty" => texts.is_empty(),
+        "isNotEmpty" => !texts.is_empty(),
+        "is" => is_equal(cell, &texts, &expected),
+        "isNot" => !is_equal(cell, &texts, &expected),
+        "contains" => contains(&texts, &expected),
+        "doesNotContain" => !contains(&texts, &expected),
+        "isGreater" | "isGreaterEqual" | "isLess" | "isLessEqual" => {
+            let Some(ordering) = compare_with(cell, &expected) else {
+                return Ok(false);
+            };
+            match operator {
+                "isGreater" => ordering == Ordering::Greater,
+                "isGreaterEqual" => ordering != Ordering::Less,
+                "isLess" => ordering == Ordering::Less,
+                _ => ordering != Ordering::Greater,
+            }
+        }
+        other => return Err(format!("unsupported operator: {other}")),
+    };
+    Ok(result)
+}
+
+fn is_equal(cell: Option<&Value>, texts: &[String], expected: &[String]) -> bool {
+    if let Some(target) = date_target(expected) {
+        return cell_number(cell).is_some_and(|ms| day_of(ms) == day_of(target));
+    }
+    match cell {
+        // 多选、人员等数组字段：选项集合一致
+        Some(Value::Array(_)) if expected.len() > 1 || texts.len() > 1 => {
+            let mut left = texts.to_vec();
+            let mut right = expected.to_vec();
+            left.sort();
+            right.sort();
+            left == right
+        }
+        Some(Value::Number(number)) => match expected.first() {
+            Some(value) => value
+                .parse::<f64>()
+                .ok()
+                .zip(number.as_f64())
+                .is_some_and(|(expected, actual)| expected == actual),
+            None => false,
+        },
+        _ => match expected.first() {
+            Some(value) => texts.concat() == *value,
+            None => texts.is_empty(),
+        },
+    }
+}
+
+fn contains(texts: &[String], expected: &[String]) -> bool {
+    expected
+        .iter()
+        .any(|value| texts.iter().any(|text| text.contains(value.as_str())))
+}
+
+fn compare_with(cell: Option<&Value>, expected: &[String]) -> Option<Ordering> {
+    if let Some(target) = date_target(expected) {
+        return Some(day_of(cell_number(cell)?).cmp(&day_of(target)));
+    }
+    let actual = match cell? {
+        Value::Number(number) => number.as_f64()?,
+        Value::String(text) => text.parse().ok()?,
+        _ => return None,
+    };
+    actual.partial_cmp(&expected.first()?.parse::<f64>().ok()?)
+}
+
+/// 日期条件的目标毫秒时间戳
+fn date_target(expected: &[String]) -> Option<i64> {
+    let now = chrono::Utc::now().timestamp_millis();
+    match expected.first().map(String::as_str)? {
+        "ExactDate" => expected.get(1)?.parse().ok(),
+        "Today" => Some(now),
+        "Yesterday" => Some(now - DAY_MS),
+        "Tomorrow" => Some(now + DAY_MS),
+        _ => None,
+    }
+}
+
+fn day_of(ms: i64) -> i64 {
+    (ms + UTC8_OFFSET_MS).div_euclid(DAY_MS)
+}
+
+fn cell_number(cell: Option<&Value>) -> Option<i64> {
+    match cell? {
+        Value::Number(number) => number
+            .as_i64()
+            .or_else(|| number.as_f64().map(|value| value as i64)),
+        Value::String(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+/// 把单元格归一化为文本列表，空值返回空列表
+fn cell_texts(cell: Option<&Value>) -> Vec<String> {
+    match cell {
+        None | Some(Value::Null) => Vec::new(),
+        Some(Value::String(text)) if text.is_empty() => Vec::new(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(scalar_text)
+            .filter(|text| !text.is_empty())
+            .collect(),
+        Some(value) => vec![scalar_text(value)],
+    }
+}
+
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Number(number) => number.to_string(),
+        Value::Bool(flag) => flag.to_string(),
+        // 文本片段 {"text": ..}、人员 {"id": ..}、链接 {"link": ..}
+        Value::Object(object) => ["text", "name", "id", "link"]
+            .iter()
+            .find_map(|key| object.get(*key).and_then(Value::as_str))
+            .unwrap_or_default()
+            .to_string(),
+        _ => String::new(),
+    }
+}
+
+fn compare_cells(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    match (a, b) {
+        (Some(Value::Number(a)), Some(Value::Number(b))) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        // 空值排在最后
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        _ => cell_texts(a).concat().cmp(&cell_texts(b).concat()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FakeFeishu;
+    use super::*;
+    use crate::{api::ApiRequest, config::Config, http::Transport};
+
+    async fn post(config: &Config, url: &str, body: Value) -> Value {
+        let req: ApiRequest<Value> = ApiRequest::post(url).json_body(&body);
+        let resp = Transport::<Value>::request(req, config, None)
+            .await
+            .unwrap();
+        assert!(resp.is_success(), "{:?}", resp.raw_response);
+        resp.data.unwrap()
+    }
+
+    async fn seeded() -> (FakeFeishu, Config, String) {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+        let app_token = fake.add_bitable_app("任务");
+        let table = post(
+            &config,
+            &format!("/open-apis/bitable/v1/apps/{app_token}/tables"),
+            json!({"table": {"name": "任务表"}}),
+        )
+        .await;
+        let base = format!(
+            "/open-apis/bitable/v1/apps/{app_token}/tables/{}/records",
+            table["table_id"].as_str().unwrap()
+        );
+        post(
+            &config,
+            &format!("{base}/batch_create"),
+            json!({"records": [
+                {"fields": {"名称": "写文档", "状态": "进行中", "工时": 3, "标签": ["文档", "紧急"]}},
+                {"fields": {"名称": "修 bug", "状态": "已完成", "工时": 5, "标签": ["研发"]}},
+                {"fields": {"名称": "评审", "状态": "进行中", "工时": 1}},
+            ]}),
+        )
+        .await;
+        (fake, config, base)
+    }
+
+    fn names(data: &Value) -> Vec<String> {
+        data["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["fields"]["名称"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_and_sorts() {
+        let (fake, config, base) = seeded().await;
+        let data = post(
+            &config,
+            &format!("{base}/search"),
+            json!({
+                "filter": {
+                    "conjunction": "and",
+                    "conditions": [
+                        {"field_name": "状态", "operator": "is", "value": ["进行中"]},
+                        {"field_name": "工时", "operator": "isGreater", "value": ["0"]},
+                    ],
+                },
+                "sort": [{"field_name": "工时", "desc": true}],
+                "field_names": ["名称"],
+            }),
+        )
+        .await;
+        assert_eq!(names(&data), vec!["写文档", "评审"]);
+        assert!(data["items"][0]["fields"].get("状态").is_none());
+
+        let data = post(
+            &config,
+            &format!("{base}/search"),
+            json!({"filter": {
+                "conjunction": "or",
+                "conditions": [{"field_name": "标签", "operator": "isEmpty", "value": []}],
+                "children": [{
+                    "conjunction": "and",
+                    "conditions": [{"field_name": "标签", "operator": "contains", "value": ["研发"]}],
+                }],
+            }}),
+        )
+        .await;
+        assert_eq!(names(&data), vec!["修 bug", "评审"]);
+        assert_eq!(
+            fake.events_of("drive.file.bitable_record_changed_v1")[0].event()["action_list"]
+                .as_array()
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_operator_is_rejected() {
+        let (_fake, config, base) = seeded().await;
+        let req: ApiRequest<Value> = ApiRequest::post(format!("{base}/search")).json_body(
+            &json!({"filter": {"conjunction": "and", "conditions": [
+                {"field_name": "工时", "operator": "between", "value": ["1"]}
+            ]}}),
+        );
+        let resp = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap();
+        assert_eq!(resp.code(), ERR_CODE_INVALID_FILTER);
+    }
+
+    #[test]
+    fn test_date_conditions_compare_by_day() {
+        let record = Record {
+            record_id: "rec1".to_string(),
+            fields: Map::from_iter([("截止".to_string(), json!(1_702_449_755_000_i64))]),
+            created_time: 0,
+            last_modified_time: 0,
+        };
+        let condition = |operator: &str, value: i64| json!({"field_name": "截止", "operator": operator, "value": ["ExactDate", value.to_string()]});
+        let same_day = 1_702_400_000_000_i64;
+        assert!(matches_condition(&record, &condition("is", same_day)).unwrap());
+        assert!(matches_condition(&record, &condition("isGreater", same_day - DAY_MS)).unwrap());
+        assert!(!matches_condition(&record, &condition("isLess", same_day)).unwrap());
+    }
+}
@@ -0,0 +1,352 @@
+//! 通讯录（`/open-apis/contact/v3/...`）
+
+use serde_json::{Value, json};
+use wiremock::ResponseTemplate;
+
+use super::{FakeRequest, FakeState, fail, im::merge, ok, paginate};
+
+/// 用户不存在时返回的错误码
+const ERR_CODE_USER_NOT_FOUND: i32 = 41050;
+/// 部门不存在时返回的错误码
+const ERR_CODE_DEPARTMENT_NOT_FOUND: i32 = 40003;
+
+/// 根部门 ID
+const ROOT_DEPARTMENT_ID: &str = "0";
+
+/// 仿真用户的三种 ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeUserIds {
+    /// 租户内用户 ID
+    pub user_id: String,
+    /// 应用内用户 ID
+    pub open_id: String,
+    /// 开发商内用户 ID
+    pub union_id: String,
+}
+
+/// 通讯录状态
+#[derive(Debug)]
+pub(crate) struct ContactState {
+    users: Vec<Value>,
+    departments: Vec<Value>,
+}
+
+impl ContactState {
+    pub(super) fn new() -> Self {
+        Self {
+            users: Vec::new(),
+            departments: vec![json!({
+                "name": "",
+                "department_id": ROOT_DEPARTMENT_ID,
+                "open_department_id": ROOT_DEPARTMENT_ID,
+                "parent_department_id": "",
+                "status": { "is_deleted": false },
+            })],
+        }
+    }
+
+    fn user_index(&self, id: &str, id_type: &str) -> Option<usize> {
+        self.users.iter().position(|user| user[id_type] == id)
+    }
+
+    fn department_index(&self, id: &str) -> Option<usize> {
+        self.departments.iter().position(|department| {
+            department["department_id"] == id || department["open_department_id"] == id
+        })
+    }
+
+    /// 按 open_id 查询用户的三种 ID，未知用户只返回 open_id
+    pub(super) fn user_ids_by_open_id(&self, open_id: &str) -> Value {
+        match self.user_index(open_id, "open_id") {
+            Some(index) => {
+                let user = &self.users[index];
+                json!({
+                    "user_id": user["user_id"],
+                    "open_id": user["open_id"],
+                    "union_id": user["union_id"],
+                })
+            }
+            None => json!({ "open_id": open_id }),
+        }
+    }
+}
+
+/// 处理 `/open-apis/contact/...`
+pub(super) fn handle(
+    state: &mut FakeState,
+    req: &FakeRequest,
+    segments: &[&str],
+) -> Option<ResponseTemplate> {
+    let user_id_type = req.query("user_id_type").unwrap_or("open_id");
+    let response = match (req.method.as_str(), segments) {
+        ("POST", ["v3", "users"]) => {
+            let ids = create_user(state, req.json());
+            let index = state.contact.user_index(&ids.open_id, "open_id")?;
+            ok(json!({ "user": state.contact.users[index] }))
+        }
+        ("GET", ["v3", "users", "find_by_department"]) | ("GET", ["v3", "users"]) => {
+            let department_id = req.query("department_id").unwrap_or(ROOT_DEPARTMENT_ID);
+            let items = state
+                .contact
+                .users
+                .iter()
+                .filter(|user| {
+                    user["department_ids"]
+                        .as_array()
+                        .is_some_and(|ids| ids.iter().any(|id| id == department_id))
+                })
+                .cloned()
+                .collect();
+            ok(paginate(items, req, 10))
+        }
+        ("GET", ["v3", "users", id]) => match state.contact.user_index(id, user_id_type) {
+            Some(index) => ok(json!({ "user": state.contact.users[index] })),
+            None => user_not_found(),
+        },
+        ("PATCH", ["v3", "users", id]) | ("PUT", ["v3", "users", id]) => {
+            let Some(index) = state.contact.user_index(id, user_id_type) else {
+                return Some(user_not_found());
+            };
+            let patch = req.json();
+            let user = &mut state.contact.users[index];
+            let old_object = changed_fields(user, &patch);
+            merge(user, &patch);
+            let object = user.clone();
+            state.emit(
+                "contact.user.updated_v3",
+                json!({"object": object, "old_object": old_object}),
+            );
+            ok(json!({ "user": object }))
+        }
+        ("DELETE", ["v3", "users", id]) => match state.contact.user_index(id, user_id_type) {
+            Some(index) => {
+                let user = state.contact.users.remove(index);
+                state.emit("contact.user.deleted_v3", json!({ "object": user }));
+                ok(json!({}))
+            }
+            None => user_not_found(),
+        },
+        ("POST", ["v3", "departments"]) => {
+            let department_id = create_department(state, req.json());
+            let index = state.contact.department_index(&department_id)?;
+            ok(json!({ "department": state.contact.departments[index] }))
+        }
+        ("GET", ["v3", "departments", id, "children"]) => ok(children(state, id, req)),
+        ("GET", ["v3", "departments"]) => {
+            let parent = req
+                .query("parent_department_id")
+                .unwrap_or(ROOT_DEPARTMENT_ID);
+            ok(children(state, parent, req))
+        }
+        ("GET", ["v3", "departments", id]) => match state.contact.department_index(id) {
+            Some(index) => ok(json!({ "department": state.contact.departments[index] })),
+            None => department_not_found(),
+        },
+        ("PATCH", ["v3", "departments", id]) | ("PUT", ["v3", "departments", id]) => {
+            let Some(index) = state.contact.department_index(id) else {
+                return Some(department_not_found());
+            };
+            let patch = req.json();
+            let department = &mut state.contact.departments[index];
+            let old_object = changed_fields(department, &patch);
+            merge(department, &patch);
+            let object = department.clone();
+            state.emit(
+                "contact.department.updated_v3",
+                json!({"object": object, "old_object": old_object}),
+            );
+            ok(json!({ "department": object }))
+        }
+        ("DELETE", ["v3", "departments", id]) => match state.contact.department_index(id) {
+            Some(index) if id != &ROOT_DEPARTMENT_ID => {
+                let department = state.contact.departments.remove(index);
+                state.emit(
+                    "contact.department.deleted_v3",
+                    json!({ "object": department }),
+                );
+                ok(json!({}))
+            }
+            _ => department_not_found(),
+        },
+        _ => return None,
+    };
+    Some(response)
+}
+
+fn user_not_found() -> ResponseTemplate {
+    fail(400, ERR_CODE_USER_NOT_FOUND, "user not found")
+}
+
+fn department_not_found() -> ResponseTemplate {
+    fail(400, ERR_CODE_DEPARTMENT_NOT_FOUND, "department not found")
+}
+
+/// 补丁涉及字段的旧值，对应事件中的 `old_object`
+fn changed_fields(current: &Value, patch: &Value) -> Value {
+    let mut old = json!({});
+    if let Some(patch) = patch.as_object() {
+        for key in patch.keys() {
+            old[key] = current.get(key).cloned().unwrap_or(Value::Null);
+        }
+    }
+    old
+}
+
+fn children(state: &FakeState, parent: &str, req: &FakeRequest) -> Value {
+    // 子部门以 parent_department_id 关联，兼容按 open_department_id 查询
+    let parent_ids: Vec<Value> = match state.contact.department_index(parent) {
+        Some(index) => {
+            let department = &state.contact.departments[index];
+            vec![
+                department["department_id"].clone(),
+                department["open_department_id"].clone(),
+            ]
+        }
+        None => vec![Value::String(parent.to_string())],
+    };
+    let recursive = req.query("fetch_child") == Some("true");
+    let mut items = Vec::new();
+    let mut frontier = parent_ids;
+    while !frontier.is_empty() {
+        let found: Vec<Value> = state
+            .contact
+            .departments
+            .iter()
+            .filter(|department| frontier.contains(&department["parent_department_id"]))
+            .cloned()
+            .collect();
+        frontier = if recursive {
+            found
+                .iter()
+                .flat_map(|department| {
+                    [
+                        department["department_id"].clone(),
+                        department["open_department_id"].clone(),
+                    ]
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        items.extend(found);
+    }
+    paginate(items, req, 10)
+}
+
+/// 创建用户并生成 `contact.user.created_v3` 事件
+pub(super) fn create_user(state: &mut FakeState, body: Value) -> FakeUserIds {
+    let open_id = state.next_id("ou_");
+    let union_id = state.next_id("on_");
+    let user_id = match body["user_id"].as_str() {
+        Some(user_id) => user_id.to_string(),
+        None => state.next_id("u"),
+    };
+    let mut user = json!({
+        "user_id": user_id,
+        "open_id": open_id,
+        "union_id": union_id,
+        "department_ids": [ROOT_DEPARTMENT_ID],
+        "status": { "is_activated": true, "is_frozen": false, "is_resigned": false },
+    });
+    merge(&mut user, &body);
+    user["user_id"] = Value::String(user_id.clone());
+    state.contact.users.push(user.clone());
+    state.emit("contact.user.created_v3", json!({ "object": user }));
+    FakeUserIds {
+        user_id,
+        open_id,
+        union_id,
+    }
+}
+
+/// 创建部门并生成 `contact.department.created_v3` 事件，返回 `department_id`
+pub(super) fn create_department(state: &mut FakeState, body: Value) -> String {
+    let open_department_id = state.next_id("od-");
+    let department_id = body["department_id"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| open_department_id.clone());
+    let mut department = json!({
+        "parent_department_id": ROOT_DEPARTMENT_ID,
+        "status": { "is_deleted": false },
+    });
+    merge(&mut department, &body);
+    department["department_id"] = Value::String(department_id.clone());
+    department["open_department_id"] = Value::String(open_department_id);
+    state.contact.departments.push(department.clone());
+    state.emit(
+        "contact.department.created_v3",
+        json!({ "object": department }),
+    );
+    department_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FakeFeishu;
+    use super::*;
+    use crate::{api::ApiRequest, http::Transport};
+
+    #[tokio::test]
+    async fn test_user_lifecycle_emits_events() {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+        let department_id = fake.add_department("研发部", "0");
+        let ids = fake.add_user("李四", &[department_id.as_str()]);
+
+        let req: ApiRequest<Value> =
+            ApiRequest::get(format!("/open-apis/contact/v3/users/{}", ids.user_id))
+                .query("user_id_type", "user_id");
+        let user = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        assert_eq!(user["user"]["open_id"], ids.open_id.as_str());
+
+        let req: ApiRequest<Value> =
+            ApiRequest::patch(format!("/open-apis/contact/v3/users/{}", ids.open_id))
+                .json_body(&json!({ "name": "李四四" }));
+        Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap();
+        let updated = &fake.events_of("contact.user.updated_v3")[0];
+        assert_eq!(updated.event()["old_object"]["name"], "李四");
+        assert_eq!(updated.event()["object"]["name"], "李四四");
+
+        let req: ApiRequest<Value> =
+            ApiRequest::get("/open-apis/contact/v3/users/find_by_department")
+                .query("department_id", department_id.as_str());
+        let list = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        assert_eq!(list["items"].as_array().unwrap().len(), 1);
+
+        let req: ApiRequest<Value> =
+            ApiRequest::delete(format!("/open-apis/contact/v3/users/{}", ids.open_id));
+        Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap();
+        assert_eq!(fake.events_of("contact.user.deleted_v3").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_department_children_recursive() {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+        let parent = fake.add_department("总部", "0");
+        fake.add_department("一组", &parent);
+
+        let req: ApiRequest<Value> =
+            ApiRequest::get("/open-apis/contact/v3/departments/0/children")
+                .query("fetch_child", "true");
+        let data = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        assert_eq!(data["items"].as_array().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,347 @@
+//! 云空间（`/open-apis/drive/...`）
+
+use serde_json::{Value, json};
+use wiremock::ResponseTemplate;
+
+use super::{FakeRequest, FakeState, fail, multipart, ok};
+
+/// 根目录 token
+pub(super) const ROOT_FOLDER_TOKEN: &str = "fldcn_fake_root";
+
+/// 参数错误
+const ERR_CODE_INVALID_PARAM: i32 = 1061002;
+/// 文件不存在或已删除
+const ERR_CODE_FILE_NOT_FOUND: i32 = 1061007;
+/// 父目录不存在
+const ERR_CODE_PARENT_NOT_FOUND: i32 = 1061044;
+
+/// 云空间状态
+#[derive(Debug, Default)]
+pub(crate) struct DriveState {
+    files: Vec<DriveFile>,
+}
+
+#[derive(Debug, Clone)]
+struct DriveFile {
+    token: String,
+    name: String,
+    file_type: &'static str,
+    parent_token: String,
+    content: Vec<u8>,
+    created_time: String,
+}
+
+impl DriveFile {
+    fn to_json(&self) -> Value {
+        json!({
+            "token": self.token,
+            "name": self.name,
+            "type": self.file_type,
+            "parent_token": self.parent_token,
+            "url": format!("https://fake.feishu.cn/{}/{}", self.file_type, self.token),
+            "created_time": self.created_time,
+            "modified_time": self.created_time,
+            "size": self.content.len(),
+        })
+    }
+}
+
+impl DriveState {
+    fn is_folder(&self, token: &str) -> bool {
+        token == ROOT_FOLDER_TOKEN
+            || self
+                .files
+                .iter()
+                .any(|file| file.token == token && file.file_type == "folder")
+    }
+}
+
+/// 处理 `/open-apis/drive/...`
+pub(super) fn handle(
+    state: &mut FakeState,
+    req: &FakeRequest,
+    segments: &[&str],
+) -> Option<ResponseTemplate> {
+    let response = match (req.method.as_str(), segments) {
+        ("GET", ["explorer", "v2", "root_folder", "meta"]) => {
+            ok(json!({"token": ROOT_FOLDER_TOKEN, "id": "0", "user_id": ""}))
+        }
+        ("POST", ["v1", "files", "upload_all"]) => upload(state, req),
+        ("POST", ["v1", "files", "create_folder"]) => {
+            let body = req.json();
+            let parent = body["folder_token"]
+                .as_str()
+                .filter(|token| !token.is_empty())
+                .unwrap_or(ROOT_FOLDER_TOKEN);
+            if !state.drive.is_folder(parent) {
+                return Some(parent_not_found());
+            }
+            let name = body["name"].as_str().unwrap_or_default();
+            let token = create_folder(state, name, parent);
+            ok(json!({
+                "token": token,
+                "url": format!("https://fake.feishu.cn/drive/folder/{token}"),
+            }))
+        }
+        ("GET", ["v1", "files"]) => list(state, req),
+        ("GET", ["v1", "files", token, "download"]) => {
+            match state.drive.files.iter().find(|file| file.token == *token) {
+                Some(file) if file.file_type == "file" => ResponseTemplate::new(200)
+                    .insert_header("content-type", "application/octet-stream")
+                    .insert_header(
+                        "content-disposition",
+                        format!("attachment; filename=\"{}\"", file.name).as_str(),
+                    )
+                    .set_body_bytes(file.content.clone()),
+                _ => file_not_found(),
+            }
+        }
+        ("DELETE", ["v1", "files", token]) => delete(state, token),
+        _ => return None,
+    };
+    Some(response)
+}
+
+fn parent_not_found() -> ResponseTemplate {
+    fail(400, ERR_CODE_PARENT_NOT_FOUND, "parent node not exist")
+}
+
+fn file_not_found() -> ResponseTemplate {
+    fail(404, ERR_CODE_FILE_NOT_FOUND, "file not found")
+}
+
+fn upload(state: &mut FakeState, req: &FakeRequest) -> ResponseTemplate {
+    let content_type = req
+        .headers
+        .get("content-type")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let Some(form) = multipart::boundary(content_type)
+        .and_then(|boundary| multipart::parse(&req.body, boundary))
+    else {
+        return fail(
+            400,
+            ERR_CODE_INVALID_PARAM,
+            "multipart/form-data body required",
+        );
+    };
+    let Some((part_name, content)) = form.files.get("file") else {
+        return fail(400, ERR_CODE_INVALID_PARAM, "file is required");
+    };
+    let Some(name) = form
+        .fields
+        .get("file_name")
+        .cloned()
+        .or_else(|| part_name.clone())
+    else {
+        return fail(400, ERR_CODE_INVALID_PARAM, "file_name is required");
+    };
+    if let Some(size) = form.fields.get("size")
+        && size.parse::<usize>().ok() != Some(content.len())
+    {
+        return fail(
+            400,
+            ERR_CODE_INVALID_PARAM,
+            "size does not match file content",
+        );
+    }
+    let parent = form
+        .fields
+        .get("parent_node")
+        .map(String::as_str)
+        .filter(|token| !token.is_empty())
+        .unwrap_or(ROOT_FOLDER_TOKEN)
+        .to_string();
+    if !state.drive.is_folder(&parent) {
+        return parent_not_found();
+    }
+
+    let token = state.next_id("boxcn");
+    state.drive.files.push(DriveFile {
+        token: token.clone(),
+        name,
+        file_type: "file",
+        parent_token: parent.clone(),
+        content: content.clone(),
+        created_time: now_secs(),
+    });
+    emit_created(state, &token, "file", &parent);
+    ok(json!({ "file_token": token }))
+}
+
+fn list(state: &FakeState, req: &FakeRequest) -> ResponseTemplate {
+    let folder = req
+        .query("folder_token")
+        .filter(|token| !token.is_empty())
+        .unwrap_or(ROOT_FOLDER_TOKEN);
+    if !state.drive.is_folder(folder) {
+        return parent_not_found();
+    }
+    let files: Vec<Value> = state
+        .drive
+        .files
+        .iter()
+        .filter(|file| file.parent_token == folder)
+        .map(DriveFile::to_json)
+        .collect();
+    // 该接口使用 files / next_page_token 而不是通用的 items / page_token
+    let mut data = super::paginate(files, req, 50);
+    let mut result = json!({
+        "files": data["items"].take(),
+        "has_more": data["has_more"],
+    });
+    if let Some(token) = data.get("page_token") {
+        result["next_page_token"] = token.clone();
+    }
+    ok(result)
+}
+
+fn delete(state: &mut FakeState, token: &str) -> ResponseTemplate {
+    let Some(file) = state
+        .drive
+        .files
+        .iter()
+        .find(|file| file.token == token)
+        .cloned()
+    else {
+        return file_not_found();
+    };
+
+    // 删除文件夹时一并删除其下的内容
+    let mut removed = vec![file.token.clone()];
+    let mut index = 0;
+    while index < removed.len() {
+        let parent = removed[index].clone();
+        removed.extend(
+            state
+                .drive
+                .files
+                .iter()
+                .filter(|child| child.parent_token == parent)
+                .map(|child| child.token.clone()),
+        );
+        index += 1;
+    }
+    state
+        .drive
+        .files
+        .retain(|file| !removed.contains(&file.token));
+    state.emit(
+        "drive.file.trashed_v1",
+        json!({
+            "file_token": file.token,
+            "file_type": file.file_type,
+            "operator_id": { "open_id": "" },
+        }),
+    );
+    ok(json!({}))
+}
+
+fn emit_created(state: &mut FakeState, token: &str, file_type: &str, folder: &str) {
+    state.emit(
+        "drive.file.created_in_folder_v1",
+        json!({
+            "file_token": token,
+            "file_type": file_type,
+            "folder_token": folder,
+            "operator_id": { "open_id": "" },
+        }),
+    );
+}
+
+fn now_secs() -> String {
+    chrono::Utc::now().timestamp().to_string()
+}
+
+/// 创建文件夹，返回文件夹 token
+pub(super) fn create_folder(state: &mut FakeState, name: &str, parent: &str) -> String {
+    let token = state.next_id("fldcn");
+    state.drive.files.push(DriveFile {
+        token: token.clone(),
+        name: name.to_string(),
+        file_type: "folder",
+        parent_token: parent.to_string(),
+        content: Vec::new(),
+        created_time: now_secs(),
+    });
+    emit_created(state, &token, "folder", parent);
+    token
+}
+
+/// 已上传文件的内容
+pub(super) fn content_of(state: &FakeState, token: &str) -> Option<Vec<u8>> {
+    state
+        .drive
+        .files
+        .iter()
+        .find(|file| file.token == token && file.file_type == "file")
+        .map(|file| file.content.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FakeFeishu;
+    use super::*;
+    use crate::{api::ApiRequest, http::Transport, req_option::RequestOption};
+
+    #[tokio::test]
+    async fn test_upload_list_download_and_delete() {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+        let folder = fake.add_folder("报表", &fake.root_folder_token());
+
+        let content = b"id,name\n1,foo\n".to_vec();
+        let req: ApiRequest<Value> = ApiRequest::post("/open-apis/drive/v1/files/upload_all")
+            .json_body(&json!({
+                "file_name": "report.csv",
+                "parent_type": "explorer",
+                "parent_node": folder,
+                "size": content.len(),
+            }))
+            .file_content(content.clone());
+        let option = RequestOption::builder().file_upload(true).build();
+        let resp = Transport::<Value>::request(req, &config, Some(option))
+            .await
+            .unwrap();
+        assert!(resp.is_success(), "{:?}", resp.raw_response);
+        let file_token = resp.data.unwrap()["file_token"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(fake.file_content(&file_token), Some(content.clone()));
+
+        let req: ApiRequest<Value> =
+            ApiRequest::get("/open-apis/drive/v1/files").query("folder_token", folder.as_str());
+        let data = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        assert_eq!(data["files"][0]["name"], "report.csv");
+        assert_eq!(data["has_more"], false);
+
+        let token = fake.issue_tenant_token();
+        let downloaded = reqwest::Client::new()
+            .get(format!(
+                "{}/open-apis/drive/v1/files/{file_token}/download",
+                fake.uri()
+            ))
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap();
+        assert_eq!(downloaded.as_ref(), content.as_slice());
+
+        let req: ApiRequest<Value> =
+            ApiRequest::delete(format!("/open-apis/drive/v1/files/{folder}"));
+        Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap();
+        assert!(fake.file_content(&file_token).is_none());
+        assert_eq!(fake.events_of("drive.file.trashed_v1").len(), 1);
+        assert_eq!(fake.events_of("drive.file.created_in_folder_v1").len(), 2);
+    }
+}
@@ -0,0 +1,439 @@
+//! 消息与群组（`/open-apis/im/v1/...`）
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value, json};
+use wiremock::ResponseTemplate;
+
+use super::{FakeRequest, FakeState, fail, ok, paginate};
+
+/// 参数非法时返回的错误码
+const ERR_CODE_INVALID_PARAM: i32 = 230001;
+/// 消息不存在或已撤回时返回的错误码
+const ERR_CODE_MESSAGE_NOT_FOUND: i32 = 230011;
+/// 群组不存在时返回的错误码
+const ERR_CODE_CHAT_NOT_FOUND: i32 = 232011;
+
+/// 消息模块状态
+#[derive(Debug, Default)]
+pub(crate) struct ImState {
+    chats: Vec<Value>,
+    messages: Vec<Value>,
+    /// 发送消息的幂等 uuid -> message_id
+    uuids: HashMap<String, String>,
+    /// 单聊接收者 -> chat_id
+    p2p_chats: HashMap<String, String>,
+}
+
+impl ImState {
+    fn chat(&self, chat_id: &str) -> Option<&Value> {
+        self.chats.iter().find(|chat| chat["chat_id"] == chat_id)
+    }
+
+    fn message(&self, message_id: &str) -> Option<&Value> {
+        self.messages
+            .iter()
+            .find(|message| message["message_id"] == message_id && message["deleted"] == false)
+    }
+}
+
+/// 处理 `/open-apis/im/...`
+pub(super) fn handle(
+    state: &mut FakeState,
+    req: &FakeRequest,
+    segments: &[&str],
+) -> Option<ResponseTemplate> {
+    let response = match (req.method.as_str(), segments) {
+        ("POST", ["v1", "chats"]) => ok(create_chat(state, req.json())),
+        ("GET", ["v1", "chats"]) => ok(paginate(state.im.chats.clone(), req, 20)),
+        ("GET", ["v1", "chats", chat_id]) => match state.im.chat(chat_id) {
+            Some(chat) => ok(chat.clone()),
+            None => chat_not_found(),
+        },
+        ("PUT", ["v1", "chats", chat_id]) => update_chat(state, chat_id, req.json()),
+        ("DELETE", ["v1", "chats", chat_id]) => delete_chat(state, chat_id),
+        ("POST", ["v1", "messages"]) => send_message(state, req),
+        ("GET", ["v1", "messages"]) => list_messages(state, req),
+        ("GET", ["v1", "messages", message_id]) => match state.im.message(message_id) {
+            Some(message) => ok(json!({ "items": [message] })),
+            None => message_not_found(),
+        },
+        ("PUT", ["v1", "messages", message_id]) | ("PATCH", ["v1", "messages", message_id]) => {
+            update_message(state, message_id, req.json())
+        }
+        ("DELETE", ["v1", "messages", message_id]) => recall_message(state, message_id),
+        ("POST", ["v1", "messages", message_id, "reply"]) => reply_message(state, message_id, req),
+        _ => return None,
+    };
+    Some(response)
+}
+
+fn chat_not_found() -> ResponseTemplate {
+    fail(400, ERR_CODE_CHAT_NOT_FOUND, "chat not found")
+}
+
+fn message_not_found() -> ResponseTemplate {
+    fail(
+        400,
+        ERR_CODE_MESSAGE_NOT_FOUND,
+        "message not found or recalled",
+    )
+}
+
+/// 创建群组，返回群信息
+pub(super) fn create_chat(state: &mut FakeState, body: Value) -> Value {
+    let chat_id = state.next_id("oc_");
+    let chat = json!({
+        "chat_id": chat_id,
+        "name": body["name"].as_str().unwrap_or_default(),
+        "description": body["description"].as_str().unwrap_or_default(),
+        "owner_id": body["owner_id"].as_str().unwrap_or(state.app_id.as_str()),
+        "owner_id_type": "open_id",
+        "chat_mode": "group",
+        "chat_type": body["chat_type"].as_str().unwrap_or("private"),
+        "tenant_key": state.tenant_key,
+    });
+    state.im.chats.push(chat.clone());
+    chat
+}
+
+fn update_chat(state: &mut FakeState, chat_id: &str, body: Value) -> ResponseTemplate {
+    let Some(chat) = state
+        .im
+        .chats
+        .iter_mut()
+        .find(|chat| chat["chat_id"] == chat_id)
+    else {
+        return chat_not_found();
+    };
+    let before = chat.clone();
+    merge(chat, &body);
+    let after = chat.clone();
+    state.emit(
+        "im.chat.updated_v1",
+        json!({"chat_id": chat_id, "before_change": before, "after_change": after}),
+    );
+    ok(json!({}))
+}
+
+fn delete_chat(state: &mut FakeState, chat_id: &str) -> ResponseTemplate {
+    let before = state.im.chats.len();
+    state.im.chats.retain(|chat| chat["chat_id"] != chat_id);
+    if state.im.chats.len() == before {
+        return chat_not_found();
+    }
+    state.emit("im.chat.disbanded_v1", json!({ "chat_id": chat_id }));
+    ok(json!({}))
+}
+
+fn send_message(state: &mut FakeState, req: &FakeRequest) -> ResponseTemplate {
+    let body = req.json();
+    let Some(receive_id) = body["receive_id"].as_str() else {
+        return fail(400, ERR_CODE_INVALID_PARAM, "receive_id is required");
+    };
+    if let Some(existing) = body["uuid"]
+        .as_str()
+        .and_then(|uuid| state.im.uuids.get(uuid))
+        .and_then(|message_id| state.im.message(message_id))
+    {
+        return ok(existing.clone());
+    }
+
+    let chat_id = match req.query("receive_id_type").unwrap_or("open_id") {
+        "chat_id" => {
+            if state.im.chat(receive_id).is_none() {
+                return chat_not_found();
+            }
+            receive_id.to_string()
+        }
+        _ => match state.im.p2p_chats.get(receive_id) {
+            Some(chat_id) => chat_id.clone(),
+            None => {
+                let chat_id = state.next_id("oc_p2p_");
+                state
+                    .im
+                    .p2p_chats
+                    .insert(receive_id.to_string(), chat_id.clone());
+                chat_id
+            }
+        },
+    };
+
+    let sender = app_sender(state);
+    let message = push_message(state, &chat_id, sender, &body, None);
+    if let Some(uuid) = body["uuid"].as_str() {
+        state.im.uuids.insert(
+            uuid.to_string(),
+            message["message_id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        );
+    }
+    ok(message)
+}
+
+fn reply_message(state: &mut FakeState, message_id: &str, req: &FakeRequest) -> ResponseTemplate {
+    let Some(parent) = state.im.message(message_id).cloned() else {
+        return message_not_found();
+    };
+    let chat_id = parent["chat_id"].as_str().unwrap_or_default().to_string();
+    let sender = app_sender(state);
+    ok(push_message(
+        state,
+        &chat_id,
+        sender,
+        &req.json(),
+        Some(&parent),
+    ))
+}
+
+fn list_messages(state: &FakeState, req: &FakeRequest) -> ResponseTemplate {
+    let Some(container_id) = req.query("container_id") else {
+        return fail(400, ERR_CODE_INVALID_PARAM, "container_id is required");
+    };
+    let mut items = messages_in(state, container_id);
+    if req.query("sort_type") == Some("ByCreateTimeDesc") {
+        items.reverse();
+    }
+    ok(paginate(items, req, 20))
+}
+
+fn update_message(state: &mut FakeState, message_id: &str, body: Value) -> ResponseTemplate {
+    let Some(message) = state
+        .im
+        .messages
+        .iter_mut()
+        .find(|message| message["message_id"] == message_id && message["deleted"] == false)
+    else {
+        return message_not_found();
+    };
+    if let Some(content) = body.get("content") {
+        message["body"]["content"] = content.clone();
+    }
+    if let Some(msg_type) = body.get("msg_type") {
+        message["msg_type"] = msg_type.clone();
+    }
+    message["updated"] = Value::Bool(true);
+    message["update_time"] = Value::String(FakeState::now_ms());
+    ok(message.clone())
+}
+
+fn recall_message(state: &mut FakeState, message_id: &str) -> ResponseTemplate {
+    let Some(message) = state
+        .im
+        .messages
+        .iter_mut()
+        .find(|message| message["message_id"] == message_id && message["deleted"] == false)
+    else {
+        return message_not_found();
+    };
+    message["deleted"] = Value::Bool(true);
+    let chat_id = message["chat_id"].clone();
+    state.emit(
+        "im.message.recalled_v1",
+        json!({
+            "message_id": message_id,
+            "chat_id": chat_id,
+            "recall_time": FakeState::now_ms(),
+            "recall_type": "message_owner",
+        }),
+    );
+    ok(json!({}))
+}
+
+fn app_sender(state: &FakeState) -> Value {
+    json!({
+        "id": state.app_id,
+        "id_type": "app_id",
+        "sender_type": "app",
+        "tenant_key": state.tenant_key,
+    })
+}
+
+fn push_message(
+    state: &mut FakeState,
+    chat_id: &str,
+    sender: Value,
+    body: &Value,
+    parent: Option<&Value>,
+) -> Value {
+    let message_id = state.next_id("om_");
+    let now = FakeState::now_ms();
+    let mut message = json!({
+        "message_id": message_id,
+        "msg_type": body["msg_type"].as_str().unwrap_or("text"),
+        "create_time": now,
+        "update_time": now,
+        "deleted": false,
+        "updated": false,
+        "chat_id": chat_id,
+        "sender": sender,
+        "body": { "content": body["content"].as_str().unwrap_or_default() },
+    });
+    if let Some(parent) = parent {
+        let root = match parent["root_id"].as_str() {
+            Some(root) => root.to_string(),
+            None => parent["message_id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        };
+        message["root_id"] = Value::String(root);
+        message["parent_id"] = parent["message_id"].clone();
+    }
+    state.im.messages.push(message.clone());
+    message
+}
+
+/// 模拟用户发送文本消息并生成 `im.message.receive_v1` 事件
+pub(super) fn simulate_user_message(
+    state: &mut FakeState,
+    chat_id: &str,
+    open_id: &str,
+    text: &str,
+) -> String {
+    let content = json!({ "text": text }).to_string();
+    let sender = json!({
+        "id": open_id,
+        "id_type": "open_id",
+        "sender_type": "user",
+        "tenant_key": state.tenant_key,
+    });
+    let message = push_message(
+        state,
+        chat_id,
+        sender,
+        &json!({"msg_type": "text", "content": content}),
+        None,
+    );
+    let chat_type = if state.im.chat(chat_id).is_some() {
+        "group"
+    } else {
+        "p2p"
+    };
+    let user = state.contact.user_ids_by_open_id(open_id);
+    state.emit(
+        "im.message.receive_v1",
+        json!({
+            "sender": {
+                "sender_id": user,
+                "sender_type": "user",
+                "tenant_key": state.tenant_key,
+            },
+            "message": {
+                "message_id": message["message_id"],
+                "create_time": message["create_time"],
+                "update_time": message["update_time"],
+                "chat_id": chat_id,
+                "chat_type": chat_type,
+                "message_type": "text",
+                "content": content,
+            },
+        }),
+    );
+    message["message_id"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// 会话中未撤回的消息
+pub(super) fn messages_in(state: &FakeState, chat_id: &str) -> Vec<Value> {
+    state
+        .im
+        .messages
+        .iter()
+        .filter(|message| message["chat_id"] == chat_id && message["deleted"] == false)
+        .cloned()
+        .collect()
+}
+
+/// 浅合并 JSON 对象
+pub(super) fn merge(target: &mut Value, patch: &Value) {
+    if let (Some(target), Some(patch)) = (target.as_object_mut(), patch.as_object()) {
+        for (key, value) in patch {
+            target.insert(key.clone(), value.clone());
+        }
+    } else if target.is_null() {
+        *target = Value::Object(Map::new());
+        merge(target, patch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FakeFeishu;
+    use super::*;
+    use crate::{api::ApiRequest, http::Transport};
+
+    #[tokio::test]
+    async fn test_send_list_and_recall_messages() {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+        let chat_id = fake.add_chat("项目群");
+
+        let body = json!({
+            "receive_id": chat_id,
+            "msg_type": "text",
+            "content": "{\"text\":\"hi\"}",
+            "uuid": "dedupe-1",
+        });
+        let req: ApiRequest<Value> = ApiRequest::post("/open-apis/im/v1/messages")
+            .query("receive_id_type", "chat_id")
+            .json_body(&body);
+        let first = Transport::<Value>::request(req.clone(), &config, None)
+            .await
+            .unwrap();
+        let second = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap();
+        let message_id = first.data.unwrap()["message_id"].clone();
+        assert_eq!(second.data.unwrap()["message_id"], message_id);
+        assert_eq!(fake.messages(&chat_id).len(), 1);
+
+        let req: ApiRequest<Value> = ApiRequest::get("/open-apis/im/v1/messages")
+            .query("container_id_type", "chat")
+            .query("container_id", chat_id.as_str());
+        let list = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap();
+        assert_eq!(list.data.unwrap()["items"][0]["message_id"], message_id);
+
+        let url = format!("/open-apis/im/v1/messages/{}", message_id.as_str().unwrap());
+        let req: ApiRequest<Value> = ApiRequest::delete(url.as_str());
+        assert!(
+            Transport::<Value>::request(req, &config, None)
+                .await
+                .unwrap()
+                .is_success()
+        );
+        assert!(fake.messages(&chat_id).is_empty());
+        assert_eq!(fake.events_of("im.message.recalled_v1").len(), 1);
+
+        let req: ApiRequest<Value> = ApiRequest::get(url.as_str());
+        let resp = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap();
+        assert_eq!(resp.code(), ERR_CODE_MESSAGE_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_reply_threads_under_root() {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+        let chat_id = fake.add_chat("话题群");
+        let root = fake.simulate_user_message(&chat_id, "ou_x", "问题");
+
+        let req: ApiRequest<Value> =
+            ApiRequest::post(format!("/open-apis/im/v1/messages/{root}/reply"))
+                .json_body(&json!({"msg_type": "text", "content": "{\"text\":\"答复\"}"}));
+        let reply = Transport::<Value>::request(req, &config, None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+        assert_eq!(reply["root_id"], root.as_str());
+        assert_eq!(reply["chat_id"], chat_id.as_str());
+    }
+}
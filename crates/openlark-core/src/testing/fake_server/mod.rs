@@ -0,0 +1,833 @@
+//! 有状态的进程内飞书开放平台仿真服务
+//!
+//! 与 [`TestServer`](super::mock_server) 按路由挂载静态响应不同，[`FakeFeishu`] 在内存中维护一份
+//! "开放平台"状态，让业务代码可以在无网络的情况下端到端测试：
+//!
+//! - 鉴权：签发并校验 `tenant_access_token` / `app_access_token` / `user_access_token`，支持过期
+//! - 消息：群组、发送/回复/撤回消息、按会话分页拉取历史消息
+//! - 多维表格：数据表、记录增删改查、批量操作，以及带真实筛选/排序语义的记录搜索
+//! - 云空间：文件夹、multipart 上传文件、下载、列表、删除
+//! - 通讯录：部门、用户的创建/查询/更新/删除
+//! - 事件：状态变化时生成与开放平台一致结构（schema 2.0）的事件，推送给注册的处理函数
+//! - 故障注入：限流、token 失效、5xx
+//!
+//! ```rust,ignore
+//! use openlark_core::testing::fake_server::{FakeFeishu, Fault};
+//!
+//! let fake = FakeFeishu::start().await;
+//! let chat_id = fake.add_chat("测试群");
+//! fake.on_event(|event| println!("{}", event.event_type));
+//! fake.inject(Fault::rate_limit().path("/open-apis/im").times(1));
+//!
+//! // 使用 fake.config() 构建任意业务服务，请求都会落到仿真服务上
+//! let config = fake.config();
+//! ```
+//!
+//! 需要启用 `fake-server` feature。
+
+mod auth;
+mod bitable;
+mod contact;
+mod drive;
+mod im;
+mod multipart;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use serde_json::{Map, Value, json};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate, matchers::any};
+
+use crate::{
+    config::Config,
+    constants::{
+        ERR_CODE_ACCESS_TOKEN_INVALID, ERR_CODE_APP_ACCESS_TOKEN_INVALID,
+        ERR_CODE_TENANT_ACCESS_TOKEN_INVALID, HTTP_HEADER_KEY_LOG_ID, HTTP_HEADER_RATELIMIT_LIMIT,
+        HTTP_HEADER_RATELIMIT_RESET,
+    },
+};
+
+pub use auth::FakeTokenProvider;
+pub use contact::FakeUserIds;
+
+/// 缺少 access token 时返回的错误码
+const ERR_CODE_MISSING_ACCESS_TOKEN: i32 = 99991661;
+/// 用户 token 过期时返回的错误码
+const ERR_CODE_USER_ACCESS_TOKEN_EXPIRED: i32 = 99991677;
+/// 触发限流时返回的错误码
+const ERR_CODE_RATE_LIMITED: i32 = 99991400;
+/// 路由不存在时返回的错误码
+const ERR_CODE_NOT_FOUND: i32 = 404;
+
+/// 事件处理函数
+type EventHandler = Arc<dyn Fn(&FakeEvent) + Send + Sync>;
+
+/// 仿真服务推送的事件
+#[derive(Debug, Clone, PartialEq)]
+pub struct FakeEvent {
+    /// 事件类型，例如 `im.message.receive_v1`
+    pub event_type: String,
+    /// 事件 ID
+    pub event_id: String,
+    /// 完整的事件回调体（schema 2.0）
+    pub payload: Value,
+}
+
+impl FakeEvent {
+    /// 事件体（`payload.event`）
+    pub fn event(&self) -> &Value {
+        &self.payload["event"]
+    }
+
+    /// 序列化为回调请求体，可直接交给事件分发器处理
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.payload).unwrap_or_default()
+    }
+}
+
+/// 故障类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultKind {
+    /// 限流：HTTP 429，携带 `x-ogw-ratelimit-*` 响应头
+    RateLimit {
+        /// 限流配额
+        limit: u32,
+        /// 重置等待秒数
+        reset_secs: u64,
+    },
+    /// token 失效：按请求携带的 token 类型返回对应的失效错误码
+    TokenExpired,
+    /// 服务端错误
+    ServerError(u16),
+}
+
+/// 故障注入规则
+///
+/// 默认匹配除鉴权接口外的所有请求，且一直生效；可通过 [`Fault::path`] / [`Fault::method`]
+/// 缩小范围，通过 [`Fault::times`] 限定生效次数。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fault {
+    kind: FaultKind,
+    method: Option<String>,
+    path_prefix: Option<String>,
+    remaining: Option<usize>,
+}
+
+impl Fault {
+    /// 创建指定类型的故障
+    pub fn new(kind: FaultKind) -> Self {
+        Self {
+            kind,
+            method: None,
+            path_prefix: None,
+            remaining: None,
+        }
+    }
+
+    /// 限流故障（配额 50，1 秒后重置）
+    pub fn rate_limit() -> Self {
+        Self::new(FaultKind::RateLimit {
+            limit: 50,
+            reset_secs: 1,
+        })
+    }
+
+    /// token 失效故障
+    pub fn token_expired() -> Self {
+        Self::new(FaultKind::TokenExpired)
+    }
+
+    /// 服务端错误
+    pub fn server_error(status: u16) -> Self {
+        Self::new(FaultKind::ServerError(status))
+    }
+
+    /// 仅匹配以该前缀开头的路径，例如 `/open-apis/im`
+    pub fn path(mut self, prefix: impl Into<String>) -> Self {
+        self.path_prefix = Some(prefix.into());
+        self
+    }
+
+    /// 仅匹配指定 HTTP 方法
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into().to_ascii_uppercase());
+        self
+    }
+
+    /// 仅生效 `n` 次
+    pub fn times(mut self, n: usize) -> Self {
+        self.remaining = Some(n);
+        self
+    }
+
+    fn matches(&self, req: &FakeRequest) -> bool {
+        if let Some(method) = &self.method
+            && method != &req.method
+        {
+            return false;
+        }
+        match &self.path_prefix {
+            Some(prefix) => req.path.starts_with(prefix.as_str()),
+            // 未指定路径时不影响换取 token，避免故障落在 TokenProvider 上
+            None => !req.path.starts_with("/open-apis/auth/"),
+        }
+    }
+}
+
+/// 解析后的请求
+pub(crate) struct FakeRequest {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) query: HashMap<String, String>,
+    pub(crate) headers: wiremock::http::HeaderMap,
+    pub(crate) body: Vec<u8>,
+}
+
+impl FakeRequest {
+    fn from_wiremock(request: &Request) -> Self {
+        Self {
+            method: request.method.as_str().to_ascii_uppercase(),
+            path: request.url.path().to_string(),
+            query: request.url.query_pairs().into_owned().collect(),
+            headers: request.headers.clone(),
+            body: request.body.clone(),
+        }
+    }
+
+    /// 请求体 JSON（为空或非法时返回空对象）
+    pub(crate) fn json(&self) -> Value {
+        serde_json::from_slice(&self.body).unwrap_or_else(|_| Value::Object(Map::new()))
+    }
+
+    pub(crate) fn query(&self, key: &str) -> Option<&str> {
+        self.query.get(key).map(String::as_str)
+    }
+
+    fn bearer_token(&self) -> Option<&str> {
+        self.headers
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+    }
+}
+
+/// 构造成功响应
+pub(crate) fn ok(data: Value) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({"code": 0, "msg": "success", "data": data}))
+}
+
+/// 构造业务错误响应
+pub(crate) fn fail(status: u16, code: i32, msg: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_json(json!({"code": code, "msg": msg}))
+}
+
+/// 按 `page_size` / `page_token` 分页，`page_token` 为偏移量
+pub(crate) fn paginate(items: Vec<Value>, req: &FakeRequest, default_size: usize) -> Value {
+    let page_size = req
+        .query("page_size")
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|size| *size > 0)
+        .unwrap_or(default_size);
+    let offset = req
+        .query("page_token")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let total = items.len();
+    let page: Vec<Value> = items.into_iter().skip(offset).take(page_size).collect();
+    let next = offset + page.len();
+    let has_more = next < total;
+    let mut data = json!({"items": page, "has_more": has_more, "total": total});
+    if has_more {
+        data["page_token"] = Value::String(next.to_string());
+    }
+    data
+}
+
+/// 已签发的 token
+#[derive(Debug, Clone)]
+struct IssuedToken {
+    kind: TokenKind,
+    expired: bool,
+    expires_at: std::time::Instant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    App,
+    Tenant,
+    User,
+}
+
+/// 仿真服务内部状态
+pub(crate) struct FakeState {
+    pub(crate) app_id: String,
+    pub(crate) app_secret: String,
+    pub(crate) tenant_key: String,
+    token_ttl: Duration,
+    tokens: HashMap<String, IssuedToken>,
+    faults: Vec<Fault>,
+    seq: u64,
+    events: Vec<FakeEvent>,
+    pending: Vec<FakeEvent>,
+    request_log: Vec<String>,
+    pub(crate) im: im::ImState,
+    pub(crate) bitable: bitable::BitableState,
+    pub(crate) drive: drive::DriveState,
+    pub(crate) contact: contact::ContactState,
+}
+
+impl FakeState {
+    fn new(app_id: String, app_secret: String) -> Self {
+        Self {
+            app_id,
+            app_secret,
+            tenant_key: "fake_tenant".to_string(),
+            token_ttl: Duration::from_secs(7200),
+            tokens: HashMap::new(),
+            faults: Vec::new(),
+            seq: 0,
+            events: Vec::new(),
+            pending: Vec::new(),
+            request_log: Vec::new(),
+            im: Default::default(),
+            bitable: Default::default(),
+            drive: Default::default(),
+            contact: contact::ContactState::new(),
+        }
+    }
+
+    /// 生成带前缀的唯一 ID
+    pub(crate) fn next_id(&mut self, prefix: &str) -> String {
+        self.seq += 1;
+        format!("{prefix}{:012x}", self.seq)
+    }
+
+    /// 当前毫秒时间戳字符串
+    pub(crate) fn now_ms() -> String {
+        chrono::Utc::now().timestamp_millis().to_string()
+    }
+
+    /// 生成事件，等待响应返回后推送
+    pub(crate) fn emit(&mut self, event_type: &str, event: Value) {
+        let event_id = self.next_id("ev_");
+        let payload = json!({
+            "schema": "2.0",
+            "header": {
+                "event_id": event_id,
+                "token": "",
+                "create_time": Self::now_ms(),
+                "event_type": event_type,
+                "tenant_key": self.tenant_key,
+                "app_id": self.app_id,
+            },
+            "event": event,
+        });
+        self.pending.push(FakeEvent {
+            event_type: event_type.to_string(),
+            event_id,
+            payload,
+        });
+    }
+
+    fn issue_token(&mut self, kind: TokenKind) -> String {
+        let prefix = match kind {
+            TokenKind::App => "a-",
+            TokenKind::Tenant => "t-",
+            TokenKind::User => "u-",
+        };
+        let token = self.next_id(prefix);
+        self.tokens.insert(
+            token.clone(),
+            IssuedToken {
+                kind,
+                expired: false,
+                expires_at: std::time::Instant::now() + self.token_ttl,
+            },
+        );
+        token
+    }
+
+    /// 校验请求携带的 token
+    #[allow(clippy::result_large_err)]
+    fn check_token(&self, req: &FakeRequest) -> Result<(), ResponseTemplate> {
+        let Some(token) = req.bearer_token() else {
+            return Err(fail(
+                400,
+                ERR_CODE_MISSING_ACCESS_TOKEN,
+                "Missing access token for authorization",
+            ));
+        };
+        let Some(issued) = self.tokens.get(token) else {
+            return Err(fail(
+                400,
+                ERR_CODE_ACCESS_TOKEN_INVALID,
+                "Invalid access token",
+            ));
+        };
+        if issued.expired || issued.expires_at <= std::time::Instant::now() {
+            return Err(token_expired(issued.kind));
+        }
+        Ok(())
+    }
+
+    fn take_fault(&mut self, req: &FakeRequest) -> Option<FaultKind> {
+        let index = self.faults.iter().position(|fault| fault.matches(req))?;
+        let fault = &mut self.faults[index];
+        let kind = fault.kind;
+        if let Some(remaining) = fault.remaining.as_mut() {
+            *remaining -= 1;
+            if *remaining == 0 {
+                self.faults.remove(index);
+            }
+        }
+        Some(kind)
+    }
+
+    fn token_kind(&self, req: &FakeRequest) -> TokenKind {
+        req.bearer_token()
+            .and_then(|token| self.tokens.get(token))
+            .map(|issued| issued.kind)
+            .unwrap_or(TokenKind::Tenant)
+    }
+
+    fn handle(&mut self, req: &FakeRequest) -> ResponseTemplate {
+        self.request_log
+            .push(format!("{} {}", req.method, req.path));
+
+        if let Some(kind) = self.take_fault(req) {
+            return match kind {
+                FaultKind::RateLimit { limit, reset_secs } => fail(
+                    429,
+                    ERR_CODE_RATE_LIMITED,
+                    "request trigger frequency limit",
+                )
+                .insert_header(HTTP_HEADER_RATELIMIT_LIMIT, limit.to_string().as_str())
+                .insert_header(HTTP_HEADER_RATELIMIT_RESET, reset_secs.to_string().as_str()),
+                FaultKind::TokenExpired => token_expired(self.token_kind(req)),
+                FaultKind::ServerError(status) => fail(status, -1, "internal server error"),
+            };
+        }
+
+        let segments: Vec<&str> = req
+            .path
+            .trim_start_matches("/open-apis/")
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        if segments.first() == Some(&"auth") {
+            return auth::handle(self, req, &segments[1..])
+                .unwrap_or_else(|| fail(404, ERR_CODE_NOT_FOUND, "not found"));
+        }
+
+        if let Err(resp) = self.check_token(req) {
+            return resp;
+        }
+
+        let handled = match segments.first().copied() {
+            Some("im") => im::handle(self, req, &segments[1..]),
+            Some("bitable") => bitable::handle(self, req, &segments[1..]),
+            Some("drive") => drive::handle(self, req, &segments[1..]),
+            Some("contact") => contact::handle(self, req, &segments[1..]),
+            _ => None,
+        };
+        handled.unwrap_or_else(|| fail(404, ERR_CODE_NOT_FOUND, "not found"))
+    }
+}
+
+fn token_expired(kind: TokenKind) -> ResponseTemplate {
+    match kind {
+        TokenKind::App => fail(
+            400,
+            ERR_CODE_APP_ACCESS_TOKEN_INVALID,
+            "Invalid access token for authorization",
+        ),
+        TokenKind::Tenant => fail(
+            400,
+            ERR_CODE_TENANT_ACCESS_TOKEN_INVALID,
+            "Invalid access token for authorization",
+        ),
+        TokenKind::User => fail(400, ERR_CODE_USER_ACCESS_TOKEN_EXPIRED, "token expired"),
+    }
+}
+
+/// wiremock 响应器：把每个请求交给内存状态处理
+struct FakeResponder {
+    state: Arc<Mutex<FakeState>>,
+    handlers: Arc<Mutex<Vec<EventHandler>>>,
+}
+
+impl Respond for FakeResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let req = FakeRequest::from_wiremock(request);
+        let (response, events, log_id) = {
+            let mut state = lock(&self.state);
+            let response = state.handle(&req);
+            let events = std::mem::take(&mut state.pending);
+            state.events.extend(events.iter().cloned());
+            let log_id = state.next_id("fake_log_");
+            (response, events, log_id)
+        };
+
+        // 释放状态锁后再回调，处理函数可以安全地读取仿真服务
+        if !events.is_empty() {
+            let handlers = lock(&self.handlers).clone();
+            for event in &events {
+                for handler in &handlers {
+                    handler(event);
+                }
+            }
+        }
+        response.insert_header(HTTP_HEADER_KEY_LOG_ID, log_id.as_str())
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 有状态的飞书开放平台仿真服务
+pub struct FakeFeishu {
+    server: MockServer,
+    state: Arc<Mutex<FakeState>>,
+    handlers: Arc<Mutex<Vec<EventHandler>>>,
+}
+
+impl std::fmt::Debug for FakeFeishu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FakeFeishu")
+            .field("uri", &self.server.uri())
+            .finish()
+    }
+}
+
+impl FakeFeishu {
+    /// 使用默认应用凭证启动
+    pub async fn start() -> Self {
+        Self::start_with("cli_fake_app", "fake_app_secret").await
+    }
+
+    /// 使用指定应用凭证启动
+    pub async fn start_with(app_id: impl Into<String>, app_secret: impl Into<String>) -> Self {
+        let server = MockServer::start().await;
+        let state = Arc::new(Mutex::new(FakeState::new(app_id.into(), app_secret.into())));
+        let handlers: Arc<Mutex<Vec<EventHandler>>> = Arc::new(Mutex::new(Vec::new()));
+        Mock::given(any())
+            .respond_with(FakeResponder {
+                state: state.clone(),
+                handlers: handlers.clone(),
+            })
+            .mount(&server)
+            .await;
+        Self {
+            server,
+            state,
+            handlers,
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, FakeState> {
+        lock(&self.state)
+    }
+
+    /// 服务地址，作为 `base_url` 使用
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// 应用 ID
+    pub fn app_id(&self) -> String {
+        self.state().app_id.clone()
+    }
+
+    /// 应用密钥
+    pub fn app_secret(&self) -> String {
+        self.state().app_secret.clone()
+    }
+
+    /// 租户 key
+    pub fn tenant_key(&self) -> String {
+        self.state().tenant_key.clone()
+    }
+
+    /// 指向仿真服务的配置，token 通过 [`FakeTokenProvider`] 向仿真服务换取
+    pub fn config(&self) -> Config {
+        let state = self.state();
+        Config::builder()
+            .app_id(state.app_id.clone())
+            .app_secret(state.app_secret.clone())
+            .base_url(self.server.uri())
+            .token_provider(FakeTokenProvider::new(
+                self.server.uri(),
+                state.app_id.clone(),
+                state.app_secret.clone(),
+            ))
+            .build()
+    }
+
+    /// 设置新签发 token 的有效期（默认 2 小时）
+    pub fn set_token_ttl(&self, ttl: Duration) {
+        self.state().token_ttl = ttl;
+    }
+
+    /// 直接签发一个 `tenant_access_token`
+    pub fn issue_tenant_token(&self) -> String {
+        self.state().issue_token(TokenKind::Tenant)
+    }
+
+    /// 直接签发一个 `user_access_token`
+    pub fn issue_user_token(&self) -> String {
+        self.state().issue_token(TokenKind::User)
+    }
+
+    /// 使所有已签发的 token 失效
+    pub fn expire_tokens(&self) {
+        for token in self.state().tokens.values_mut() {
+            token.expired = true;
+        }
+    }
+
+    /// 注入故障
+    pub fn inject(&self, fault: Fault) {
+        self.state().faults.push(fault);
+    }
+
+    /// 清除所有故障
+    pub fn clear_faults(&self) {
+        self.state().faults.clear();
+    }
+
+    /// 注册事件处理函数
+    ///
+    /// 处理函数在仿真服务的请求处理线程中同步调用，应尽快返回。
+    pub fn on_event(&self, handler: impl Fn(&FakeEvent) + Send + Sync + 'static) {
+        lock(&self.handlers).push(Arc::new(handler));
+    }
+
+    /// 已产生的全部事件
+    pub fn events(&self) -> Vec<FakeEvent> {
+        self.state().events.clone()
+    }
+
+    /// 指定类型的事件
+    pub fn events_of(&self, event_type: &str) -> Vec<FakeEvent> {
+        self.state()
+            .events
+            .iter()
+            .filter(|event| event.event_type == event_type)
+            .cloned()
+            .collect()
+    }
+
+    /// 已收到的请求（`METHOD /path`）
+    pub fn request_log(&self) -> Vec<String> {
+        self.state().request_log.clone()
+    }
+
+    /// 创建群组，返回 `chat_id`
+    pub fn add_chat(&self, name: &str) -> String {
+        let mut state = self.state();
+        im::create_chat(&mut state, json!({ "name": name }))["chat_id"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// 模拟用户在群里发送文本消息，生成 `im.message.receive_v1` 事件，返回 `message_id`
+    pub fn simulate_user_message(&self, chat_id: &str, open_id: &str, text: &str) -> String {
+        let message_id = {
+            let mut state = self.state();
+            im::simulate_user_message(&mut state, chat_id, open_id, text)
+        };
+        self.flush_events();
+        message_id
+    }
+
+    /// 群内未撤回的消息（按发送顺序）
+    pub fn messages(&self, chat_id: &str) -> Vec<Value> {
+        im::messages_in(&self.state(), chat_id)
+    }
+
+    /// 创建多维表格，返回 `app_token`
+    pub fn add_bitable_app(&self, name: &str) -> String {
+        bitable::create_app(&mut self.state(), name)
+    }
+
+    /// 数据表中的全部记录
+    pub fn records(&self, app_token: &str, table_id: &str) -> Vec<Value> {
+        bitable::records_of(&self.state(), app_token, table_id)
+    }
+
+    /// 云空间根目录 token
+    pub fn root_folder_token(&self) -> String {
+        drive::ROOT_FOLDER_TOKEN.to_string()
+    }
+
+    /// 创建文件夹，返回文件夹 token
+    pub fn add_folder(&self, name: &str, parent: &str) -> String {
+        drive::create_folder(&mut self.state(), name, parent)
+    }
+
+    /// 已上传文件的内容
+    pub fn file_content(&self, file_token: &str) -> Option<Vec<u8>> {
+        drive::content_of(&self.state(), file_token)
+    }
+
+    /// 创建部门，返回 `department_id`
+    pub fn add_department(&self, name: &str, parent_department_id: &str) -> String {
+        let department_id = {
+            let mut state = self.state();
+            contact::create_department(
+                &mut state,
+                json!({"name": name, "parent_department_id": parent_department_id}),
+            )
+        };
+        self.flush_events();
+        department_id
+    }
+
+    /// 创建用户，返回三种用户 ID
+    pub fn add_user(&self, name: &str, department_ids: &[&str]) -> FakeUserIds {
+        let ids = {
+            let mut state = self.state();
+            contact::create_user(
+                &mut state,
+                json!({"name": name, "department_ids": department_ids}),
+            )
+        };
+        self.flush_events();
+        ids
+    }
+
+    /// 把直接操作状态时产生的事件推送给处理函数
+    fn flush_events(&self) {
+        let events = {
+            let mut state = self.state();
+            let events = std::mem::take(&mut state.pending);
+            state.events.extend(events.iter().cloned());
+            events
+        };
+        let handlers = lock(&self.handlers).clone();
+        for event in &events {
+            for handler in &handlers {
+                handler(event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        SDKResult,
+        api::{ApiRequest, Response},
+        http::Transport,
+        req_option::RequestOption,
+    };
+
+    async fn call(
+        config: &Config,
+        req: ApiRequest<Value>,
+        option: Option<RequestOption>,
+    ) -> SDKResult<Response<Value>> {
+        Transport::request(req, config, option).await
+    }
+
+    #[tokio::test]
+    async fn test_tenant_token_flow_and_validation() {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+
+        let req = ApiRequest::get("/open-apis/im/v1/chats");
+        let resp = call(&config, req, None).await.unwrap();
+        assert!(resp.is_success(), "{:?}", resp.raw_response);
+        assert!(
+            fake.request_log()
+                .contains(&"POST /open-apis/auth/v3/tenant_access_token/internal".to_string())
+        );
+        assert!(resp.log_id().unwrap().starts_with("fake_log_"));
+
+        let option = RequestOption::builder()
+            .tenant_access_token("t-unknown")
+            .build();
+        let req = ApiRequest::get("/open-apis/im/v1/chats");
+        let resp = call(&config, req, Some(option)).await.unwrap();
+        assert_eq!(resp.code(), ERR_CODE_ACCESS_TOKEN_INVALID);
+    }
+
+    #[tokio::test]
+    async fn test_expired_tokens_are_rejected() {
+        let fake = FakeFeishu::start().await;
+        let token = fake.issue_tenant_token();
+        fake.expire_tokens();
+        let option = RequestOption::builder().tenant_access_token(token).build();
+        let req = ApiRequest::get("/open-apis/im/v1/chats");
+        let resp = call(&fake.config(), req, Some(option)).await.unwrap();
+        assert_eq!(resp.code(), ERR_CODE_TENANT_ACCESS_TOKEN_INVALID);
+    }
+
+    #[tokio::test]
+    async fn test_faults_apply_in_order_and_expire() {
+        let fake = FakeFeishu::start().await;
+        let config = fake.config();
+        fake.inject(Fault::rate_limit().path("/open-apis/im").times(1));
+        fake.inject(Fault::server_error(503).method("GET").times(1));
+
+        let req = ApiRequest::get("/open-apis/im/v1/chats");
+        let resp = call(&config, req, None).await.unwrap();
+        assert_eq!(resp.code(), ERR_CODE_RATE_LIMITED);
+        assert_eq!(resp.meta.status, 429);
+        assert_eq!(resp.meta.rate_limit.unwrap().limit, Some(50));
+
+        let req = ApiRequest::get("/open-apis/im/v1/chats");
+        let resp = call(&config, req, None).await.unwrap();
+        assert_eq!(resp.meta.status, 503);
+
+        let req = ApiRequest::get("/open-apis/im/v1/chats");
+        assert!(call(&config, req, None).await.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_event_handlers_receive_events() {
+        let fake = FakeFeishu::start().await;
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        fake.on_event(move |event| sink.lock().unwrap().push(event.event_type.clone()));
+
+        let chat_id = fake.add_chat("测试群");
+        let user = fake.add_user("张三", &["0"]);
+        fake.simulate_user_message(&chat_id, &user.open_id, "hello");
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(
+            received,
+            vec!["contact.user.created_v3", "im.message.receive_v1"]
+        );
+        let event = &fake.events_of("im.message.receive_v1")[0];
+        assert_eq!(event.event()["message"]["chat_id"], chat_id);
+        assert_eq!(event.payload["header"]["app_id"], "cli_fake_app");
+    }
+
+    #[test]
+    fn test_paginate() {
+        let req = FakeRequest {
+            method: "GET".to_string(),
+            path: "/".to_string(),
+            query: HashMap::from([("page_size".to_string(), "2".to_string())]),
+            headers: Default::default(),
+            body: Vec::new(),
+        };
+        let data = paginate((0..5).map(|i| json!(i)).collect(), &req, 20);
+        assert_eq!(data["items"], json!([0, 1]));
+        assert_eq!(data["page_token"], "2");
+        assert_eq!(data["total"], 5);
+    }
+}
@@ -0,0 +1,104 @@
+//! 最小化的 multipart/form-data 解析器，只覆盖 SDK 上传接口发出的请求
+
+use std::collections::HashMap;
+
+/// 解析后的表单
+#[derive(Debug, Default)]
+pub(super) struct MultipartForm {
+    /// 普通文本字段
+    pub(super) fields: HashMap<String, String>,
+    /// 文件字段：字段名 -> (文件名, 内容)
+    pub(super) files: HashMap<String, (Option<String>, Vec<u8>)>,
+}
+
+/// 从 `Content-Type` 中取出 boundary
+pub(super) fn boundary(content_type: &str) -> Option<&str> {
+    let (mime, params) = content_type.split_once(';')?;
+    if !mime.trim().eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+    params.split(';').find_map(|param| {
+        let (key, value) = param.trim().split_once('=')?;
+        key.eq_ignore_ascii_case("boundary")
+            .then(|| value.trim_matches('"'))
+    })
+}
+
+/// 解析 multipart 请求体
+pub(super) fn parse(body: &[u8], boundary: &str) -> Option<MultipartForm> {
+    let delimiter = format!("--{boundary}").into_bytes();
+    let mut form = MultipartForm::default();
+    let mut rest = body;
+
+    let start = find(rest, &delimiter)?;
+    rest = &rest[start + delimiter.len()..];
+    loop {
+        // 结束分隔符 `--boundary--`
+        if rest.starts_with(b"--") {
+            return Some(form);
+        }
+        rest = rest.strip_prefix(b"\r\n")?;
+        let end = find(rest, &delimiter)?;
+        let part = rest[..end].strip_suffix(b"\r\n")?;
+        rest = &rest[end + delimiter.len()..];
+
+        let split = find(part, b"\r\n\r\n")?;
+        let headers = std::str::from_utf8(&part[..split]).ok()?;
+        let content = &part[split + 4..];
+        let disposition = headers.lines().find(|line| {
+            line.to_ascii_lowercase()
+                .starts_with("content-disposition:")
+        })?;
+        let name = param(disposition, "name")?;
+        match param(disposition, "filename") {
+            Some(file_name) => {
+                form.files.insert(name, (Some(file_name), content.to_vec()));
+            }
+            None if headers
+                .to_ascii_lowercase()
+                .contains("application/octet-stream") =>
+            {
+                form.files.insert(name, (None, content.to_vec()));
+            }
+            None => {
+                form.fields
+                    .insert(name, String::from_utf8_lossy(content).into_owned());
+            }
+        }
+    }
+}
+
+fn param(header: &str, key: &str) -> Option<String> {
+    header.split(';').skip(1).find_map(|item| {
+        let (name, value) = item.trim().split_once('=')?;
+        (name == key).then(|| value.trim_matches('"').to_string())
+    })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fields_and_file() {
+        let body = b"--XyZ\r\n\
+Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\n\r\n\
+hello\r\nworld\r\n\
+--XyZ\r\n\
+Content-Disposition: form-data; name=\"parent_node\"\r\n\r\n\
+fld_root\r\n\
+--XyZ--\r\n";
+        let content_type = "multipart/form-data; boundary=XyZ";
+        let form = parse(body, boundary(content_type).unwrap()).unwrap();
+        assert_eq!(form.fields["parent_node"], "fld_root");
+        let (name, content) = &form.files["file"];
+        assert_eq!(name.as_deref(), Some("a.txt"));
+        assert_eq!(content, b"hello\r\nworld");
+    }
+}
@@ -7,6 +7,7 @@
 //! - [`assertions`]：类型安全的断言宏系统
 //! - [`fixtures`]：统一的测试夹具和配置构建器
 //! - [`mock_context`]：Mock 服务器配置和测试运行时
+//! - `fake_server`：有状态的进程内开放平台仿真服务（需启用 `fake-server` feature）
 //!
 //! # 使用示例
 //!
@@ -28,6 +29,9 @@ pub mod assertions;
 pub mod fixtures;
 pub mod mock_context;
 
+#[cfg(feature = "fake-server")]
+pub mod fake_server;
+
 #[cfg(test)]
 pub mod mock_server;
 
@@ -41,5 +45,8 @@ pub mod prelude {
     #[cfg(test)]
     pub use super::mock_server::TestServer;
 
+    #[cfg(feature = "fake-server")]
+    pub use super::fake_server::{FakeEvent, FakeFeishu, Fault};
+
     // 宏通过 #[macro_export] 自动导出，不需要 pub use
 }
@@ -5,6 +5,7 @@
 //! 以便在各业务 crate 里通过宏批量实现。
 
 use crate::SDKResult;
+use crate::api::{ResponseMeta, with_meta};
 use crate::req_option::RequestOption;
 
 /// Builder 可执行抽象
@@ -21,6 +22,11 @@ pub trait ExecutableBuilder<S: Sync, Req, Resp>: Sized {
     async fn execute_with_options(self, service: &S, _option: RequestOption) -> SDKResult<Resp> {
         self.execute(service).await
     }
+
+    /// 执行请求，并返回最后一次 HTTP 响应的元信息（状态码、`X-Tt-Logid`、限流信息等）
+    async fn execute_with_meta(self, service: &S) -> SDKResult<(Resp, ResponseMeta)> {
+        with_meta(self.execute(service)).await
+    }
 }
 
 #[cfg(test)]
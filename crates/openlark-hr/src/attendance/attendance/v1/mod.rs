@@ -12,6 +12,8 @@ pub mod leave_accrual_record;
 pub mod leave_employ_expire_record;
 /// shift 子模块。
 pub mod shift;
+/// types 子模块（日期、时间戳与打卡枚举）。
+pub mod types;
 /// user_approval 子模块。
 pub mod user_approval;
 /// user_daily_shift 子模块。
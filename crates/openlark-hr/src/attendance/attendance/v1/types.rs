@@ -0,0 +1,297 @@
+//! 考勤 v1 通用类型
+//!
+//! 考勤接口中的日期统一为 `yyyyMMdd` 整数（如 `20240101`），打卡时间为 Unix 秒级时间戳。
+//! 本模块提供基于 `chrono` 的强类型封装以及打卡类型、结果、方式等枚举。
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use openlark_core::{SDKResult, error::CoreError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// 考勤日期
+///
+/// 写入时为 `yyyyMMdd` 整数；读取时兼容整数、`yyyyMMdd` 字符串与 `yyyy-MM-dd` 字符串。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AttendanceDate(pub NaiveDate);
+
+impl AttendanceDate {
+    /// 由年月日构建，日期非法时返回 `None`
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        NaiveDate::from_ymd_opt(year, month, day).map(Self)
+    }
+
+    /// 由 `yyyyMMdd` 整数构建，日期非法时返回 `None`
+    pub fn from_int(value: u32) -> Option<Self> {
+        Self::from_ymd((value / 10000) as i32, value / 100 % 100, value % 100)
+    }
+
+    /// 转换为 `yyyyMMdd` 整数
+    pub fn to_int(&self) -> u32 {
+        self.0.year() as u32 * 10000 + self.0.month() * 100 + self.0.day()
+    }
+
+    /// 获取内部的 `NaiveDate`
+    pub fn naive(&self) -> NaiveDate {
+        self.0
+    }
+
+    /// 该日期在指定时区内的零点
+    ///
+    /// 考勤日按考勤组所在时区划分，跨时区统计时应使用该方法而非 UTC 零点。
+    pub fn start_in<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        tz.from_local_datetime(&self.0.and_hms_opt(0, 0, 0)?)
+            .earliest()
+    }
+}
+
+impl From<NaiveDate> for AttendanceDate {
+    fn from(date: NaiveDate) -> Self {
+        Self(date)
+    }
+}
+
+impl fmt::Display for AttendanceDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.format("%Y%m%d"))
+    }
+}
+
+impl FromStr for AttendanceDate {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        NaiveDate::parse_from_str(s, "%Y%m%d")
+            .or_else(|_| NaiveDate::parse_from_str(s, "%Y-%m-%d"))
+            .map(Self)
+    }
+}
+
+impl Serialize for AttendanceDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.to_int())
+    }
+}
+
+impl<'de> Deserialize<'de> for AttendanceDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let parsed = match &value {
+            Value::Number(n) => n
+                .as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .and_then(Self::from_int),
+            Value::String(s) => s.parse().ok(),
+            _ => None,
+        };
+        parsed.ok_or_else(|| serde::de::Error::custom(format!("无法解析考勤日期: {value}")))
+    }
+}
+
+/// 考勤时间戳（Unix 秒）
+///
+/// 写入时为秒级时间戳字符串（与开放平台返回格式一致）；读取时兼容数字与字符串。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AttendanceTimestamp(pub DateTime<Utc>);
+
+impl AttendanceTimestamp {
+    /// 由 Unix 秒构建，超出范围时返回 `None`
+    pub fn from_secs(secs: i64) -> Option<Self> {
+        DateTime::<Utc>::from_timestamp(secs, 0).map(Self)
+    }
+
+    /// 转换为 Unix 秒
+    pub fn secs(&self) -> i64 {
+        self.0.timestamp()
+    }
+
+    /// 转换到指定时区
+    pub fn with_timezone<Tz: TimeZone>(&self, tz: &Tz) -> DateTime<Tz> {
+        self.0.with_timezone(tz)
+    }
+
+    /// 该时刻在指定时区内所属的考勤日期
+    ///
+    /// 直接取 UTC 日期会让东八区 0 点至 8 点的打卡落到前一天。
+    pub fn date_in<Tz: TimeZone>(&self, tz: &Tz) -> AttendanceDate {
+        AttendanceDate(self.0.with_timezone(tz).date_naive())
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for AttendanceTimestamp {
+    fn from(value: DateTime<Tz>) -> Self {
+        Self(value.with_timezone(&Utc))
+    }
+}
+
+impl Serialize for AttendanceTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.secs().to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AttendanceTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let secs = match &value {
+            Value::Number(n) => n.as_i64(),
+            Value::String(s) => s.trim().parse::<i64>().ok(),
+            _ => None,
+        };
+        secs.and_then(Self::from_secs)
+            .ok_or_else(|| serde::de::Error::custom(format!("无法解析打卡时间: {value}")))
+    }
+}
+
+/// 打卡类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(i32)]
+pub enum CheckInType {
+    /// 上班打卡
+    OnDuty = 1,
+    /// 下班打卡
+    OffDuty = 2,
+    /// 外出打卡
+    Out = 3,
+}
+
+/// 打卡方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(i32)]
+pub enum CheckInMethod {
+    /// 手机打卡
+    Mobile = 1,
+    /// 考勤机打卡
+    Machine = 2,
+    /// 手动导入
+    Import = 3,
+    /// 补卡
+    Remedy = 4,
+}
+
+/// 打卡结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CheckInResult {
+    /// 正常
+    Normal,
+    /// 早退
+    Early,
+    /// 迟到
+    Late,
+    /// 缺卡
+    Lack,
+    /// 无需打卡
+    #[serde(rename = "NoNeedCheck")]
+    NoNeed,
+    /// 系统打卡
+    SystemCheck,
+    /// 未打卡（当天考勤尚未结束）
+    Todo,
+    /// 未识别的结果
+    #[serde(other)]
+    Unknown,
+}
+
+impl CheckInResult {
+    /// 是否为需要关注的异常结果（早退、迟到、缺卡）
+    pub fn is_abnormal(&self) -> bool {
+        matches!(self, Self::Early | Self::Late | Self::Lack)
+    }
+
+    /// 开放平台使用的取值
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Early => "Early",
+            Self::Late => "Late",
+            Self::Lack => "Lack",
+            Self::NoNeed => "NoNeedCheck",
+            Self::SystemCheck => "SystemCheck",
+            Self::Todo => "Todo",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+/// 校验并取出必填的查询日期范围
+pub(crate) fn require_date_range(
+    start_date: Option<AttendanceDate>,
+    end_date: Option<AttendanceDate>,
+) -> SDKResult<(AttendanceDate, AttendanceDate)> {
+    let start_date = start_date.ok_or_else(|| CoreError::validation_msg("查询起始日期不能为空"))?;
+    let end_date = end_date.ok_or_else(|| CoreError::validation_msg("查询结束日期不能为空"))?;
+    if end_date < start_date {
+        return Err(openlark_core::error::validation_error(
+            "查询日期范围无效",
+            "查询结束日期不能早于起始日期",
+        ));
+    }
+    Ok((start_date, end_date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+    use serde_json::json;
+
+    #[test]
+    fn test_attendance_date_wire_format() {
+        let date = AttendanceDate::from_ymd(2024, 1, 5).unwrap();
+        assert_eq!(serde_json::to_value(date).unwrap(), json!(20240105));
+        assert_eq!(date.to_string(), "20240105");
+
+        for raw in [json!(20240105), json!("20240105"), json!("2024-01-05")] {
+            let parsed: AttendanceDate = serde_json::from_value(raw).unwrap();
+            assert_eq!(parsed, date);
+        }
+        assert!(serde_json::from_value::<AttendanceDate>(json!(20241301)).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_date_in_timezone() {
+        // 2024-01-05 01:30 +08:00，UTC 下仍是 1 月 4 日
+        let ts: AttendanceTimestamp = serde_json::from_value(json!("1704389400")).unwrap();
+        let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+
+        assert_eq!(
+            ts.date_in(&Utc),
+            AttendanceDate::from_int(20240104).unwrap()
+        );
+        assert_eq!(
+            ts.date_in(&shanghai),
+            AttendanceDate::from_int(20240105).unwrap()
+        );
+        assert_eq!(serde_json::to_value(ts).unwrap(), json!("1704389400"));
+        assert_eq!(
+            serde_json::from_value::<AttendanceTimestamp>(json!(1704389400)).unwrap(),
+            ts
+        );
+
+        let start = AttendanceDate::from_int(20240105)
+            .unwrap()
+            .start_in(&shanghai)
+            .unwrap();
+        assert!(AttendanceTimestamp::from(start) <= ts);
+    }
+
+    #[test]
+    fn test_check_in_enums() {
+        let result: CheckInResult = serde_json::from_value(json!("NoNeedCheck")).unwrap();
+        assert_eq!(result, CheckInResult::NoNeed);
+        let result: CheckInResult = serde_json::from_value(json!("Leave")).unwrap();
+        assert_eq!(result, CheckInResult::Unknown);
+        assert!(CheckInResult::Late.is_abnormal());
+
+        assert_eq!(
+            serde_json::to_value(CheckInType::OffDuty).unwrap(),
+            json!(2)
+        );
+        let method: CheckInMethod = serde_json::from_value(json!(4)).unwrap();
+        assert_eq!(method, CheckInMethod::Remedy);
+    }
+}
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::attendance::attendance::v1::types::AttendanceDate;
+
 // 导入打卡流水相关模型 (batch_create)
 
 /// 导入打卡流水请求体
@@ -97,10 +99,10 @@ pub struct UserFlowResult {
 /// 查询打卡流水请求体
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryUserFlowRequestBody {
-    /// 查询的起始日期，格式为 yyyy-MM-dd
-    pub start_date: String,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
-    pub end_date: String,
+    /// 查询的起始日期（yyyyMMdd）
+    pub start_date: AttendanceDate,
+    /// 查询的结束日期（yyyyMMdd）
+    pub end_date: AttendanceDate,
     /// 查询的用户 ID 列表，最多支持 50 个用户
     pub user_ids: Vec<String>,
     /// 用户 ID 类型，可选值：open_id、union_id、user_id
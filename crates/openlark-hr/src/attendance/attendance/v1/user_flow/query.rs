@@ -7,24 +7,28 @@ use openlark_core::{
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
-    validate_required, validate_required_list,
+    identity::{UserRef, common_user_id_type},
+    validate_required_list,
 };
 
 use super::models::{QueryUserFlowRequestBody, QueryUserFlowResponse};
+use crate::attendance::attendance::v1::types::{AttendanceDate, require_date_range};
 
 /// 批量查询打卡流水请求
 #[derive(Debug, Clone)]
 pub struct QueryUserFlowRequest {
-    /// 查询的起始日期，格式为 yyyy-MM-dd
-    start_date: String,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
-    end_date: String,
+    /// 查询的起始日期
+    start_date: Option<AttendanceDate>,
+    /// 查询的结束日期
+    end_date: Option<AttendanceDate>,
     /// 查询的用户 ID 列表，最多支持 50 个用户
     user_ids: Vec<String>,
     /// 用户 ID 类型，可选值：open_id、union_id、user_id
     user_id_type: Option<String>,
     /// 查询的打卡类型，可选值：1-上班打卡、2-下班打卡、3-外出打卡
     punch_type: Option<i32>,
+    /// 通过 [`QueryUserFlowRequest::users`] 设置的用户引用
+    users: Vec<UserRef>,
     /// 配置信息
     config: Config,
 }
@@ -33,24 +37,25 @@ impl QueryUserFlowRequest {
     /// 创建批量查询打卡流水请求
     pub fn new(config: Config) -> Self {
         Self {
-            start_date: String::new(),
-            end_date: String::new(),
+            start_date: None,
+            end_date: None,
             user_ids: Vec::new(),
             user_id_type: None,
             punch_type: None,
+            users: Vec::new(),
             config,
         }
     }
 
     /// 设置查询起始日期（必填）
-    pub fn start_date(mut self, start_date: String) -> Self {
-        self.start_date = start_date;
+    pub fn start_date(mut self, start_date: impl Into<AttendanceDate>) -> Self {
+        self.start_date = Some(start_date.into());
         self
     }
 
     /// 设置查询结束日期（必填）
-    pub fn end_date(mut self, end_date: String) -> Self {
-        self.end_date = end_date;
+    pub fn end_date(mut self, end_date: impl Into<AttendanceDate>) -> Self {
+        self.end_date = Some(end_date.into());
         self
     }
 
@@ -60,6 +65,19 @@ impl QueryUserFlowRequest {
         self
     }
 
+    /// 使用类型化的用户引用设置查询用户列表与用户 ID 类型
+    ///
+    /// 所有引用需为同一种 open_id / union_id / user_id，否则执行时返回校验错误。
+    pub fn users(mut self, users: impl IntoIterator<Item = UserRef>) -> Self {
+        self.users = users.into_iter().collect();
+        self.user_ids = self.users.iter().map(|u| u.value().to_string()).collect();
+        self.user_id_type = common_user_id_type(&self.users)
+            .ok()
+            .flatten()
+            .map(str::to_string);
+        self
+    }
+
     /// 设置用户 ID 类型（可选）
     pub fn user_id_type(mut self, user_id_type: String) -> Self {
         self.user_id_type = Some(user_id_type);
@@ -86,9 +104,9 @@ impl QueryUserFlowRequest {
         use crate::common::api_endpoints::AttendanceApiV1;
 
         // 1. 验证必填字段
-        validate_required!(self.start_date.trim(), "查询起始日期不能为空");
-        validate_required!(self.end_date.trim(), "查询结束日期不能为空");
+        let (start_date, end_date) = require_date_range(self.start_date, self.end_date)?;
         validate_required_list!(self.user_ids, 50, "用户 ID 列表不能为空且不能超过 50 个");
+        common_user_id_type(&self.users)?;
 
         // 2. 构建端点
         let api_endpoint = AttendanceApiV1::UserFlowQuery;
@@ -101,8 +119,8 @@ impl QueryUserFlowRequest {
 
         // 4. 序列化请求体
         let request_body = QueryUserFlowRequestBody {
-            start_date: self.start_date,
-            end_date: self.end_date,
+            start_date,
+            end_date,
             user_ids: self.user_ids,
             user_id_type: self.user_id_type,
             punch_type: self.punch_type,
@@ -142,7 +160,7 @@ mod tests {
     #[test]
     fn test_query_user_flow_request_builder_new() {
         let request = QueryUserFlowRequest::new(TestConfigBuilder::new().build())
-            .start_date("test".to_string());
+            .start_date(AttendanceDate::from_int(20240101).unwrap());
         let _ = request;
     }
 
@@ -153,4 +171,20 @@ mod tests {
         let result = rt.block_on(request.execute());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_query_user_flow_request_with_user_refs() {
+        let request = QueryUserFlowRequest::new(TestConfigBuilder::new().build())
+            .users([UserRef::open_id("ou_1"), UserRef::open_id("ou_2")]);
+        assert_eq!(request.user_ids, vec!["ou_1", "ou_2"]);
+        assert_eq!(request.user_id_type.as_deref(), Some("open_id"));
+
+        let request = QueryUserFlowRequest::new(TestConfigBuilder::new().build())
+            .start_date(AttendanceDate::from_int(20240101).unwrap())
+            .end_date(AttendanceDate::from_int(20240102).unwrap())
+            .users([UserRef::open_id("ou_1"), UserRef::user_id("u_2")]);
+        assert_eq!(request.user_id_type, None);
+        let rt = tokio::runtime::Runtime::new().expect("创建 tokio runtime 失败");
+        assert!(rt.block_on(request.execute()).is_err());
+    }
 }
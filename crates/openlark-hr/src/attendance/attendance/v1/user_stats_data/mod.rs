@@ -1,5 +1,8 @@
 pub mod models;
 pub mod query;
 
-pub use models::{QueryRequestBody, QueryResponse, UserStatsDataItem};
+pub use models::{
+    QueryRequestBody, QueryResponse, StatDuration, StatFieldIndex, StatItem, StatValue,
+    UserStatsDataItem,
+};
 pub use query::QueryRequest;
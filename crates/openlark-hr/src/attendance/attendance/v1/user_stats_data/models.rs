@@ -2,16 +2,22 @@
 //!
 //! docPath: https://open.feishu.cn/document/server-docs/attendance-v1/user_stats_data/query
 
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::TimeDelta;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::attendance::attendance::v1::types::AttendanceDate;
+use crate::attendance::attendance::v1::user_stats_field::{StatField, StatFieldKind};
+
 /// 查询统计数据请求体
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryRequestBody {
-    /// 查询的起始日期，格式为 yyyy-MM-dd
-    pub start_date: String,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
-    pub end_date: String,
+    /// 查询的起始日期（yyyyMMdd）
+    pub start_date: AttendanceDate,
+    /// 查询的结束日期（yyyyMMdd）
+    pub end_date: AttendanceDate,
     /// 查询的用户 ID 列表，最多支持 50 个用户
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_ids: Option<Vec<String>>,
@@ -40,8 +46,8 @@ pub struct UserStatsDataItem {
     /// 用户姓名
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_name: Option<String>,
-    /// 日期，格式为 yyyy-MM-dd
-    pub date: String,
+    /// 考勤日期（yyyyMMdd）
+    pub date: AttendanceDate,
     /// 考勤组 ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_id: Option<String>,
@@ -53,16 +59,16 @@ pub struct UserStatsDataItem {
     pub basic_info: Option<Value>,
     /// 出勤统计
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attendance_stats: Option<Value>,
+    pub attendance_stats: Option<Vec<StatItem>>,
     /// 异常统计
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub abnormal_stats: Option<Value>,
+    pub abnormal_stats: Option<Vec<StatItem>>,
     /// 请假统计
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub leave_stats: Option<Value>,
+    pub leave_stats: Option<Vec<StatItem>>,
     /// 加班统计
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub overtime_stats: Option<Value>,
+    pub overtime_stats: Option<Vec<StatItem>>,
     /// 打卡时间
     #[serde(skip_serializing_if = "Option::is_none")]
     pub punch_time: Option<Value>,
@@ -74,6 +80,161 @@ pub struct UserStatsDataItem {
     pub custom_fields: Option<Value>,
 }
 
+impl UserStatsDataItem {
+    /// 遍历出勤、异常、请假、加班分组下的全部统计项
+    pub fn stat_items(&self) -> impl Iterator<Item = &StatItem> {
+        [
+            &self.attendance_stats,
+            &self.abnormal_stats,
+            &self.leave_stats,
+            &self.overtime_stats,
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+    }
+
+    /// 按字段编码查找统计项
+    pub fn stat(&self, code: &str) -> Option<&StatItem> {
+        self.stat_items().find(|item| item.code == code)
+    }
+}
+
+/// 单个统计项
+///
+/// `code` 对应 `user_stats_field` 返回的 `field_id`。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatItem {
+    /// 字段编码
+    pub code: String,
+    /// 字段标题
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 原始取值
+    #[serde(default)]
+    pub value: String,
+    /// 时长明细（仅时长类字段返回）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_num: Option<StatDuration>,
+}
+
+impl StatItem {
+    /// 按字段种类解析取值
+    ///
+    /// 无法按指定种类解析时退化为 [`StatValue::Text`]，不会丢失原始内容。
+    pub fn parse(&self, kind: StatFieldKind) -> StatValue {
+        let raw = self.value.trim();
+        if raw.is_empty() || raw == "-" {
+            return match self
+                .duration_num
+                .as_ref()
+                .and_then(StatDuration::to_duration)
+            {
+                Some(duration) if kind == StatFieldKind::Duration => StatValue::Duration(duration),
+                _ => StatValue::Empty,
+            };
+        }
+        let parsed = match kind {
+            StatFieldKind::Number => raw.parse().ok().map(StatValue::Number),
+            StatFieldKind::Duration => self
+                .duration_num
+                .as_ref()
+                .and_then(StatDuration::to_duration)
+                .map(StatValue::Duration)
+                .or_else(|| raw.parse().ok().map(StatValue::Number)),
+            StatFieldKind::Date => raw.parse().ok().map(StatValue::Date),
+            StatFieldKind::Text => None,
+        };
+        parsed.unwrap_or_else(|| StatValue::Text(raw.to_string()))
+    }
+}
+
+/// 时长明细
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StatDuration {
+    /// 小时数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hour: Option<String>,
+    /// 分钟数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minute: Option<String>,
+}
+
+impl StatDuration {
+    /// 合并为 `TimeDelta`，小时与分钟均缺失或无法解析时返回 `None`
+    pub fn to_duration(&self) -> Option<TimeDelta> {
+        let parse = |v: &Option<String>| v.as_deref().and_then(|v| v.trim().parse::<f64>().ok());
+        let (hour, minute) = (parse(&self.hour), parse(&self.minute));
+        if hour.is_none() && minute.is_none() {
+            return None;
+        }
+        let secs = hour.unwrap_or(0.0) * 3600.0 + minute.unwrap_or(0.0) * 60.0;
+        TimeDelta::try_seconds(secs.round() as i64)
+    }
+}
+
+/// 解析后的统计值
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatValue {
+    /// 数值
+    Number(f64),
+    /// 时长
+    Duration(TimeDelta),
+    /// 日期
+    Date(AttendanceDate),
+    /// 文本
+    Text(String),
+    /// 无数据
+    Empty,
+}
+
+/// 以统计字段编码为键的字段定义索引
+///
+/// 由 `user_stats_field` 查询结果构建，用于把统计数据中的原始字符串解析为 [`StatValue`]。
+#[derive(Debug, Clone, Default)]
+pub struct StatFieldIndex {
+    fields: HashMap<String, StatField>,
+}
+
+impl StatFieldIndex {
+    /// 由字段定义列表构建
+    pub fn new(fields: impl IntoIterator<Item = StatField>) -> Self {
+        Self {
+            fields: fields
+                .into_iter()
+                .map(|field| (field.field_id.clone(), field))
+                .collect(),
+        }
+    }
+
+    /// 按编码获取字段定义
+    pub fn get(&self, code: &str) -> Option<&StatField> {
+        self.fields.get(code)
+    }
+
+    /// 解析单个统计项，未登记的字段按文本处理
+    pub fn value(&self, item: &StatItem) -> StatValue {
+        let kind = self
+            .get(&item.code)
+            .map(StatField::kind)
+            .unwrap_or(StatFieldKind::Text);
+        item.parse(kind)
+    }
+
+    /// 解析用户统计数据中的全部统计项，以字段编码为键
+    pub fn resolve(&self, data: &UserStatsDataItem) -> BTreeMap<String, StatValue> {
+        data.stat_items()
+            .map(|item| (item.code.clone(), self.value(item)))
+            .collect()
+    }
+}
+
+impl FromIterator<StatField> for StatFieldIndex {
+    fn from_iter<T: IntoIterator<Item = StatField>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
 /// 查询统计数据响应
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct QueryResponse {
@@ -97,14 +258,14 @@ mod tests {
         let item = UserStatsDataItem {
             user_id: "u_1".to_string(),
             user_name: Some("张三".to_string()),
-            date: "2024-01-01".to_string(),
+            date: AttendanceDate::from_int(20240101).unwrap(),
             group_id: Some("g_1".to_string()),
             group_name: Some("研发组".to_string()),
             basic_info: Some(json!({"dept": "RD"})),
-            attendance_stats: Some(json!({"normal": 1})),
-            abnormal_stats: Some(json!({"late": 0})),
-            leave_stats: Some(json!({"annual": 1})),
-            overtime_stats: Some(json!({"hours": 2})),
+            attendance_stats: Some(vec![stat("51201", "1")]),
+            abnormal_stats: Some(vec![stat("51302", "0")]),
+            leave_stats: Some(vec![stat("51401", "1")]),
+            overtime_stats: None,
             punch_time: Some(json!({"start": "09:00"})),
             check_result: Some(json!({"status": "normal"})),
             custom_fields: Some(json!({"level": "P7"})),
@@ -114,5 +275,67 @@ mod tests {
         let parsed: UserStatsDataItem = serde_json::from_str(&text).expect("反序列化失败");
         assert_eq!(parsed.user_id, "u_1");
         assert_eq!(parsed.group_name.as_deref(), Some("研发组"));
+        assert_eq!(parsed, item);
+    }
+
+    fn stat(code: &str, value: &str) -> StatItem {
+        StatItem {
+            code: code.to_string(),
+            title: None,
+            value: value.to_string(),
+            duration_num: None,
+        }
+    }
+
+    fn field(id: &str, field_type: &str) -> StatField {
+        StatField {
+            field_id: id.to_string(),
+            field_name: None,
+            field_type: Some(field_type.to_string()),
+            field_desc: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_stats_by_field_metadata() {
+        let item: UserStatsDataItem = serde_json::from_value(json!({
+            "user_id": "u_1",
+            "date": 20240105,
+            "attendance_stats": [
+                {"code": "51201", "title": "出勤天数", "value": "21.5"},
+                {"code": "51202", "title": "工作时长", "value": "8.5小时",
+                 "duration_num": {"hour": "8", "minute": "30"}},
+                {"code": "51203", "title": "最早打卡日", "value": "2024-01-02"}
+            ],
+            "abnormal_stats": [{"code": "51302", "title": "迟到次数", "value": "-"}],
+            "overtime_stats": [{"code": "59999", "value": "备注"}]
+        }))
+        .expect("反序列化失败");
+
+        let index: StatFieldIndex = [
+            field("51201", "number"),
+            field("51202", "duration"),
+            field("51203", "date"),
+            field("51302", "number"),
+        ]
+        .into_iter()
+        .collect();
+        let values = index.resolve(&item);
+
+        assert_eq!(values["51201"], StatValue::Number(21.5));
+        assert_eq!(
+            values["51202"],
+            StatValue::Duration(TimeDelta::minutes(510))
+        );
+        assert_eq!(
+            values["51203"],
+            StatValue::Date(AttendanceDate::from_int(20240102).unwrap())
+        );
+        assert_eq!(values["51302"], StatValue::Empty);
+        assert_eq!(values["59999"], StatValue::Text("备注".to_string()));
+        assert_eq!(
+            item.stat("51202").and_then(|s| s.title.as_deref()),
+            Some("工作时长")
+        );
     }
 }
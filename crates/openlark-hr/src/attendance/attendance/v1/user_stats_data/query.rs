@@ -7,18 +7,18 @@ use openlark_core::{
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
-    validate_required,
 };
 
 use super::models::{QueryRequestBody, QueryResponse};
+use crate::attendance::attendance::v1::types::{AttendanceDate, require_date_range};
 
 /// 查询统计数据请求
 #[derive(Debug, Clone)]
 pub struct QueryRequest {
-    /// 查询的起始日期，格式为 yyyy-MM-dd
-    start_date: String,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
-    end_date: String,
+    /// 查询的起始日期
+    start_date: Option<AttendanceDate>,
+    /// 查询的结束日期
+    end_date: Option<AttendanceDate>,
     /// 查询的用户 ID 列表，最多支持 50 个用户
     user_ids: Vec<String>,
     /// 查询的考勤组 ID 列表
@@ -39,8 +39,8 @@ impl QueryRequest {
     /// 创建查询统计数据请求
     pub fn new(config: Config) -> Self {
         Self {
-            start_date: String::new(),
-            end_date: String::new(),
+            start_date: None,
+            end_date: None,
             user_ids: Vec::new(),
             group_ids: Vec::new(),
             user_id_type: None,
@@ -52,14 +52,14 @@ impl QueryRequest {
     }
 
     /// 设置查询起始日期（必填）
-    pub fn start_date(mut self, start_date: String) -> Self {
-        self.start_date = start_date;
+    pub fn start_date(mut self, start_date: impl Into<AttendanceDate>) -> Self {
+        self.start_date = Some(start_date.into());
         self
     }
 
     /// 设置查询结束日期（必填）
-    pub fn end_date(mut self, end_date: String) -> Self {
-        self.end_date = end_date;
+    pub fn end_date(mut self, end_date: impl Into<AttendanceDate>) -> Self {
+        self.end_date = Some(end_date.into());
         self
     }
 
@@ -113,8 +113,7 @@ impl QueryRequest {
         use crate::common::api_endpoints::AttendanceApiV1;
 
         // 1. 验证必填字段
-        validate_required!(self.start_date.trim(), "查询起始日期不能为空");
-        validate_required!(self.end_date.trim(), "查询结束日期不能为空");
+        let (start_date, end_date) = require_date_range(self.start_date, self.end_date)?;
 
         // 至少需要指定用户 ID 或考勤组 ID
         if self.user_ids.is_empty() && self.group_ids.is_empty() {
@@ -153,8 +152,8 @@ impl QueryRequest {
 
         // 4. 序列化请求体
         let request_body = QueryRequestBody {
-            start_date: self.start_date,
-            end_date: self.end_date,
+            start_date,
+            end_date,
             user_ids: if self.user_ids.is_empty() {
                 None
             } else {
@@ -204,8 +203,8 @@ mod tests {
 
     #[test]
     fn test_query_request_builder_new() {
-        let request =
-            QueryRequest::new(TestConfigBuilder::new().build()).start_date("test".to_string());
+        let request = QueryRequest::new(TestConfigBuilder::new().build())
+            .start_date(AttendanceDate::from_int(20240101).unwrap());
         let _ = request;
     }
 
@@ -1,5 +1,5 @@
 pub mod models;
 pub mod query;
 
-pub use models::{QueryRequestBody, QueryResponse, StatField};
+pub use models::{QueryRequestBody, QueryResponse, StatField, StatFieldKind};
 pub use query::QueryRequest;
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::attendance::attendance::v1::types::AttendanceDate;
+
 /// 查询统计字段定义请求体
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryRequestBody {
@@ -16,12 +18,12 @@ pub struct QueryRequestBody {
     /// 用户 ID 列表
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_ids: Option<Vec<String>>,
-    /// 查询的起始日期，格式为 yyyy-MM-dd
+    /// 查询的起始日期（yyyyMMdd）
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<String>,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
+    pub start_date: Option<AttendanceDate>,
+    /// 查询的结束日期（yyyyMMdd）
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date: Option<String>,
+    pub end_date: Option<AttendanceDate>,
     /// 是否包含下属，默认值为 false
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_include_subordinate: Option<bool>,
@@ -46,6 +48,41 @@ pub struct StatField {
     pub field_desc: Option<String>,
 }
 
+impl StatField {
+    /// 按字段类型推断统计值的种类
+    pub fn kind(&self) -> StatFieldKind {
+        self.field_type
+            .as_deref()
+            .map(StatFieldKind::from_field_type)
+            .unwrap_or(StatFieldKind::Text)
+    }
+}
+
+/// 统计字段值的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatFieldKind {
+    /// 数值（次数、天数等）
+    Number,
+    /// 时长
+    Duration,
+    /// 日期
+    Date,
+    /// 文本及其他无法识别的类型
+    Text,
+}
+
+impl StatFieldKind {
+    /// 由 `field_type` 字符串解析
+    pub fn from_field_type(field_type: &str) -> Self {
+        match field_type.trim().to_ascii_lowercase().as_str() {
+            "number" | "int" | "integer" | "float" | "decimal" => Self::Number,
+            "duration" | "time_length" => Self::Duration,
+            "date" => Self::Date,
+            _ => Self::Text,
+        }
+    }
+}
+
 /// 查询统计字段定义响应
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct QueryResponse {
@@ -76,5 +113,6 @@ mod tests {
         let parsed: StatField = serde_json::from_str(&text).expect("反序列化失败");
         assert_eq!(parsed.field_id, "f_1");
         assert_eq!(parsed.field_type.as_deref(), Some("number"));
+        assert_eq!(parsed.kind(), StatFieldKind::Number);
     }
 }
@@ -11,6 +11,7 @@ use openlark_core::{
 };
 
 use super::models::{QueryRequestBody, QueryResponse};
+use crate::attendance::attendance::v1::types::AttendanceDate;
 
 /// 查询统计字段定义请求
 #[derive(Debug, Clone)]
@@ -21,10 +22,10 @@ pub struct QueryRequest {
     stat_type: Option<String>,
     /// 用户 ID 列表
     user_ids: Vec<String>,
-    /// 查询的起始日期，格式为 yyyy-MM-dd
-    start_date: Option<String>,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
-    end_date: Option<String>,
+    /// 查询的起始日期
+    start_date: Option<AttendanceDate>,
+    /// 查询的结束日期
+    end_date: Option<AttendanceDate>,
     /// 是否包含下属，默认值为 false
     is_include_subordinate: Option<bool>,
     /// 分页标记，用于获取下一页数据
@@ -73,14 +74,14 @@ impl QueryRequest {
     }
 
     /// 设置查询起始日期（可选）
-    pub fn start_date(mut self, start_date: String) -> Self {
-        self.start_date = Some(start_date);
+    pub fn start_date(mut self, start_date: impl Into<AttendanceDate>) -> Self {
+        self.start_date = Some(start_date.into());
         self
     }
 
     /// 设置查询结束日期（可选）
-    pub fn end_date(mut self, end_date: String) -> Self {
-        self.end_date = Some(end_date);
+    pub fn end_date(mut self, end_date: impl Into<AttendanceDate>) -> Self {
+        self.end_date = Some(end_date.into());
         self
     }
 
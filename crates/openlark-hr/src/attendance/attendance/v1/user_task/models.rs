@@ -4,24 +4,25 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::attendance::attendance::v1::types::{
+    AttendanceDate, AttendanceTimestamp, CheckInMethod, CheckInResult, CheckInType,
+};
+
 /// 查询打卡结果请求体
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryUserTaskRequestBody {
-    /// 查询的起始日期，格式为 yyyy-MM-dd
-    pub start_date: String,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
-    pub end_date: String,
+    /// 查询的起始日期（yyyyMMdd）
+    pub start_date: AttendanceDate,
+    /// 查询的结束日期（yyyyMMdd）
+    pub end_date: AttendanceDate,
     /// 查询的用户 ID 列表，最多支持 50 个用户
     pub user_ids: Vec<String>,
     /// 用户 ID 类型，可选值：open_id、union_id、user_id
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id_type: Option<String>,
-    /// 查询的打卡类型，可选值：
-    /// - 1: 上班打卡
-    /// - 2: 下班打卡
-    /// - 3: 外出打卡
+    /// 查询的打卡类型
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub check_in_type: Option<i32>,
+    pub check_in_type: Option<CheckInType>,
 }
 
 /// 打卡记录
@@ -29,15 +30,12 @@ pub struct QueryUserTaskRequestBody {
 pub struct UserTaskRecord {
     /// 用户 ID
     pub user_id: String,
-    /// 打卡日期，格式为 yyyy-MM-dd
-    pub date: String,
+    /// 考勤日期（yyyyMMdd）
+    pub date: AttendanceDate,
     /// 打卡类型
-    /// - 1: 上班打卡
-    /// - 2: 下班打卡
-    /// - 3: 外出打卡
-    pub check_in_type: i32,
-    /// 打卡时间，格式为 yyyy-MM-dd HH:mm:ss
-    pub check_in_time: String,
+    pub check_in_type: CheckInType,
+    /// 打卡时间（Unix 秒）
+    pub check_in_time: AttendanceTimestamp,
     /// 打卡地点名称
     #[serde(skip_serializing_if = "Option::is_none")]
     pub check_in_place_name: Option<String>,
@@ -45,20 +43,9 @@ pub struct UserTaskRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub check_in_place_id: Option<String>,
     /// 打卡结果
-    /// - 1: 正常
-    /// - 2: 迟到
-    /// - 3: 早退
-    /// - 4: 缺卡
-    /// - 5: 地点异常
-    /// - 6: Wi-Fi 异常
-    /// - 7: 设备异常
-    pub check_in_result: i32,
+    pub check_in_result: CheckInResult,
     /// 打卡方式
-    /// - 1: 手机打卡
-    /// - 2: 考勤机打卡
-    /// - 3: 手动导入
-    /// - 4: 补卡
-    pub check_in_method: i32,
+    pub check_in_method: CheckInMethod,
     /// 打卡设备 ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub device_id: Option<String>,
@@ -112,13 +99,13 @@ mod tests {
     fn test_user_task_record_serialization_roundtrip() {
         let record = UserTaskRecord {
             user_id: "u_1".to_string(),
-            date: "2024-01-01".to_string(),
-            check_in_type: 1,
-            check_in_time: "2024-01-01 09:00:00".to_string(),
+            date: AttendanceDate::from_int(20240101).unwrap(),
+            check_in_type: CheckInType::OnDuty,
+            check_in_time: AttendanceTimestamp::from_secs(1704070800).unwrap(),
             check_in_place_name: Some("总部".to_string()),
             check_in_place_id: Some("p_1".to_string()),
-            check_in_result: 1,
-            check_in_method: 1,
+            check_in_result: CheckInResult::Late,
+            check_in_method: CheckInMethod::Mobile,
             device_id: Some("dev_1".to_string()),
             device_name: Some("iPhone".to_string()),
             wifi_name: Some("Office".to_string()),
@@ -134,6 +121,9 @@ mod tests {
         let text = serde_json::to_string(&record).expect("序列化失败");
         let parsed: UserTaskRecord = serde_json::from_str(&text).expect("反序列化失败");
         assert_eq!(parsed.user_id, "u_1");
-        assert_eq!(parsed.check_in_result, 1);
+        assert_eq!(parsed, record);
+        assert!(text.contains(r#""date":20240101"#));
+        assert!(text.contains(r#""check_in_time":"1704070800""#));
+        assert!(text.contains(r#""check_in_result":"Late""#));
     }
 }
@@ -7,24 +7,25 @@ use openlark_core::{
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
-    validate_required, validate_required_list,
+    validate_required_list,
 };
 
 use super::models::{QueryUserTaskRequestBody, QueryUserTaskResponse};
+use crate::attendance::attendance::v1::types::{AttendanceDate, CheckInType, require_date_range};
 
 /// 查询打卡结果请求
 #[derive(Debug, Clone)]
 pub struct QueryUserTaskRequest {
-    /// 查询的起始日期，格式为 yyyy-MM-dd
-    start_date: String,
-    /// 查询的结束日期，格式为 yyyy-MM-dd
-    end_date: String,
+    /// 查询的起始日期
+    start_date: Option<AttendanceDate>,
+    /// 查询的结束日期
+    end_date: Option<AttendanceDate>,
     /// 查询的用户 ID 列表，最多支持 50 个用户
     user_ids: Vec<String>,
     /// 用户 ID 类型，可选值：open_id、union_id、user_id
     user_id_type: Option<String>,
-    /// 查询的打卡类型，可选值：1-上班打卡、2-下班打卡、3-外出打卡
-    check_in_type: Option<i32>,
+    /// 查询的打卡类型
+    check_in_type: Option<CheckInType>,
     /// 配置信息
     config: Config,
 }
@@ -33,8 +34,8 @@ impl QueryUserTaskRequest {
     /// 创建查询打卡结果请求
     pub fn new(config: Config) -> Self {
         Self {
-            start_date: String::new(),
-            end_date: String::new(),
+            start_date: None,
+            end_date: None,
             user_ids: Vec::new(),
             user_id_type: None,
             check_in_type: None,
@@ -43,14 +44,14 @@ impl QueryUserTaskRequest {
     }
 
     /// 设置查询起始日期（必填）
-    pub fn start_date(mut self, start_date: String) -> Self {
-        self.start_date = start_date;
+    pub fn start_date(mut self, start_date: impl Into<AttendanceDate>) -> Self {
+        self.start_date = Some(start_date.into());
         self
     }
 
     /// 设置查询结束日期（必填）
-    pub fn end_date(mut self, end_date: String) -> Self {
-        self.end_date = end_date;
+    pub fn end_date(mut self, end_date: impl Into<AttendanceDate>) -> Self {
+        self.end_date = Some(end_date.into());
         self
     }
 
@@ -67,7 +68,7 @@ impl QueryUserTaskRequest {
     }
 
     /// 设置打卡类型（可选）
-    pub fn check_in_type(mut self, check_in_type: i32) -> Self {
+    pub fn check_in_type(mut self, check_in_type: CheckInType) -> Self {
         self.check_in_type = Some(check_in_type);
         self
     }
@@ -86,8 +87,7 @@ impl QueryUserTaskRequest {
         use crate::common::api_endpoints::AttendanceApiV1;
 
         // 1. 验证必填字段
-        validate_required!(self.start_date.trim(), "查询起始日期不能为空");
-        validate_required!(self.end_date.trim(), "查询结束日期不能为空");
+        let (start_date, end_date) = require_date_range(self.start_date, self.end_date)?;
         validate_required_list!(self.user_ids, 50, "用户 ID 列表不能为空且不能超过 50 个");
 
         // 2. 构建端点
@@ -101,8 +101,8 @@ impl QueryUserTaskRequest {
 
         // 4. 序列化请求体
         let request_body = QueryUserTaskRequestBody {
-            start_date: self.start_date,
-            end_date: self.end_date,
+            start_date,
+            end_date,
             user_ids: self.user_ids,
             user_id_type: self.user_id_type,
             check_in_type: self.check_in_type,
@@ -142,7 +142,7 @@ mod tests {
     #[test]
     fn test_query_user_task_request_builder_new() {
         let request = QueryUserTaskRequest::new(TestConfigBuilder::new().build())
-            .start_date("test".to_string());
+            .start_date(AttendanceDate::from_int(20240101).unwrap());
         let _ = request;
     }
 
@@ -8,6 +8,17 @@ use openlark_core::config::Config;
 #[allow(clippy::module_inception)]
 pub mod attendance;
 
+#[cfg(feature = "acs")]
+pub use crate::common::attendance_import::AcsPunchSource;
+pub use crate::common::attendance_import::{
+    ContactIdResolver, EmployeeIdResolver, EmployeeRef, ImportOutcome, ImportReport, ImportStatus,
+    PunchImporter, PunchRecord, ResolveFuture, StaticIdResolver,
+};
+pub use crate::common::attendance_report::{
+    AttendanceReport, AttendanceReportRow, AttendanceReporter, ReportFailure, ReportStage,
+    ReportUsers,
+};
+
 /// 考勤服务
 /// Attendance 服务入口。
 #[derive(Debug, Clone)]
@@ -1890,7 +1890,7 @@ pub enum OkrApiV1 {
 
     // === user.okr 资源 (1个) ===
     /// 获取用户的 OKR 列表
-    UserOkrList,
+    UserOkrList(String), // user_id
 }
 
 impl OkrApiV1 {
@@ -1929,7 +1929,9 @@ impl OkrApiV1 {
             OkrApiV1::ReviewQuery => "/open-apis/okr/v1/reviews/query".to_string(),
 
             // user.okr
-            OkrApiV1::UserOkrList => "/open-apis/okr/v1/user_okrs/list".to_string(),
+            OkrApiV1::UserOkrList(user_id) => {
+                format!("/open-apis/okr/v1/users/{user_id}/okrs")
+            }
         }
     }
 }
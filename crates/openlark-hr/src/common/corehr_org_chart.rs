@@ -0,0 +1,182 @@
+//! CoreHR 组织架构数据源
+//!
+//! 将 [`OrgSnapshot`] 适配为 `openlark-communication` 的 [`OrgSource`]，
+//! 使 HR 侧数据与通讯录数据共用同一套组织树构建、导出与对比逻辑。
+
+use chrono::NaiveDate;
+use openlark_communication::contact::{
+    OrgChart, OrgChartBuilder, OrgFuture, OrgMember, OrgSource, OrgUnit,
+};
+use openlark_core::SDKResult;
+
+use super::corehr_timeline::{CorehrTimelineLoader, OrgSnapshot};
+
+/// CoreHR 组织树的虚拟根部门 ID，顶层部门挂在其下。
+pub const COREHR_ROOT_DEPARTMENT_ID: &str = "0";
+
+/// 基于 CoreHR 快照的组织数据源。
+///
+/// - 部门负责人取部门的 `manager_id`（雇佣 ID）；
+/// - 成员为当日主任职所在部门的员工，用户 ID 为雇佣 ID；
+/// - 直属上级取主任职的 `direct_manager_id`；
+/// - 上级部门不在快照中（或为空）的部门视为顶层部门。
+#[derive(Debug, Clone)]
+pub struct CorehrOrgSource {
+    snapshot: OrgSnapshot,
+}
+
+impl CorehrOrgSource {
+    /// 使用已还原的快照创建数据源。
+    pub fn new(snapshot: OrgSnapshot) -> Self {
+        Self { snapshot }
+    }
+
+    /// 加载时间线并还原指定日期的组织。
+    pub async fn load(loader: CorehrTimelineLoader, date: NaiveDate) -> SDKResult<Self> {
+        Ok(Self::new(loader.load().await?.as_of(date)))
+    }
+
+    /// 快照。
+    pub fn snapshot(&self) -> &OrgSnapshot {
+        &self.snapshot
+    }
+
+    /// 构建完整的组织树。
+    pub async fn build_chart(self) -> SDKResult<OrgChart> {
+        OrgChartBuilder::new(self).build().await
+    }
+
+    fn is_top_level(&self, parent_id: Option<&str>) -> bool {
+        parent_id.is_none_or(|parent| !self.snapshot.departments.contains_key(parent))
+    }
+}
+
+impl OrgSource for CorehrOrgSource {
+    fn root_id(&self) -> String {
+        COREHR_ROOT_DEPARTMENT_ID.to_string()
+    }
+
+    fn children<'a>(&'a self, department_id: &'a str) -> OrgFuture<'a, Vec<OrgUnit>> {
+        let root = department_id == COREHR_ROOT_DEPARTMENT_ID;
+        let units = self
+            .snapshot
+            .departments
+            .values()
+            .filter(|d| {
+                let parent = d.parent_department_id.as_deref();
+                if root {
+                    self.is_top_level(parent)
+                } else {
+                    parent == Some(department_id)
+                }
+            })
+            .map(|d| OrgUnit {
+                id: d.department_id.clone(),
+                name: d.name.clone(),
+                parent_id: Some(department_id.to_string()),
+                leader_id: d.manager_id.clone(),
+            })
+            .collect();
+        Box::pin(async move { Ok(units) })
+    }
+
+    fn members<'a>(&'a self, department_id: &'a str) -> OrgFuture<'a, Vec<OrgMember>> {
+        let members = self
+            .snapshot
+            .members_of(department_id)
+            .map(|e| OrgMember {
+                user_id: e.employee.employment_id.clone(),
+                name: e.employee.name.clone(),
+                department_ids: vec![department_id.to_string()],
+                manager_id: e.job.as_ref().and_then(|j| j.direct_manager_id.clone()),
+            })
+            .collect();
+        Box::pin(async move { Ok(members) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::corehr_timeline::{
+        Department, EffectivePeriod, Employee, EmployeeSnapshot, Employment, JobData,
+    };
+    use std::collections::BTreeMap;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn department(id: &str, parent: Option<&str>, manager: &str) -> Department {
+        Department {
+            department_id: id.to_string(),
+            version_id: None,
+            name: Some(id.to_uppercase()),
+            code: None,
+            parent_department_id: parent.map(str::to_string),
+            manager_id: Some(manager.to_string()),
+            active: true,
+            period: EffectivePeriod::new(date("2024-01-01"), None),
+        }
+    }
+
+    fn employee(id: &str, dept: &str, manager: Option<&str>) -> EmployeeSnapshot {
+        EmployeeSnapshot {
+            employee: Employee {
+                employment_id: id.to_string(),
+                person_id: None,
+                employee_number: None,
+                name: Some(id.to_string()),
+                email: None,
+                employment: Employment {
+                    employment_id: id.to_string(),
+                    status: None,
+                    primary: true,
+                    period: EffectivePeriod::new(date("2024-01-01"), None),
+                },
+            },
+            job: Some(JobData {
+                job_data_id: format!("jd-{id}"),
+                version_id: None,
+                employment_id: id.to_string(),
+                department_id: Some(dept.to_string()),
+                position_id: None,
+                job_id: None,
+                job_level_id: None,
+                job_grade_id: None,
+                work_location_id: None,
+                direct_manager_id: manager.map(str::to_string),
+                employee_type_id: None,
+                cost_centers: Vec::new(),
+                primary: true,
+                period: EffectivePeriod::new(date("2024-01-01"), None),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_corehr_source_builds_chart() {
+        let departments = BTreeMap::from([
+            ("hq".to_string(), department("hq", None, "e1")),
+            ("rd".to_string(), department("rd", Some("hq"), "e2")),
+        ]);
+        let employees = BTreeMap::from([
+            ("e1".to_string(), employee("e1", "hq", None)),
+            ("e2".to_string(), employee("e2", "rd", Some("e1"))),
+            ("e3".to_string(), employee("e3", "rd", Some("e2"))),
+        ]);
+        let snapshot = OrgSnapshot {
+            date: date("2024-06-01"),
+            employees,
+            departments,
+            positions: BTreeMap::new(),
+        };
+
+        let chart = CorehrOrgSource::new(snapshot).build_chart().await.unwrap();
+        assert_eq!(chart.nodes["hq"].depth, 1);
+        assert_eq!(chart.nodes["rd"].unit.parent_id.as_deref(), Some("hq"));
+        assert_eq!(chart.nodes["hq"].total_headcount, 3);
+        assert_eq!(chart.leader_chain("rd"), vec!["e2", "e1"]);
+        assert_eq!(chart.manager_chain("e3"), vec!["e2", "e1"]);
+    }
+}
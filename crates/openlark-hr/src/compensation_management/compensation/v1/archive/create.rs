@@ -7,13 +7,19 @@ use openlark_core::{
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 
+use super::models::ArchiveItemValue;
+
 /// 创建薪资档案请求
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct CreateRequest {
+    /// 请求体
+    body: CreateRequestBody,
+    /// 用户 ID 类型（可选）
+    user_id_type: Option<String>,
     /// 配置信息
     config: Config,
 }
@@ -21,7 +27,77 @@ pub struct CreateRequest {
 impl CreateRequest {
     /// 创建请求
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            body: CreateRequestBody::default(),
+            user_id_type: None,
+            config,
+        }
+    }
+
+    /// 设置员工 ID（必填）
+    pub fn user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.body.user_id = user_id.into();
+        self
+    }
+
+    /// 设置用户 ID 类型（可选）
+    pub fn user_id_type(mut self, user_id_type: impl Into<String>) -> Self {
+        self.user_id_type = Some(user_id_type.into());
+        self
+    }
+
+    /// 设置幂等标识（可选）
+    pub fn unique_id(mut self, unique_id: impl Into<String>) -> Self {
+        self.body.unique_id = Some(unique_id.into());
+        self
+    }
+
+    /// 设置薪资方案 ID（必填）
+    pub fn plan_id(mut self, plan_id: impl Into<String>) -> Self {
+        self.body.plan_id = plan_id.into();
+        self
+    }
+
+    /// 设置币种 ID（必填）
+    pub fn currency_id(mut self, currency_id: impl Into<String>) -> Self {
+        self.body.currency_id = currency_id.into();
+        self
+    }
+
+    /// 设置生效日期（必填，`yyyy-MM-dd`）
+    pub fn effective_date(mut self, effective_date: impl Into<String>) -> Self {
+        self.body.effective_date = effective_date.into();
+        self
+    }
+
+    /// 设置调薪原因 ID（可选）
+    pub fn change_reason_id(mut self, change_reason_id: impl Into<String>) -> Self {
+        self.body.change_reason_id = Some(change_reason_id.into());
+        self
+    }
+
+    /// 设置调薪说明（可选）
+    pub fn change_description(mut self, change_description: impl Into<String>) -> Self {
+        self.body.change_description = Some(change_description.into());
+        self
+    }
+
+    /// 设置薪资等级 ID（可选）
+    pub fn salary_level_id(mut self, salary_level_id: impl Into<String>) -> Self {
+        self.body.salary_level_id = Some(salary_level_id.into());
+        self
+    }
+
+    /// 追加薪酬项取值
+    pub fn archive_item(mut self, item: ArchiveItemValue) -> Self {
+        self.body.archive_items.push(item);
+        self
+    }
+
+    /// 设置薪酬项取值列表（必填）
+    pub fn archive_items(mut self, items: Vec<ArchiveItemValue>) -> Self {
+        self.body.archive_items = items;
+        self
     }
 
     /// 执行请求
@@ -37,10 +113,30 @@ impl CreateRequest {
     ) -> SDKResult<CreateResponse> {
         use crate::common::api_endpoints::CompensationApiV1;
 
+        // 1. 验证必填字段
+        validate_required!(self.body.user_id.trim(), "员工 ID 不能为空");
+        validate_required!(self.body.plan_id.trim(), "薪资方案 ID 不能为空");
+        validate_required!(self.body.currency_id.trim(), "币种 ID 不能为空");
+        validate_required!(self.body.effective_date.trim(), "生效日期不能为空");
+        validate_required!(self.body.archive_items, "薪酬项取值不能为空");
+
+        // 2. 构建端点
         let api_endpoint = CompensationApiV1::ArchiveCreate;
-        let request = ApiRequest::<CreateResponse>::post(api_endpoint.to_url());
+        let request = ApiRequest::<CreateResponse>::post(api_endpoint.to_url())
+            .query_opt("user_id_type", self.user_id_type);
+
+        // 3. 序列化请求体
+        let request = request.body(serde_json::to_value(&self.body).map_err(|e| {
+            openlark_core::error::validation_error(
+                "请求体序列化失败",
+                format!("无法序列化请求参数: {e}"),
+            )
+        })?);
+
+        // 4. 发送请求
         let response = Transport::request(request, &self.config, Some(option)).await?;
 
+        // 5. 提取响应数据
         response.data.ok_or_else(|| {
             openlark_core::error::validation_error(
                 "创建薪资档案响应数据为空",
@@ -50,6 +146,33 @@ impl CreateRequest {
     }
 }
 
+/// 创建薪资档案请求体
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateRequestBody {
+    /// 员工 ID
+    pub user_id: String,
+    /// 幂等标识
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_id: Option<String>,
+    /// 薪资方案 ID
+    pub plan_id: String,
+    /// 币种 ID
+    pub currency_id: String,
+    /// 生效日期（`yyyy-MM-dd`）
+    pub effective_date: String,
+    /// 调薪原因 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_reason_id: Option<String>,
+    /// 调薪说明
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_description: Option<String>,
+    /// 薪资等级 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salary_level_id: Option<String>,
+    /// 薪酬项取值
+    pub archive_items: Vec<ArchiveItemValue>,
+}
+
 /// 创建薪资档案响应
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CreateResponse {
@@ -1,2 +1,3 @@
 pub mod create;
+pub mod models;
 pub mod query;
@@ -0,0 +1,24 @@
+//! 薪资档案模型
+
+use serde::{Deserialize, Serialize};
+
+use crate::common::money::Decimal;
+
+/// 薪资档案中的薪酬项取值
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArchiveItemValue {
+    /// 薪酬项 ID
+    pub item_id: String,
+    /// 金额
+    pub amount: Decimal,
+}
+
+impl ArchiveItemValue {
+    /// 创建薪酬项取值
+    pub fn new(item_id: impl Into<String>, amount: Decimal) -> Self {
+        Self {
+            item_id: item_id.into(),
+            amount,
+        }
+    }
+}
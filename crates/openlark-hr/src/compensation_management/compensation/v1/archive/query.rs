@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use super::models::ArchiveItemValue;
+
 /// 批量查询员工薪资档案请求
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -101,6 +103,15 @@ pub struct Archive {
     /// 薪资方案 ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub plan_id: Option<String>,
+    /// 币种 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_id: Option<String>,
+    /// 生效日期
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<String>,
+    /// 薪酬项取值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_items: Option<Vec<ArchiveItemValue>>,
 }
 
 impl ApiResponseTrait for QueryResponse {
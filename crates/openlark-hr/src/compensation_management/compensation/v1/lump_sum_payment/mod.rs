@@ -1,5 +1,6 @@
 pub mod batch_create;
 pub mod batch_remove;
 pub mod batch_update;
+pub mod models;
 pub mod query;
 pub mod query_detail;
@@ -0,0 +1,77 @@
+//! 一次性支付写入模型
+
+use serde::{Deserialize, Serialize};
+
+use crate::common::money::Decimal;
+
+/// 一次性支付记录（批量创建 / 更正）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LumpSumPaymentRecord {
+    /// 记录 ID（更正时必填）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// 幂等标识，重复提交相同标识不会重复创建
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_id: Option<String>,
+    /// 员工 ID
+    pub user_id: String,
+    /// 薪酬项 ID
+    pub item_id: String,
+    /// 金额
+    pub amount: Decimal,
+    /// 币种 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_id: Option<String>,
+    /// 发放期间（如 `2024-01`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuance_period: Option<String>,
+    /// 备注
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl LumpSumPaymentRecord {
+    /// 创建记录
+    pub fn new(user_id: impl Into<String>, item_id: impl Into<String>, amount: Decimal) -> Self {
+        Self {
+            id: None,
+            unique_id: None,
+            user_id: user_id.into(),
+            item_id: item_id.into(),
+            amount,
+            currency_id: None,
+            issuance_period: None,
+            description: None,
+        }
+    }
+
+    /// 设置记录 ID（更正时必填）
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// 设置幂等标识
+    pub fn unique_id(mut self, unique_id: impl Into<String>) -> Self {
+        self.unique_id = Some(unique_id.into());
+        self
+    }
+
+    /// 设置币种 ID
+    pub fn currency_id(mut self, currency_id: impl Into<String>) -> Self {
+        self.currency_id = Some(currency_id.into());
+        self
+    }
+
+    /// 设置发放期间
+    pub fn issuance_period(mut self, issuance_period: impl Into<String>) -> Self {
+        self.issuance_period = Some(issuance_period.into());
+        self
+    }
+
+    /// 设置备注
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
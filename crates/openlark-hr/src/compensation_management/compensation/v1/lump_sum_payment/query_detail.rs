@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::Decimal;
+
 /// 查询一次性支付授予明细请求
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -101,6 +103,18 @@ pub struct LumpSumPaymentDetail {
     /// 员工 ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
+    /// 薪酬项 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    /// 发放金额
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal>,
+    /// 币种 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_id: Option<String>,
+    /// 发放日期
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuance_date: Option<String>,
 }
 
 impl ApiResponseTrait for QueryDetailResponse {
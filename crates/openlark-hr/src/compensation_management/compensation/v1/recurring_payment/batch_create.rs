@@ -7,13 +7,17 @@ use openlark_core::{
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
+    validate_required_list,
 };
 use serde::{Deserialize, Serialize};
 
+use super::models::RecurringPaymentRecord;
+
 /// 批量创建经常性支付记录请求
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct BatchCreateRequest {
+    /// 记录列表（必填，不超过 100 条）
+    records: Vec<RecurringPaymentRecord>,
     /// 配置信息
     config: Config,
 }
@@ -21,7 +25,22 @@ pub struct BatchCreateRequest {
 impl BatchCreateRequest {
     /// 创建请求
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            records: Vec::new(),
+            config,
+        }
+    }
+
+    /// 设置记录列表（必填，不超过 100 条）
+    pub fn records(mut self, records: Vec<RecurringPaymentRecord>) -> Self {
+        self.records = records;
+        self
+    }
+
+    /// 追加一条记录
+    pub fn record(mut self, record: RecurringPaymentRecord) -> Self {
+        self.records.push(record);
+        self
     }
 
     /// 执行请求
@@ -37,10 +56,28 @@ impl BatchCreateRequest {
     ) -> SDKResult<BatchCreateResponse> {
         use crate::common::api_endpoints::CompensationApiV1;
 
+        // 1. 验证必填字段
+        validate_required_list!(self.records, 100, "记录列表不能为空且不能超过 100 条");
+
+        // 2. 构建端点
         let api_endpoint = CompensationApiV1::RecurringPaymentBatchCreate;
         let request = ApiRequest::<BatchCreateResponse>::post(api_endpoint.to_url());
+
+        // 3. 序列化请求体
+        let request_body = BatchCreateRequestBody {
+            records: self.records,
+        };
+        let request = request.body(serde_json::to_value(&request_body).map_err(|e| {
+            openlark_core::error::validation_error(
+                "请求体序列化失败",
+                format!("无法序列化请求参数: {e}"),
+            )
+        })?);
+
+        // 4. 发送请求
         let response = Transport::request(request, &self.config, Some(option)).await?;
 
+        // 5. 提取响应数据
         response.data.ok_or_else(|| {
             openlark_core::error::validation_error(
                 "批量创建经常性支付记录响应数据为空",
@@ -50,6 +87,13 @@ impl BatchCreateRequest {
     }
 }
 
+/// 批量创建经常性支付记录请求体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCreateRequestBody {
+    /// 记录列表
+    pub records: Vec<RecurringPaymentRecord>,
+}
+
 /// 批量创建经常性支付记录响应
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BatchCreateResponse {
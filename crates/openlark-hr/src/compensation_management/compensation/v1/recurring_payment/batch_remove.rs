@@ -7,13 +7,15 @@ use openlark_core::{
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
+    validate_required_list,
 };
 use serde::{Deserialize, Serialize};
 
 /// 批量删除经常性支付记录请求
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct BatchRemoveRequest {
+    /// 记录 ID 列表（必填，不超过 100 个）
+    record_ids: Vec<String>,
     /// 配置信息
     config: Config,
 }
@@ -21,7 +23,16 @@ pub struct BatchRemoveRequest {
 impl BatchRemoveRequest {
     /// 创建请求
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            record_ids: Vec::new(),
+            config,
+        }
+    }
+
+    /// 设置记录 ID 列表（必填，不超过 100 个）
+    pub fn record_ids(mut self, record_ids: Vec<String>) -> Self {
+        self.record_ids = record_ids;
+        self
     }
 
     /// 执行请求
@@ -37,10 +48,32 @@ impl BatchRemoveRequest {
     ) -> SDKResult<BatchRemoveResponse> {
         use crate::common::api_endpoints::CompensationApiV1;
 
+        // 1. 验证必填字段
+        validate_required_list!(
+            self.record_ids,
+            100,
+            "记录 ID 列表不能为空且不能超过 100 个"
+        );
+
+        // 2. 构建端点
         let api_endpoint = CompensationApiV1::RecurringPaymentBatchRemove;
         let request = ApiRequest::<BatchRemoveResponse>::post(api_endpoint.to_url());
+
+        // 3. 序列化请求体
+        let request_body = BatchRemoveRequestBody {
+            record_ids: self.record_ids,
+        };
+        let request = request.body(serde_json::to_value(&request_body).map_err(|e| {
+            openlark_core::error::validation_error(
+                "请求体序列化失败",
+                format!("无法序列化请求参数: {e}"),
+            )
+        })?);
+
+        // 4. 发送请求
         let response = Transport::request(request, &self.config, Some(option)).await?;
 
+        // 5. 提取响应数据
         response.data.ok_or_else(|| {
             openlark_core::error::validation_error(
                 "批量删除经常性支付记录响应数据为空",
@@ -50,6 +83,13 @@ impl BatchRemoveRequest {
     }
 }
 
+/// 批量删除经常性支付记录请求体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchRemoveRequestBody {
+    /// 记录 ID 列表
+    pub record_ids: Vec<String>,
+}
+
 /// 批量删除经常性支付记录响应
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BatchRemoveResponse {
@@ -1,4 +1,5 @@
 pub mod batch_create;
 pub mod batch_remove;
 pub mod batch_update;
+pub mod models;
 pub mod query;
@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::Decimal;
+
 /// 查询经常性支付记录请求
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -98,9 +100,21 @@ pub struct RecurringPayment {
     /// 员工 ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
-    /// 金额
+    /// 薪酬项 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    /// 每期金额
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f64>,
+    pub amount: Option<Decimal>,
+    /// 币种 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_id: Option<String>,
+    /// 开始日期
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// 结束日期
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
 }
 
 impl ApiResponseTrait for QueryResponse {
@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::Decimal;
+
 /// 批量获取员工参保档案请求
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -101,6 +103,25 @@ pub struct SocialArchive {
     /// 参保城市
     #[serde(skip_serializing_if = "Option::is_none")]
     pub city: Option<String>,
+    /// 险种缴纳明细
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<SocialArchiveDetail>>,
+}
+
+/// 险种缴纳明细
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SocialArchiveDetail {
+    /// 险种 ID
+    pub insurance_id: String,
+    /// 缴纳基数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<Decimal>,
+    /// 公司缴纳金额
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_amount: Option<Decimal>,
+    /// 个人缴纳金额
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub personal_amount: Option<Decimal>,
 }
 
 impl ApiResponseTrait for QueryResponse {
@@ -6,6 +6,8 @@ use openlark_core::config::Config;
 /// compensation 子模块。
 pub mod compensation;
 
+pub use crate::common::money::{CurrencyCode, Decimal, Money, MoneyTotals};
+
 /// 薪酬管理服务
 /// CompensationManagement 服务入口。
 #[derive(Debug, Clone)]
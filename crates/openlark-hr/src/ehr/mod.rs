@@ -8,6 +8,9 @@ use openlark_core::config::Config;
 #[allow(clippy::module_inception)]
 pub mod ehr;
 
+#[cfg(feature = "identity")]
+pub use crate::common::identity::EhrEmployeeNoLookup;
+
 /// EHR 服务
 /// Ehr 服务入口。
 #[derive(Debug, Clone)]
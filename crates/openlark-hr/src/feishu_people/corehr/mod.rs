@@ -8,6 +8,14 @@ pub mod v1;
 /// v2 子模块。
 pub mod v2;
 
+pub use crate::common::corehr_timeline::{
+    ChangeKind, CorehrRefResolver, CorehrTimelineLoader, CostCenterRate, Department, Effective,
+    EffectivePeriod, Employee, EmployeeSnapshot, Employment, EntityKind, FieldChange, I18nText,
+    JobData, OrgChange, OrgSnapshot, OrgTimeline, Position, RefKind, Timeline, diff_snapshots,
+};
+#[cfg(feature = "org-chart")]
+pub use crate::common::corehr_org_chart::{COREHR_ROOT_DEPARTMENT_ID, CorehrOrgSource};
+
 /// corehr 项目 v1 版本服务
 /// CorehrV1 服务入口。
 #[derive(Debug, Clone)]
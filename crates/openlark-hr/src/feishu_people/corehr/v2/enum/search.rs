@@ -3,10 +3,10 @@
 //! docPath: https://open.feishu.cn/document/server-docs/corehr-v2/enum/search
 
 use openlark_core::{
+    SDKResult,
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
-    SDKResult,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -26,6 +26,7 @@ impl SearchRequest {
         Self { config, body: None }
     }
 
+    /// 设置请求体
     pub fn body(mut self, body: Value) -> Self {
         self.body = Some(body);
         self
@@ -37,6 +38,7 @@ impl SearchRequest {
             .await
     }
 
+    /// 使用指定请求选项执行请求。
     pub async fn execute_with_options(
         self,
         option: openlark_core::req_option::RequestOption,
@@ -49,10 +51,7 @@ impl SearchRequest {
 
         let response = Transport::request(request, &self.config, Some(option)).await?;
         response.data.ok_or_else(|| {
-            openlark_core::error::validation_error(
-                "接口响应数据为空",
-                "服务器没有返回有效的数据",
-            )
+            openlark_core::error::validation_error("接口响应数据为空", "服务器没有返回有效的数据")
         })
     }
 }
@@ -60,7 +59,6 @@ impl SearchRequest {
 /// 查询枚举信息响应
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SearchResponse {
-    /// 响应数据
     /// 数据列表
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<EnumItem>>,
@@ -114,4 +112,13 @@ mod tests {
         let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
         assert_eq!(value["field"], "data");
     }
+
+    #[test]
+    fn test_search_response_keeps_extra_fields() {
+        let json = r#"{"items":[{"enum_value":"hired","enum_name":"在职","enum_apiname":"employment_status"}],"has_more":false}"#;
+        let response: SearchResponse = serde_json::from_str(json).expect("JSON 反序列化失败");
+        let item = &response.items.expect("缺少 items")[0];
+        assert_eq!(item.enum_value.as_deref(), Some("hired"));
+        assert_eq!(item.extra["enum_apiname"], "employment_status");
+    }
 }
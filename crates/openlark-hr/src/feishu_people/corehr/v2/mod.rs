@@ -26,6 +26,8 @@ pub mod draft;
 pub mod employee;
 /// employees 子模块。
 pub mod employees;
+/// enum 子模块。
+pub mod r#enum;
 /// job 子模块。
 pub mod job;
 /// job_change 子模块。
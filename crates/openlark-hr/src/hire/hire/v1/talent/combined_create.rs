@@ -31,6 +31,8 @@ pub struct CombinedCreateRequest {
     education_list: Option<Vec<TalentEducation>>,
     /// 工作经历
     work_experience_list: Option<Vec<TalentWorkExperience>>,
+    /// 简历附件 ID
+    resume_attachment_id: Option<String>,
 }
 
 impl CombinedCreateRequest {
@@ -44,6 +46,7 @@ impl CombinedCreateRequest {
             resume: None,
             education_list: None,
             work_experience_list: None,
+            resume_attachment_id: None,
         }
     }
 
@@ -83,6 +86,12 @@ impl CombinedCreateRequest {
         self
     }
 
+    /// 设置简历附件 ID（可通过 `attachment::create` 获得）
+    pub fn resume_attachment_id(mut self, resume_attachment_id: String) -> Self {
+        self.resume_attachment_id = Some(resume_attachment_id);
+        self
+    }
+
     /// 执行请求
     pub async fn execute(self) -> SDKResult<CombinedCreateResponse> {
         self.execute_with_options(openlark_core::req_option::RequestOption::default())
@@ -121,6 +130,7 @@ impl CombinedCreateRequest {
             resume: self.resume,
             education_list: self.education_list,
             work_experience_list: self.work_experience_list,
+            resume_attachment_id: self.resume_attachment_id,
         };
         let request = request.body(serde_json::to_value(&request_body).map_err(|e| {
             openlark_core::error::validation_error(
@@ -187,6 +197,7 @@ mod tests {
             resume: request.resume,
             education_list: request.education_list,
             work_experience_list: request.work_experience_list,
+            resume_attachment_id: request.resume_attachment_id,
         };
 
         let value = serde_json::to_value(body).expect("序列化请求体失败");
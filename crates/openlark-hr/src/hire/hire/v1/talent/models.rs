@@ -166,6 +166,9 @@ pub struct CombinedCreateRequestBody {
     /// 工作经历
     #[serde(skip_serializing_if = "Option::is_none")]
     pub work_experience_list: Option<Vec<TalentWorkExperience>>,
+    /// 简历附件 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume_attachment_id: Option<String>,
 }
 
 /// 综合创建候选人响应
@@ -8,6 +8,13 @@ use openlark_core::config::Config;
 #[allow(clippy::module_inception)]
 pub mod hire;
 
+pub use crate::common::hire_pipeline::{
+    ApplicationRef, CandidateImport, ExternalApplicationImport, FeedbackDimension, FeedbackModule,
+    HiringFuture, HiringKey, HiringPipeline, HiringProcess, HiringStage, HiringStore,
+    ImportedCandidate, InMemoryHiringStore, InterviewFeedback, InterviewSchedule, OfferDraft,
+    OfferRef, OfferStatus, OfferTracking, ResumeAttachment, StageTransition, StageType,
+};
+
 /// 招聘服务
 /// Hire 服务入口。
 #[derive(Debug, Clone)]
@@ -8,6 +8,16 @@ use openlark_core::config::Config;
 #[allow(clippy::module_inception)]
 pub mod okr;
 
+pub use crate::common::okr_report::{
+    KeyResult, Objective, ObjectiveAlignment, ObjectiveSummary, OkrItem, OkrOwner, OkrPeriodReport,
+    OkrPeriodReporter, OkrProgressRate, OkrReportFailure, OkrUserSummary, ProgressRecordRef,
+    ProgressStatus, batch_get_okrs, list_user_okrs,
+};
+pub use crate::common::okr_rich_text::{
+    OkrBlock, OkrDocsLink, OkrElement, OkrGallery, OkrImage, OkrLink, OkrListStyle, OkrParagraph,
+    OkrParagraphStyle, OkrPerson, OkrRichText, OkrTextRun, OkrTextStyle,
+};
+
 /// OKR 服务
 /// Okr 服务入口。
 #[derive(Debug, Clone)]
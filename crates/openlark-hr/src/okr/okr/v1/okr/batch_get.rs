@@ -46,16 +46,8 @@ impl BatchGetRequest {
         let api_endpoint = OkrApiV1::OkrBatchGet;
         let request = ApiRequest::<BatchGetResponse>::get(api_endpoint.to_url());
 
-        // 3. 序列化请求体
-        let request_body = BatchGetRequestBody {
-            okr_ids: self.okr_ids.join(","),
-        };
-        let request = request.body(serde_json::to_value(&request_body).map_err(|e| {
-            openlark_core::error::validation_error(
-                "请求体序列化失败",
-                format!("无法序列化请求参数: {e}"),
-            )
-        })?);
+        // 3. GET 请求通过重复的查询参数传递 OKR ID
+        let request = request.query_repeated("okr_ids", self.okr_ids);
 
         // 4. 发送请求
         let response = Transport::request(request, &self.config, Some(option)).await?;
@@ -71,6 +63,8 @@ impl BatchGetRequest {
 }
 
 /// 批量获取 OKR请求体
+///
+/// 接口为 GET 请求，实际通过 `okr_ids` 查询参数传递；保留该结构以兼容现有调用方。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchGetRequestBody {
     /// OKR ID 列表（必填，不超过 50 个）
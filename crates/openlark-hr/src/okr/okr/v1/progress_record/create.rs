@@ -11,6 +11,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::okr_rich_text::OkrRichText;
+
 /// 创建 OKR 进展记录请求
 #[derive(Debug, Clone)]
 pub struct CreateRequest {
@@ -18,6 +20,8 @@ pub struct CreateRequest {
     okr_id: String,
     /// 进展内容（必填）
     content: String,
+    /// 富文本进展内容（可选，设置后优先于纯文本内容）
+    rich_content: Option<OkrRichText>,
     /// 进展百分比（必填，0-100）
     progress_rate: i32,
     /// 进展说明（可选）
@@ -34,6 +38,7 @@ impl CreateRequest {
         Self {
             okr_id,
             content,
+            rich_content: None,
             progress_rate,
             description: None,
             attachments: None,
@@ -47,6 +52,12 @@ impl CreateRequest {
         self
     }
 
+    /// 设置富文本进展内容（可选），可通过 [`OkrRichText::from_markdown`] 构造
+    pub fn rich_content(mut self, rich_content: OkrRichText) -> Self {
+        self.rich_content = Some(rich_content);
+        self
+    }
+
     /// 设置附件列表（可选）
     pub fn attachments(mut self, attachments: Vec<ProgressAttachment>) -> Self {
         self.attachments = Some(attachments);
@@ -68,7 +79,7 @@ impl CreateRequest {
 
         // 1. 验证必填字段
         validate_required!(self.okr_id.trim(), "OKR ID 不能为空");
-        validate_required!(self.content.trim(), "进展内容不能为空");
+        let content = ProgressContent::resolve(self.content, self.rich_content)?;
 
         // 2. 构建端点
         let api_endpoint = OkrApiV1::ProgressRecordCreate;
@@ -77,7 +88,7 @@ impl CreateRequest {
         // 3. 序列化请求体
         let request_body = CreateRequestBody {
             okr_id: self.okr_id,
-            content: self.content,
+            content,
             progress_rate: self.progress_rate,
             description: self.description,
             attachments: self.attachments,
@@ -108,7 +119,7 @@ pub struct CreateRequestBody {
     /// OKR ID（必填）
     pub okr_id: String,
     /// 进展内容（必填）
-    pub content: String,
+    pub content: ProgressContent,
     /// 进展百分比（必填，0-100）
     pub progress_rate: i32,
     /// 进展说明（可选）
@@ -119,6 +130,41 @@ pub struct CreateRequestBody {
     pub attachments: Option<Vec<ProgressAttachment>>,
 }
 
+/// 进展内容：富文本或纯文本
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ProgressContent {
+    /// 富文本内容
+    Rich(OkrRichText),
+    /// 纯文本内容
+    Text(String),
+}
+
+impl ProgressContent {
+    /// 优先使用非空的富文本内容，否则要求纯文本内容非空。
+    pub(crate) fn resolve(text: String, rich: Option<OkrRichText>) -> SDKResult<Self> {
+        match rich {
+            Some(rich) if !rich.is_empty() => Ok(Self::Rich(rich)),
+            _ => {
+                validate_required!(text.trim(), "进展内容不能为空");
+                Ok(Self::Text(text))
+            }
+        }
+    }
+}
+
+impl From<String> for ProgressContent {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<OkrRichText> for ProgressContent {
+    fn from(rich: OkrRichText) -> Self {
+        Self::Rich(rich)
+    }
+}
+
 /// 进展记录附件
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ProgressAttachment {
@@ -138,7 +184,7 @@ pub struct CreateResponse {
     /// OKR ID
     pub okr_id: String,
     /// 进展内容
-    pub content: String,
+    pub content: ProgressContent,
     /// 进展百分比
     pub progress_rate: i32,
     /// 创建时间
@@ -154,6 +200,32 @@ impl ApiResponseTrait for CreateResponse {
 #[cfg(test)]
 #[allow(unused_imports)]
 mod tests {
+    use super::*;
+    use crate::common::okr_rich_text::OkrParagraph;
+
+    #[test]
+    fn test_progress_content_prefers_rich_text() {
+        let rich = OkrRichText::new().paragraph(OkrParagraph::new().text("完成灰度"));
+        let content = ProgressContent::resolve("忽略".to_string(), Some(rich)).unwrap();
+        let body = CreateRequestBody {
+            okr_id: "okr_1".to_string(),
+            content,
+            progress_rate: 50,
+            description: None,
+            attachments: None,
+        };
+        let value = serde_json::to_value(&body).unwrap();
+        assert_eq!(
+            value["content"]["blocks"][0]["paragraph"]["elements"][0]["textRun"]["text"],
+            "完成灰度"
+        );
+
+        assert!(ProgressContent::resolve(" ".to_string(), Some(OkrRichText::new())).is_err());
+        assert_eq!(
+            ProgressContent::resolve("纯文本".to_string(), None).unwrap(),
+            ProgressContent::Text("纯文本".to_string())
+        );
+    }
 
     #[test]
     fn test_serialization_roundtrip() {
@@ -70,7 +70,7 @@ pub struct GetResponse {
     /// OKR ID
     pub okr_id: String,
     /// 进展内容
-    pub content: String,
+    pub content: super::create::ProgressContent,
     /// 进展百分比
     pub progress_rate: i32,
     /// 进展说明
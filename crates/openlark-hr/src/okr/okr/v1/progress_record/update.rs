@@ -11,6 +11,9 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use super::create::ProgressContent;
+use crate::common::okr_rich_text::OkrRichText;
+
 /// 更新 OKR 进展记录请求
 #[derive(Debug, Clone)]
 pub struct UpdateRequest {
@@ -18,6 +21,8 @@ pub struct UpdateRequest {
     progress_id: String,
     /// 进展内容（必填）
     content: String,
+    /// 富文本进展内容（可选，设置后优先于纯文本内容）
+    rich_content: Option<OkrRichText>,
     /// 进展百分比（必填，0-100）
     progress_rate: i32,
     /// 进展说明（可选）
@@ -32,6 +37,7 @@ impl UpdateRequest {
         Self {
             progress_id,
             content,
+            rich_content: None,
             progress_rate,
             description: None,
             config,
@@ -44,6 +50,12 @@ impl UpdateRequest {
         self
     }
 
+    /// 设置富文本进展内容（可选），可通过 [`OkrRichText::from_markdown`] 构造
+    pub fn rich_content(mut self, rich_content: OkrRichText) -> Self {
+        self.rich_content = Some(rich_content);
+        self
+    }
+
     /// 执行请求
     pub async fn execute(self) -> SDKResult<UpdateResponse> {
         self.execute_with_options(openlark_core::req_option::RequestOption::default())
@@ -59,7 +71,7 @@ impl UpdateRequest {
 
         // 1. 验证必填字段
         validate_required!(self.progress_id.trim(), "进展记录 ID 不能为空");
-        validate_required!(self.content.trim(), "进展内容不能为空");
+        let content = ProgressContent::resolve(self.content, self.rich_content)?;
 
         // 2. 构建端点
         let api_endpoint = OkrApiV1::ProgressRecordUpdate(self.progress_id.clone());
@@ -67,7 +79,7 @@ impl UpdateRequest {
 
         // 3. 序列化请求体
         let request_body = UpdateRequestBody {
-            content: self.content,
+            content,
             progress_rate: self.progress_rate,
             description: self.description,
         };
@@ -95,7 +107,7 @@ impl UpdateRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateRequestBody {
     /// 进展内容（必填）
-    pub content: String,
+    pub content: ProgressContent,
     /// 进展百分比（必填，0-100）
     pub progress_rate: i32,
     /// 进展说明（可选）
@@ -111,7 +123,7 @@ pub struct UpdateResponse {
     /// OKR ID
     pub okr_id: String,
     /// 进展内容
-    pub content: String,
+    pub content: ProgressContent,
     /// 进展百分比
     pub progress_rate: i32,
     /// 更新时间
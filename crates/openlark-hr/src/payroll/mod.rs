@@ -8,6 +8,14 @@ use openlark_core::config::Config;
 #[allow(clippy::module_inception)]
 pub mod payroll;
 
+pub use crate::common::money::{CurrencyCode, Decimal, Money, MoneyTotals};
+#[cfg(feature = "ehr")]
+pub use crate::common::payroll_ledger::EhrEmployeeDirectory;
+pub use crate::common::payroll_ledger::{
+    EmployeeDirectory, LedgerEmployee, LedgerEntryKind, LedgerFuture, LedgerMismatch, LedgerPage,
+    LedgerRow, LedgerSummary, PayrollLedger, StaticEmployeeDirectory,
+};
+
 /// 薪资服务
 /// Payroll 服务入口。
 #[derive(Debug, Clone)]
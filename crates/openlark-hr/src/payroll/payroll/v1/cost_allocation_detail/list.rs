@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::{CurrencyCode, Decimal};
+
 /// 查询成本分摊报表明细请求
 #[derive(Debug, Clone)]
 pub struct ListRequest {
@@ -134,10 +136,10 @@ pub struct CostAllocationDetail {
     /// 分摊比例
     pub allocation_ratio: f64,
     /// 分摊金额
-    pub amount: f64,
+    pub amount: Decimal,
     /// 币种
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
 }
 
 impl ApiResponseTrait for ListResponse {
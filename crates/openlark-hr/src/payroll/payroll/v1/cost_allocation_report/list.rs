@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::{CurrencyCode, Decimal};
+
 /// 查询成本分摊报表汇总数据请求
 #[derive(Debug, Clone)]
 pub struct ListRequest {
@@ -135,10 +137,10 @@ pub struct CostAllocationReport {
     /// 分摊总人数
     pub employee_count: i32,
     /// 分摊总金额
-    pub total_amount: f64,
+    pub total_amount: Decimal,
     /// 币种
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
     /// 统计时间（Unix 时间戳）
     pub stats_time: i64,
 }
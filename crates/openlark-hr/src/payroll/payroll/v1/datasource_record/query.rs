@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::Decimal;
+
 /// 批量查询外部算薪数据记录请求
 #[derive(Debug, Clone)]
 pub struct QueryRequest {
@@ -161,6 +163,17 @@ pub struct DatasourceRecordItem {
     pub value: serde_json::Value,
 }
 
+impl DatasourceRecordItem {
+    /// 按金额解析字段值，兼容数字与字符串；无法解析时返回 `None`
+    pub fn decimal(&self) -> Option<Decimal> {
+        match &self.value {
+            serde_json::Value::String(raw) => raw.trim().parse().ok(),
+            serde_json::Value::Number(number) => number.to_string().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
 impl ApiResponseTrait for QueryResponse {
     fn data_format() -> ResponseFormat {
         ResponseFormat::Data
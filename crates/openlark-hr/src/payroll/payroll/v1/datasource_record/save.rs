@@ -11,6 +11,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::Decimal;
+
 /// 创建 / 更新外部算薪数据请求
 #[derive(Debug, Clone)]
 pub struct SaveRequest {
@@ -118,6 +120,16 @@ pub struct DatasourceRecordItem {
     pub value: serde_json::Value,
 }
 
+impl DatasourceRecordItem {
+    /// 金额字段，按字符串写入以保留精度
+    pub fn amount(field_name: impl Into<String>, amount: Decimal) -> Self {
+        Self {
+            field_name: field_name.into(),
+            value: serde_json::Value::String(amount.to_string()),
+        }
+    }
+}
+
 /// 创建 / 更新外部算薪数据响应
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SaveResponse {
@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::{CurrencyCode, Decimal};
+
 /// 查询发薪活动列表请求
 #[derive(Debug, Clone)]
 pub struct ListRequest {
@@ -134,10 +136,10 @@ pub struct PaymentActivity {
     pub status: i32,
     /// 发薪总额
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_amount: Option<f64>,
+    pub total_amount: Option<Decimal>,
     /// 币种
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
     /// 创建时间（Unix 时间戳）
     pub created_at: i64,
     /// 更新时间（Unix 时间戳）
@@ -10,6 +10,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::{CurrencyCode, Decimal, Money};
+
 /// 查询发薪活动明细列表请求
 #[derive(Debug, Clone)]
 pub struct ListRequest {
@@ -121,16 +123,30 @@ pub struct PaymentActivityDetail {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub department_id: Option<String>,
     /// 应发工资
-    pub gross_pay: f64,
+    pub gross_pay: Decimal,
     /// 实发工资
-    pub net_pay: f64,
+    pub net_pay: Decimal,
     /// 扣款总额
-    pub total_deduction: f64,
+    pub total_deduction: Decimal,
     /// 税额
-    pub tax_amount: f64,
+    pub tax_amount: Decimal,
     /// 币种
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    pub currency: Option<CurrencyCode>,
+}
+
+impl PaymentActivityDetail {
+    /// 带币种的实发工资；未返回币种时为 `None`
+    pub fn net_pay_money(&self) -> Option<Money> {
+        self.currency
+            .map(|currency| Money::new(self.net_pay, currency))
+    }
+
+    /// 带币种的应发工资；未返回币种时为 `None`
+    pub fn gross_pay_money(&self) -> Option<Money> {
+        self.currency
+            .map(|currency| Money::new(self.gross_pay, currency))
+    }
 }
 
 impl ApiResponseTrait for ListResponse {
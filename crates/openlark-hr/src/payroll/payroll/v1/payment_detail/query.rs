@@ -11,6 +11,8 @@ use openlark_core::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::money::{CurrencyCode, Decimal, Money};
+
 /// 批量查询发薪明细请求
 #[derive(Debug, Clone)]
 pub struct QueryRequest {
@@ -146,11 +148,33 @@ pub struct PaymentDetail {
     /// 扣款明细列表
     pub deduction_items: Vec<DeductionItem>,
     /// 实发工资
-    pub net_pay: f64,
+    pub net_pay: Decimal,
     /// 税前工资
-    pub gross_pay: f64,
+    pub gross_pay: Decimal,
     /// 币种
-    pub currency: String,
+    pub currency: CurrencyCode,
+}
+
+impl PaymentDetail {
+    /// 带币种的实发工资
+    pub fn net_pay_money(&self) -> Money {
+        Money::new(self.net_pay, self.currency)
+    }
+
+    /// 带币种的税前工资
+    pub fn gross_pay_money(&self) -> Money {
+        Money::new(self.gross_pay, self.currency)
+    }
+
+    /// 工资明细合计
+    pub fn salary_total(&self) -> Decimal {
+        self.salary_items.iter().map(|item| item.amount).sum()
+    }
+
+    /// 扣款明细合计
+    pub fn deduction_total(&self) -> Decimal {
+        self.deduction_items.iter().map(|item| item.amount).sum()
+    }
 }
 
 impl ApiResponseTrait for QueryResponse {
@@ -167,7 +191,7 @@ pub struct SalaryItem {
     /// 算薪项名称
     pub name: String,
     /// 金额
-    pub amount: f64,
+    pub amount: Decimal,
 }
 
 /// 扣款明细项
@@ -178,7 +202,7 @@ pub struct DeductionItem {
     /// 算薪项名称
     pub name: String,
     /// 扣款金额
-    pub amount: f64,
+    pub amount: Decimal,
 }
 
 #[cfg(test)]
@@ -2,8 +2,9 @@
 
 use openlark_core::{config::Config, req_option::RequestOption};
 use openlark_hr::attendance::attendance::v1::{
-    approval_info, file, group, shift, user_approval, user_setting, user_stats_data,
-    user_stats_field, user_task, user_task_remedy,
+    approval_info, file, group, shift,
+    types::{AttendanceDate, AttendanceTimestamp, CheckInMethod, CheckInResult, CheckInType},
+    user_approval, user_setting, user_stats_data, user_stats_field, user_task, user_task_remedy,
 };
 use rstest::rstest;
 use serde_json::json;
@@ -21,6 +22,10 @@ fn test_config(base_url: &str) -> Config {
         .build()
 }
 
+fn date(value: u32) -> AttendanceDate {
+    AttendanceDate::from_int(value).expect("非法日期")
+}
+
 fn auth_option() -> RequestOption {
     RequestOption::builder()
         .user_access_token("test_token")
@@ -139,10 +144,10 @@ mod builder_tests {
     smoke_builder!(
         test_get_user_task_request_builder,
         user_task::QueryUserTaskRequest::new(test_config("https://open.feishu.cn"))
-            .start_date("2026-01-01".to_string())
-            .end_date("2026-01-31".to_string())
+            .start_date(date(20260101))
+            .end_date(date(20260131))
             .user_ids(vec!["ou_1".to_string()])
-            .check_in_type(1)
+            .check_in_type(CheckInType::OnDuty)
     );
     smoke_builder!(
         test_remedy_user_task_request_builder,
@@ -173,8 +178,8 @@ mod builder_tests {
     smoke_builder!(
         test_get_user_stats_data_request_builder,
         user_stats_data::QueryRequest::new(test_config("https://open.feishu.cn"))
-            .start_date("2026-01-01".to_string())
-            .end_date("2026-01-31".to_string())
+            .start_date(date(20260101))
+            .end_date(date(20260131))
             .user_ids(vec!["ou_1".to_string()])
             .stats_type("day".to_string())
             .page_size(100)
@@ -283,8 +288,8 @@ mod validation_tests {
     #[tokio::test]
     async fn test_user_stats_data_validation_requires_user_or_group() {
         let result = user_stats_data::QueryRequest::new(test_config("https://127.0.0.1:9"))
-            .start_date("2026-01-01".to_string())
-            .end_date("2026-01-31".to_string())
+            .start_date(date(20260101))
+            .end_date(date(20260131))
             .execute_with_options(auth_option())
             .await;
 
@@ -299,8 +304,8 @@ mod validation_tests {
     #[tokio::test]
     async fn test_user_stats_data_page_size_boundary(#[case] page_size: i32) {
         let result = user_stats_data::QueryRequest::new(test_config("https://127.0.0.1:9"))
-            .start_date("2026-01-01".to_string())
-            .end_date("2026-01-31".to_string())
+            .start_date(date(20260101))
+            .end_date(date(20260131))
             .user_ids(vec!["ou_1".to_string()])
             .page_size(page_size)
             .execute_with_options(auth_option())
@@ -556,19 +561,20 @@ mod http_tests {
         Mock::given(method("POST"))
             .and(path("/open-apis/attendance/v1/user_tasks/query"))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "code":0,"msg":"success","data":{"records":[{"user_id":"ou_1","date":"2026-01-01","check_in_type":1,"check_in_time":"2026-01-01 09:00:00","check_in_result":1,"check_in_method":1}],"has_more":false}
+                "code":0,"msg":"success","data":{"records":[{"user_id":"ou_1","date":20260101,"check_in_type":1,"check_in_time":"1767229200","check_in_result":"Late","check_in_method":1}],"has_more":false}
             })))
             .mount(&mock_server)
             .await;
 
         let resp = user_task::QueryUserTaskRequest::new(test_config(&mock_server.uri()))
-            .start_date("2026-01-01".to_string())
-            .end_date("2026-01-01".to_string())
+            .start_date(date(20260101))
+            .end_date(date(20260101))
             .user_ids(vec!["ou_1".to_string()])
             .execute_with_options(auth_option())
             .await
             .unwrap();
         assert_eq!(resp.records.len(), 1);
+        assert_eq!(resp.records[0].check_in_result, CheckInResult::Late);
     }
 }
 
@@ -732,13 +738,13 @@ mod serialization_tests {
         user_task::models::QueryUserTaskResponse {
             records: vec![user_task::models::UserTaskRecord {
                 user_id: "ou_1".to_string(),
-                date: "2026-01-01".to_string(),
-                check_in_type: 1,
-                check_in_time: "2026-01-01 09:00:00".to_string(),
+                date: date(20260101),
+                check_in_type: CheckInType::OnDuty,
+                check_in_time: AttendanceTimestamp::from_secs(1767229200).unwrap(),
                 check_in_place_name: None,
                 check_in_place_id: None,
-                check_in_result: 1,
-                check_in_method: 1,
+                check_in_result: CheckInResult::Normal,
+                check_in_method: CheckInMethod::Mobile,
                 device_id: None,
                 device_name: None,
                 wifi_name: None,
@@ -801,11 +807,16 @@ mod serialization_tests {
             items: vec![user_stats_data::UserStatsDataItem {
                 user_id: "ou_1".to_string(),
                 user_name: Some("张三".to_string()),
-                date: "2026-01-01".to_string(),
+                date: date(20260101),
                 group_id: Some("g_1".to_string()),
                 group_name: None,
                 basic_info: None,
-                attendance_stats: Some(json!({"work_hours":8})),
+                attendance_stats: Some(vec![user_stats_data::StatItem {
+                    code: "51201".to_string(),
+                    title: Some("出勤天数".to_string()),
+                    value: "1".to_string(),
+                    duration_num: None,
+                }]),
                 abnormal_stats: None,
                 leave_stats: None,
                 overtime_stats: None,
@@ -905,3 +916,243 @@ mod serialization_tests {
         }
     );
 }
+
+#[cfg(test)]
+mod report_tests {
+    use super::*;
+    use openlark_hr::attendance::{AttendanceReporter, ReportStage, ReportUsers};
+
+    #[tokio::test]
+    async fn test_attendance_report_joins_tasks_and_shifts() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/attendance/v1/groups/g_1/list_user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"items":[
+                    {"user_id":"ou_1","user_name":"张三","join_time":1704067200},
+                    {"user_id":"ou_2","user_name":"李四","join_time":1704067200}
+                ],"has_more":false}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/attendance/v1/user_tasks/query"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"records":[
+                    {"user_id":"ou_1","date":20260101,"check_in_type":1,"check_in_time":"1767229500","check_in_result":"Late","check_in_method":1},
+                    {"user_id":"ou_1","date":20260101,"check_in_type":2,"check_in_time":"1767261600","check_in_result":"Normal","check_in_method":1}
+                ],"has_more":false}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/attendance/v1/user_daily_shifts/query"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"items":[
+                    {"user_id":"ou_1","date":20260101,"shift_id":"s_1"},
+                    {"user_id":"ou_2","date":20260101,"shift_id":"s_2"}
+                ],"has_more":false}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/attendance/v1/shifts/s_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"shift":{
+                    "shift_id":"s_1","shift_name":"标准班次","shift_type":0,
+                    "punch_times":[{"on_duty_time":540,"off_duty_time":1080}]
+                }}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/open-apis/attendance/v1/shifts/s_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":190001,"msg":"shift not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let report = AttendanceReporter::new(test_config(&mock_server.uri()))
+            .users(ReportUsers::Group("g_1".to_string()))
+            .date_range(date(20260101), date(20260102))
+            .rate_per_second(100)
+            .request_option(auth_option())
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(report.rows.len(), 4);
+        let first = &report.rows[0];
+        assert_eq!(first.user_name.as_deref(), Some("张三"));
+        assert_eq!(first.shift_name.as_deref(), Some("标准班次"));
+        assert_eq!(first.check_in_result, Some(CheckInResult::Late));
+        assert_eq!(first.punch_count, 2);
+        assert_eq!(report.rows[2].user_id, "ou_2");
+        assert_eq!(report.rows[2].shift_id.as_deref(), Some("s_2"));
+        assert!(!report.is_complete());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].stage, ReportStage::Shift);
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert!(csv.contains(
+            "ou_1,张三,2026-01-01,s_1,标准班次,09:00,18:00,2026-01-01 09:05:00,Late,2026-01-01 18:00:00,Normal,2"
+        ));
+
+        let mut out = Vec::new();
+        report.write_json(&mut out).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["rows"][0]["date"], json!(20260101));
+        assert_eq!(value["failures"][0]["shift_id"], json!("s_2"));
+    }
+}
+
+#[cfg(test)]
+mod import_tests {
+    use super::*;
+    use openlark_hr::attendance::{
+        ContactIdResolver, EmployeeRef, ImportStatus, PunchImporter, PunchRecord,
+    };
+
+    fn ts(secs: i64) -> AttendanceTimestamp {
+        AttendanceTimestamp::from_secs(secs).expect("非法时间戳")
+    }
+
+    fn punch_records() -> Vec<PunchRecord> {
+        let email = EmployeeRef::Email("zhangsan@example.com".to_string());
+        let lisi = EmployeeRef::UserId("ou_2".to_string());
+        vec![
+            PunchRecord::new(email.clone(), ts(1767229500), CheckInType::OnDuty)
+                .location("1F 闸机")
+                .source_id("ext_1"),
+            // 已存在于打卡流水中
+            PunchRecord::new(lisi.clone(), ts(1767229500), CheckInType::OnDuty).location("1F 闸机"),
+            // 与第一条重复（地点大小写、空白不同）
+            PunchRecord::new(email, ts(1767229500), CheckInType::OnDuty).location(" 1f 闸机 "),
+            PunchRecord::new(
+                EmployeeRef::Email("nobody@example.com".to_string()),
+                ts(1767229500),
+                CheckInType::OnDuty,
+            ),
+            PunchRecord::new(lisi, ts(1767261600), CheckInType::OffDuty).location("1F 闸机"),
+        ]
+    }
+
+    async fn mount_lookups(mock_server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/open-apis/contact/v3/users/batch_get_id"))
+            .and(query_param("user_id_type", "user_id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"user_list":[
+                    {"user_id":"ou_1","email":"zhangsan@example.com"},
+                    {"email":"nobody@example.com"}
+                ]}
+            })))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/attendance/v1/user_flows/query"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"flow_list":[
+                    {"user_flow_id":"f_1","user_id":"ou_2","punch_date":"2026-01-01",
+                     "punch_time":"2026-01-01 09:05:00","punch_type":1,"punch_method":2,
+                     "punch_place_name":"1F 闸机"}
+                ],"has_more":false}
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_import_dedups_and_retries_partial_failures() {
+        let mock_server = MockServer::start().await;
+        mount_lookups(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/attendance/v1/user_flows/batch_create"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"results":[
+                    {"user_id":"ou_1","punch_time":"2026-01-01 09:05:00","success":true},
+                    {"user_id":"ou_2","punch_time":"2026-01-01 18:00:00","success":false,
+                     "error_code":1220001,"error_msg":"system busy"}
+                ]}
+            })))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/attendance/v1/user_flows/batch_create"))
+            .and(body_json(json!({"flow_records":[{
+                "user_id":"ou_2","punch_time":"2026-01-01 18:00:00","punch_type":2,
+                "punch_method":2,"punch_place_name":"1F 闸机"
+            }]})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code":0,"msg":"success","data":{"results":[
+                    {"user_id":"ou_2","punch_time":"2026-01-01 18:00:00","success":true}
+                ]}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = test_config(&mock_server.uri());
+        let report = PunchImporter::new(config.clone())
+            .resolver(ContactIdResolver::new(config).request_option(auth_option()))
+            .rate_per_second(100)
+            .request_option(auth_option())
+            .import(punch_records())
+            .await
+            .unwrap();
+
+        let statuses: Vec<ImportStatus> = report.outcomes.iter().map(|o| o.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ImportStatus::Created,
+                ImportStatus::Duplicate,
+                ImportStatus::Duplicate,
+                ImportStatus::Unresolved,
+                ImportStatus::Created,
+            ]
+        );
+        assert_eq!(report.outcomes[0].user_id.as_deref(), Some("ou_1"));
+        assert_eq!(report.outcomes[4].attempts, 2);
+        assert!(report.outcomes[4].error.is_none());
+        assert!(!report.is_success());
+
+        let mut out = Vec::new();
+        report.write_json(&mut out).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["outcomes"][0]["source_id"], json!("ext_1"));
+        assert_eq!(value["outcomes"][3]["status"], json!("unresolved"));
+    }
+
+    #[tokio::test]
+    async fn test_import_dry_run_does_not_write() {
+        let mock_server = MockServer::start().await;
+        mount_lookups(&mock_server).await;
+        Mock::given(method("POST"))
+            .and(path("/open-apis/attendance/v1/user_flows/batch_create"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let config = test_config(&mock_server.uri());
+        let report = PunchImporter::new(config.clone())
+            .resolver(ContactIdResolver::new(config).request_option(auth_option()))
+            .dry_run(true)
+            .rate_per_second(100)
+            .request_option(auth_option())
+            .import(punch_records())
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(report.count(ImportStatus::Planned), 2);
+        assert_eq!(report.count(ImportStatus::Duplicate), 2);
+        assert_eq!(report.count(ImportStatus::Unresolved), 1);
+    }
+}
@@ -1,16 +1,30 @@
 //! compensation tests 集成测试。
 
 use openlark_core::{config::Config, req_option::RequestOption};
+use openlark_hr::compensation_management::Decimal;
 use openlark_hr::compensation_management::compensation::v1::{
     archive, change_reason, indicator, item, item_category, lump_sum_payment, plan,
     recurring_payment, social_archive, social_archive_adjust_record, social_insurance, social_plan,
 };
+use openlark_hr::compensation_management::compensation::v1::{
+    archive::models::ArchiveItemValue, lump_sum_payment::models::LumpSumPaymentRecord,
+    recurring_payment::models::RecurringPaymentRecord,
+};
 use serde_json::json;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
     matchers::{body_json, header, method, path, query_param},
 };
 
+fn archive_create_request(config: Config) -> archive::create::CreateRequest {
+    archive::create::CreateRequest::new(config)
+        .user_id("ou_1")
+        .plan_id("plan_1")
+        .currency_id("6863326262618752011")
+        .effective_date("2024-01-01")
+        .archive_item(ArchiveItemValue::new("item_1", Decimal::new(2000000, 2)))
+}
+
 fn test_config(base_url: &str) -> Config {
     Config::builder()
         .app_id("test_app_id")
@@ -169,6 +183,35 @@ mod builder_tests {
 mod validation_tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_compensation_writes_require_records() {
+        let config = test_config("http://127.0.0.1:9");
+        let err = lump_sum_payment::batch_create::BatchCreateRequest::new(config.clone())
+            .execute_with_options(auth_option())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("记录列表不能为空"));
+
+        let err = recurring_payment::batch_update::BatchUpdateRequest::new(config.clone())
+            .record(RecurringPaymentRecord::new(
+                "ou_1",
+                "item_2",
+                Decimal::new(3000, 0),
+            ))
+            .execute_with_options(auth_option())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("记录 ID"));
+
+        assert!(
+            archive::create::CreateRequest::new(config)
+                .user_id("ou_1")
+                .execute_with_options(auth_option())
+                .await
+                .is_err()
+        );
+    }
+
     #[tokio::test]
     async fn test_archive_create_data_missing_validation() {
         let mock_server = MockServer::start().await;
@@ -178,7 +221,7 @@ mod validation_tests {
             .mount(&mock_server)
             .await;
 
-        let err = archive::create::CreateRequest::new(test_config(&mock_server.uri()))
+        let err = archive_create_request(test_config(&mock_server.uri()))
             .execute_with_options(auth_option())
             .await
             .err()
@@ -257,6 +300,13 @@ mod http_tests {
         Mock::given(method("POST"))
             .and(path("/open-apis/compensation/v1/archives"))
             .and(header("Authorization", "Bearer test_token"))
+            .and(body_json(json!({
+                "user_id": "ou_1",
+                "plan_id": "plan_1",
+                "currency_id": "6863326262618752011",
+                "effective_date": "2024-01-01",
+                "archive_items": [{"item_id": "item_1", "amount": "20000.00"}]
+            })))
             .respond_with(
                 ResponseTemplate::new(200)
                     .set_body_json(json!({"code":0,"msg":"ok","data":{"archive_id":"arc_1"}})),
@@ -264,7 +314,7 @@ mod http_tests {
             .mount(&mock_server)
             .await;
 
-        let resp = archive::create::CreateRequest::new(test_config(&mock_server.uri()))
+        let resp = archive_create_request(test_config(&mock_server.uri()))
             .execute_with_options(auth_option())
             .await
             .unwrap();
@@ -390,6 +440,12 @@ mod http_tests {
             .and(path(
                 "/open-apis/compensation/v1/lump_sum_payment/batch_create",
             ))
+            .and(body_json(json!({"records": [{
+                "user_id": "ou_1",
+                "item_id": "item_1",
+                "amount": "1000.50",
+                "issuance_period": "2024-03"
+            }]})))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "code":0,
                 "msg":"ok",
@@ -401,6 +457,10 @@ mod http_tests {
         let resp = lump_sum_payment::batch_create::BatchCreateRequest::new(test_config(
             &mock_server.uri(),
         ))
+        .record(
+            LumpSumPaymentRecord::new("ou_1", "item_1", Decimal::new(100050, 2))
+                .issuance_period("2024-03"),
+        )
         .execute_with_options(auth_option())
         .await
         .unwrap();
@@ -414,6 +474,7 @@ mod http_tests {
             .and(path(
                 "/open-apis/compensation/v1/lump_sum_payment/batch_remove",
             ))
+            .and(body_json(json!({"record_ids": ["lump_1"]})))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "code":0,
                 "msg":"ok",
@@ -425,6 +486,7 @@ mod http_tests {
         let resp = lump_sum_payment::batch_remove::BatchRemoveRequest::new(test_config(
             &mock_server.uri(),
         ))
+        .record_ids(vec!["lump_1".to_string()])
         .execute_with_options(auth_option())
         .await
         .unwrap();
@@ -449,6 +511,7 @@ mod http_tests {
         let resp = lump_sum_payment::batch_update::BatchUpdateRequest::new(test_config(
             &mock_server.uri(),
         ))
+        .record(LumpSumPaymentRecord::new("ou_1", "item_1", Decimal::new(1200, 0)).id("lump_1"))
         .execute_with_options(auth_option())
         .await
         .unwrap();
@@ -476,7 +539,7 @@ mod http_tests {
             .execute_with_options(auth_option())
             .await
             .unwrap();
-        assert_eq!(resp.items[0].amount, Some(1000.0));
+        assert_eq!(resp.items[0].amount, Some(Decimal::new(1000, 0)));
     }
 
     #[tokio::test]
@@ -547,6 +610,10 @@ mod http_tests {
         let resp = recurring_payment::batch_create::BatchCreateRequest::new(test_config(
             &mock_server.uri(),
         ))
+        .record(
+            RecurringPaymentRecord::new("ou_1", "item_2", Decimal::new(3000, 0))
+                .period("2024-01-01", None),
+        )
         .execute_with_options(auth_option())
         .await
         .unwrap();
@@ -560,6 +627,7 @@ mod http_tests {
             .and(path(
                 "/open-apis/compensation/v1/recurring_payment/batch_remove",
             ))
+            .and(body_json(json!({"record_ids": ["rec_1"]})))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "code":0,
                 "msg":"ok",
@@ -571,6 +639,7 @@ mod http_tests {
         let resp = recurring_payment::batch_remove::BatchRemoveRequest::new(test_config(
             &mock_server.uri(),
         ))
+        .record_ids(vec!["rec_1".to_string()])
         .execute_with_options(auth_option())
         .await
         .unwrap();
@@ -595,6 +664,7 @@ mod http_tests {
         let resp = recurring_payment::batch_update::BatchUpdateRequest::new(test_config(
             &mock_server.uri(),
         ))
+        .record(RecurringPaymentRecord::new("ou_1", "item_2", Decimal::new(3500, 0)).id("rec_1"))
         .execute_with_options(auth_option())
         .await
         .unwrap();
@@ -778,6 +848,12 @@ mod serialization_tests {
                 id: "arc_1".to_string(),
                 user_id: Some("ou_1".to_string()),
                 plan_id: Some("plan_1".to_string()),
+                currency_id: Some("6863326262618752011".to_string()),
+                effective_date: Some("2024-01-01".to_string()),
+                archive_items: Some(vec![archive::models::ArchiveItemValue::new(
+                    "item_1",
+                    Decimal::new(2000000, 2),
+                )]),
             }],
             has_more: false,
             page_token: Some("next".to_string()),
@@ -870,7 +946,10 @@ mod serialization_tests {
             items: vec![lump_sum_payment::query::LumpSumPayment {
                 id: "lump_1".to_string(),
                 user_id: Some("ou_1".to_string()),
-                amount: Some(1000.0),
+                item_id: Some("item_1".to_string()),
+                amount: Some(Decimal::new(1000, 0)),
+                currency_id: Some("6863326262618752011".to_string()),
+                issuance_period: Some("2024-03".to_string()),
             }],
             has_more: false,
             page_token: Some("next".to_string()),
@@ -884,6 +963,10 @@ mod serialization_tests {
                 id: "detail_1".to_string(),
                 payment_id: Some("lump_1".to_string()),
                 user_id: Some("ou_1".to_string()),
+                item_id: Some("item_1".to_string()),
+                amount: Some(Decimal::new(100050, 2)),
+                currency_id: Some("6863326262618752011".to_string()),
+                issuance_date: Some("2024-03-15".to_string()),
             }],
             has_more: false,
             page_token: Some("next".to_string()),
@@ -940,7 +1023,11 @@ mod serialization_tests {
             items: vec![recurring_payment::query::RecurringPayment {
                 id: "rec_1".to_string(),
                 user_id: Some("ou_1".to_string()),
-                amount: Some(3000.0),
+                item_id: Some("item_2".to_string()),
+                amount: Some(Decimal::new(3000, 0)),
+                currency_id: Some("6863326262618752011".to_string()),
+                start_date: Some("2024-01-01".to_string()),
+                end_date: None,
             }],
             has_more: false,
             page_token: Some("next".to_string()),
@@ -954,6 +1041,12 @@ mod serialization_tests {
                 id: "sa_1".to_string(),
                 user_id: Some("ou_1".to_string()),
                 city: Some("上海".to_string()),
+                details: Some(vec![social_archive::query::SocialArchiveDetail {
+                    insurance_id: "pension".to_string(),
+                    base: Some(Decimal::new(3635900, 2)),
+                    company_amount: Some(Decimal::new(581744, 2)),
+                    personal_amount: Some(Decimal::new(290872, 2)),
+                }]),
             }],
             has_more: false,
             page_token: Some("next".to_string()),
@@ -1158,3 +1158,150 @@ mod serialization_tests {
         }
     );
 }
+
+#[cfg(test)]
+mod timeline_tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use openlark_hr::feishu_people::corehr::{
+        ChangeKind, CorehrRefResolver, CorehrTimelineLoader, EntityKind, RefKind,
+    };
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    async fn mount(mock_server: &MockServer, url: &str, data: serde_json::Value) {
+        Mock::given(method("POST"))
+            .and(path(url))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0, "msg": "success", "data": data
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_timeline_loader_reconstructs_org_and_resolves_refs() {
+        let mock_server = MockServer::start().await;
+        mount(
+            &mock_server,
+            "/open-apis/corehr/v2/employees/batch_get",
+            json!({"items":[{
+                "employment_id":"e_1","employee_number":"A001","effective_date":"2023-01-01",
+                "person_info":{"person_id":"p_1","preferred_name":"张三"},
+                "employment_status":{"enum_name":"hired"}
+            }]}),
+        )
+        .await;
+        mount(
+            &mock_server,
+            "/open-apis/corehr/v2/employees/job_datas/batch_get",
+            json!({"items":[{"employment_id":"e_1","job_datas":[
+                {"job_data_id":"jd_1","version_id":"v1","department_id":"d_1","job_level_id":"lv_1",
+                 "work_location_id":"loc_1","primary_job_data":true,
+                 "effective_time":"2023-01-01 00:00:00","expiration_time":"2024-03-31 00:00:00"},
+                {"job_data_id":"jd_1","version_id":"v2","department_id":"d_2","job_level_id":"lv_2",
+                 "work_location_id":"loc_1","primary_job_data":true,
+                 "effective_time":"2024-04-01 00:00:00","expiration_time":"9999-12-31 00:00:00"}
+            ]}]}),
+        )
+        .await;
+        mount(
+            &mock_server,
+            "/open-apis/corehr/v2/departments/query_multi_timeline",
+            json!({"items":[
+                {"id":"d_1","names":[{"lang":"zh-CN","value":"研发部"}],"effective_date":"2020-01-01"},
+                {"id":"d_2","names":[{"lang":"zh-CN","value":"平台部"}],"parent_department_id":"d_1",
+                 "effective_date":"2024-04-01"}
+            ]}),
+        )
+        .await;
+        mount(
+            &mock_server,
+            "/open-apis/corehr/v2/job_levels/batch_get",
+            json!({"items":[{"job_level_id":"lv_2","names":[{"lang":"zh-CN","value":"P6"}]}]}),
+        )
+        .await;
+        mount(
+            &mock_server,
+            "/open-apis/corehr/v2/locations/batch_get",
+            json!({"items":[{"location_id":"loc_1","names":[{"lang":"zh-CN","value":"北京"}]}]}),
+        )
+        .await;
+        mount(
+            &mock_server,
+            "/open-apis/corehr/v2/cost_centers/search",
+            json!({"items":[]}),
+        )
+        .await;
+        mount(
+            &mock_server,
+            "/open-apis/corehr/v2/enums/search",
+            json!({"items":[{"enum_value":"hired","enum_name":"在职"}]}),
+        )
+        .await;
+
+        let config = test_config(&mock_server.uri());
+        let timeline = CorehrTimelineLoader::new(config.clone())
+            .employment_ids(["e_1".to_string()])
+            .request_option(auth_option())
+            .load()
+            .await
+            .unwrap();
+
+        let before = timeline.as_of(d(2024, 1, 1));
+        assert_eq!(before.employees.len(), 1);
+        assert_eq!(before.departments.len(), 1);
+        assert_eq!(before.members_of("d_1").count(), 1);
+
+        let after = timeline.as_of(d(2024, 6, 1));
+        assert_eq!(after.members_of("d_2").count(), 1);
+        assert_eq!(
+            after.departments["d_2"].parent_department_id.as_deref(),
+            Some("d_1")
+        );
+
+        let changes = timeline.diff(d(2024, 1, 1), d(2024, 6, 1));
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].entity, EntityKind::Employee);
+        assert_eq!(changes[0].kind, ChangeKind::Modified);
+        assert_eq!(changes[0].fields.len(), 2);
+        assert_eq!(changes[1].entity, EntityKind::Department);
+        assert_eq!(changes[1].kind, ChangeKind::Added);
+
+        let resolver = CorehrRefResolver::new(config).request_option(auth_option());
+        resolver.prefetch_snapshot(&after).await.unwrap();
+        assert_eq!(
+            resolver.cached(RefKind::JobLevel, "lv_2").as_deref(),
+            Some("P6")
+        );
+        assert_eq!(
+            resolver.cached(RefKind::Location, "loc_1").as_deref(),
+            Some("北京")
+        );
+        assert_eq!(
+            resolver
+                .enum_name("employment_status", "hired")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("在职")
+        );
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let level_lookups = requests
+            .iter()
+            .filter(|r| r.url.path() == "/open-apis/corehr/v2/job_levels/batch_get")
+            .count();
+        resolver.name(RefKind::JobLevel, "lv_2").await.unwrap();
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(
+            requests
+                .iter()
+                .filter(|r| r.url.path() == "/open-apis/corehr/v2/job_levels/batch_get")
+                .count(),
+            level_lookups
+        );
+    }
+}
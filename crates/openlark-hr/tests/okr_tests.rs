@@ -472,7 +472,7 @@ mod serialization_tests {
         v1::progress_record::create::CreateResponse {
             progress_id: "p_1".to_string(),
             okr_id: "okr_1".to_string(),
-            content: "进展更新".to_string(),
+            content: "进展更新".to_string().into(),
             progress_rate: 60,
             created_at: 1735689600,
         }
@@ -483,7 +483,7 @@ mod serialization_tests {
         v1::progress_record::get::GetResponse {
             progress_id: "p_1".to_string(),
             okr_id: "okr_1".to_string(),
-            content: "进展更新".to_string(),
+            content: "进展更新".to_string().into(),
             progress_rate: 60,
             description: Some("说明".to_string()),
             attachments: Some(vec![v1::progress_record::create::ProgressAttachment {
@@ -507,7 +507,7 @@ mod serialization_tests {
         v1::progress_record::update::UpdateResponse {
             progress_id: "p_1".to_string(),
             okr_id: "okr_1".to_string(),
-            content: "新进展".to_string(),
+            content: "新进展".to_string().into(),
             progress_rate: 70,
             updated_at: 1735776000,
         }
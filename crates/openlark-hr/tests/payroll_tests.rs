@@ -2,6 +2,7 @@
 
 use openlark_core::{config::Config, req_option::RequestOption};
 use openlark_hr::payroll::payroll::v1::*;
+use openlark_hr::payroll::{CurrencyCode, Decimal};
 use rstest::rstest;
 use serde_json::json;
 use wiremock::{
@@ -408,7 +409,7 @@ mod http_tests {
         .expect("请求应成功");
 
         assert_eq!(resp.items[0].employee_id, "ou_1");
-        assert_eq!(resp.items[0].net_pay, 18500.0);
+        assert_eq!(resp.items[0].net_pay, Decimal::new(18500, 0));
     }
 
     #[tokio::test]
@@ -721,8 +722,8 @@ mod serialization_tests {
                 period_start_time: 1735689600,
                 period_end_time: 1738368000,
                 status: 1,
-                total_amount: Some(500000.0),
-                currency: Some("CNY".to_string()),
+                total_amount: Some(Decimal::new(500000, 0)),
+                currency: Some(CurrencyCode::CNY),
                 created_at: 1735689600,
                 updated_at: 1735689600,
             }],
@@ -749,11 +750,11 @@ mod serialization_tests {
                 employee_id: "ou_1".to_string(),
                 employee_name: Some("张三".to_string()),
                 department_id: Some("od_1".to_string()),
-                gross_pay: 20000.0,
-                net_pay: 18500.0,
-                total_deduction: 1500.0,
-                tax_amount: 1200.0,
-                currency: Some("CNY".to_string()),
+                gross_pay: Decimal::new(20000, 0),
+                net_pay: Decimal::new(18500, 0),
+                total_deduction: Decimal::new(1500, 0),
+                tax_amount: Decimal::new(1200, 0),
+                currency: Some(CurrencyCode::CNY),
             }],
             has_more: false,
             page_token: None,
@@ -770,16 +771,16 @@ mod serialization_tests {
                 salary_items: vec![payment_detail::query::SalaryItem {
                     acct_item_id: "acct_1".to_string(),
                     name: "基本工资".to_string(),
-                    amount: 20000.0,
+                    amount: Decimal::new(20000, 0),
                 }],
                 deduction_items: vec![payment_detail::query::DeductionItem {
                     acct_item_id: "acct_2".to_string(),
                     name: "个税".to_string(),
-                    amount: 1500.0,
+                    amount: Decimal::new(1500, 0),
                 }],
-                net_pay: 18500.0,
-                gross_pay: 20000.0,
-                currency: "CNY".to_string(),
+                net_pay: Decimal::new(18500, 0),
+                gross_pay: Decimal::new(20000, 0),
+                currency: CurrencyCode::CNY,
             }],
             has_more: false,
             page_token: Some("next_payment_detail".to_string()),
@@ -860,8 +861,8 @@ mod serialization_tests {
                 employee_id: "ou_1".to_string(),
                 cost_center_id: Some("cc_1".to_string()),
                 allocation_ratio: 0.5,
-                amount: 10000.0,
-                currency: Some("CNY".to_string()),
+                amount: Decimal::new(10000, 0),
+                currency: Some(CurrencyCode::CNY),
             }],
             has_more: false,
             page_token: None,
@@ -878,8 +879,8 @@ mod serialization_tests {
                 cost_center_id: Some("cc_1".to_string()),
                 cost_center_name: Some("研发中心".to_string()),
                 employee_count: 120,
-                total_amount: 500000.0,
-                currency: Some("CNY".to_string()),
+                total_amount: Decimal::new(500000, 0),
+                currency: Some(CurrencyCode::CNY),
                 stats_time: 1735689600,
             }],
             has_more: false,
@@ -4,6 +4,9 @@
 pub mod api_endpoints;
 /// 邮件 API 通用辅助函数。
 pub mod api_utils;
+/// 用户邮箱增量同步 helper。
+#[cfg(all(feature = "v1", feature = "async"))]
+pub mod mailbox_sync;
 
 // 重新导出API端点枚举
 pub use api_endpoints::MailApiV1;
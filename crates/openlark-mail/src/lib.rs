@@ -9,6 +9,7 @@
 //! - **邮件组管理**: 创建、更新、删除、查询邮件组
 //! - **成员管理**: 添加、删除邮件组成员
 //! - **别名管理**: 邮件别名配置
+//! - **邮箱同步**: 基于邮箱事件与分页列表的增量同步（`MailboxSync`）
 //!
 //! ## 使用示例
 //!
@@ -55,6 +56,12 @@ pub mod prelude;
 /// 邮件服务统一入口。
 pub use service::MailService;
 
+#[cfg(all(feature = "v1", feature = "async"))]
+pub use common::mailbox_sync::{
+    InMemoryMailSyncStore, MailChange, MailChangeKind, MailSyncStore, MailboxSync,
+    MailboxSyncState,
+};
+
 /// 邮件模块版本信息
 /// 当前 crate 版本号。
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -1,4 +1,5 @@
 //! 列出邮箱文件夹
+//! docPath: https://open.feishu.cn/document/mail-v1/user_mailbox-folder/list
 
 use openlark_core::{
     SDKResult,
@@ -15,13 +16,38 @@ use std::sync::Arc;
 pub struct ListMailboxFolderRequest {
     config: Arc<Config>,
     user_mailbox_id: String,
+    /// 文件夹类型（1：系统文件夹，2：用户文件夹）
+    folder_type: Option<i32>,
 }
 
 /// List Mailbox Folder Response。
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ListMailboxFolderResponse {
-    /// 响应数据。
-    pub data: Option<serde_json::Value>,
+    /// 文件夹列表。
+    #[serde(default)]
+    pub items: Vec<MailboxFolder>,
+}
+
+/// 邮箱文件夹。
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MailboxFolder {
+    /// 文件夹 ID。
+    pub id: String,
+    /// 文件夹名称。
+    #[serde(default)]
+    pub name: String,
+    /// 父文件夹 ID。
+    #[serde(default)]
+    pub parent_folder_id: Option<String>,
+    /// 文件夹类型（1：系统文件夹，2：用户文件夹）。
+    #[serde(default)]
+    pub folder_type: Option<i32>,
+    /// 未读邮件数量。
+    #[serde(default)]
+    pub unread_message_count: Option<i32>,
+    /// 未读会话数量。
+    #[serde(default)]
+    pub unread_thread_count: Option<i32>,
 }
 
 impl ApiResponseTrait for ListMailboxFolderResponse {
@@ -36,9 +62,16 @@ impl ListMailboxFolderRequest {
         Self {
             config,
             user_mailbox_id: user_mailbox_id.into(),
+            folder_type: None,
         }
     }
 
+    /// 设置文件夹类型（1：系统文件夹，2：用户文件夹）
+    pub fn folder_type(mut self, folder_type: i32) -> Self {
+        self.folder_type = Some(folder_type);
+        self
+    }
+
     /// 执行请求。
     pub async fn execute(self) -> SDKResult<ListMailboxFolderResponse> {
         self.execute_with_options(RequestOption::default()).await
@@ -53,7 +86,8 @@ impl ListMailboxFolderRequest {
             "/open-apis/mail/v1/user_mailboxes/{}/folders",
             self.user_mailbox_id
         );
-        let req: ApiRequest<ListMailboxFolderResponse> = ApiRequest::get(&path);
+        let req: ApiRequest<ListMailboxFolderResponse> = ApiRequest::get(&path)
+            .query_opt("folder_type", self.folder_type.map(|t| t.to_string()));
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
         resp.data
@@ -83,4 +117,23 @@ mod tests {
         let request = ListMailboxFolderRequest::new(arc_config.clone(), "test".to_string());
         let _ = request;
     }
+
+    #[test]
+    fn test_response_deserialization() {
+        let response: ListMailboxFolderResponse = serde_json::from_value(serde_json::json!({
+            "items": [{
+                "id": "INBOX",
+                "name": "收件箱",
+                "parent_folder_id": "0",
+                "folder_type": 1,
+                "unread_message_count": 3,
+                "unread_thread_count": 2
+            }]
+        }))
+        .expect("反序列化失败");
+
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(response.items[0].id, "INBOX");
+        assert_eq!(response.items[0].folder_type, Some(1));
+    }
 }
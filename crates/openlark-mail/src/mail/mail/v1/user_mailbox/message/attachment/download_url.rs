@@ -2,15 +2,17 @@
 //! docPath: https://open.feishu.cn/document/mail-v1/user_mailbox-message/user_mailbox-message-attachment/download_url
 
 use openlark_core::{
+    SDKResult,
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
     http::Transport,
     req_option::RequestOption,
-    SDKResult,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+/// 获取邮件附件下载链接请求。
 #[derive(Debug, Clone)]
 pub struct GetAttachmentDownloadUrlRequest {
     config: Arc<Config>,
@@ -19,9 +21,15 @@ pub struct GetAttachmentDownloadUrlRequest {
     attachment_id: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 获取邮件附件下载链接响应。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetAttachmentDownloadUrlResponse {
-    pub data: Option<DownloadUrlData>,
+    /// 下载链接列表。
+    #[serde(default)]
+    pub download_urls: Vec<AttachmentDownloadUrl>,
+    /// 获取失败的附件 ID 列表。
+    #[serde(default)]
+    pub failed_ids: Vec<String>,
 }
 
 impl ApiResponseTrait for GetAttachmentDownloadUrlResponse {
@@ -30,13 +38,17 @@ impl ApiResponseTrait for GetAttachmentDownloadUrlResponse {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DownloadUrlData {
+/// 附件下载链接。
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AttachmentDownloadUrl {
+    /// 附件 ID。
+    pub attachment_id: String,
+    /// 下载链接（有效期较短，需尽快使用）。
     pub download_url: String,
-    pub expire_time: String,
 }
 
 impl GetAttachmentDownloadUrlRequest {
+    /// 创建新的实例。
     pub fn new(
         config: Arc<Config>,
         user_mailbox_id: impl Into<String>,
@@ -51,20 +63,26 @@ impl GetAttachmentDownloadUrlRequest {
         }
     }
 
+    /// 执行请求。
     pub async fn execute(self) -> SDKResult<GetAttachmentDownloadUrlResponse> {
         self.execute_with_options(RequestOption::default()).await
     }
 
+    /// 使用指定请求选项执行请求。
     pub async fn execute_with_options(
         self,
         option: RequestOption,
     ) -> SDKResult<GetAttachmentDownloadUrlResponse> {
+        validate_required!(self.user_mailbox_id, "user_mailbox_id 不能为空");
+        validate_required!(self.message_id, "message_id 不能为空");
+        validate_required!(self.attachment_id, "attachment_id 不能为空");
+
         let path = format!(
             "/open-apis/mail/v1/user_mailboxes/{}/messages/{}/attachments/download_url",
             self.user_mailbox_id, self.message_id
         );
         let req: ApiRequest<GetAttachmentDownloadUrlResponse> =
-            ApiRequest::get(&path).query("attachment_id", self.attachment_id);
+            ApiRequest::get(&path).query("attachment_ids", self.attachment_id);
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
         resp.data.ok_or_else(|| {
@@ -79,17 +97,18 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
-    }
+    fn test_response_deserialization() {
+        let response: GetAttachmentDownloadUrlResponse =
+            serde_json::from_value(serde_json::json!({
+                "download_urls": [{
+                    "attachment_id": "att_1",
+                    "download_url": "https://example.com/att_1"
+                }],
+                "failed_ids": ["att_2"]
+            }))
+            .expect("反序列化失败");
 
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+        assert_eq!(response.download_urls[0].attachment_id, "att_1");
+        assert_eq!(response.failed_ids, vec!["att_2"]);
     }
 }
@@ -0,0 +1,2 @@
+/// download_url 模块。
+pub mod download_url;
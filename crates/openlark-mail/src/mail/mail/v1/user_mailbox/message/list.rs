@@ -1,4 +1,5 @@
 //! 列出邮件
+//! docPath: https://open.feishu.cn/document/mail-v1/user_mailbox-message/list
 
 use openlark_core::{
     SDKResult,
@@ -6,6 +7,7 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -15,13 +17,30 @@ use std::sync::Arc;
 pub struct ListMailboxMessageRequest {
     config: Arc<Config>,
     user_mailbox_id: String,
+    /// 文件夹 ID
+    folder_id: Option<String>,
+    /// 每页数量
+    page_size: Option<i32>,
+    /// 分页标记
+    page_token: Option<String>,
+    /// 是否只查询未读邮件
+    only_unread: Option<bool>,
 }
 
 /// List Mailbox Message Response。
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `items` 为邮件 ID 列表，邮件详情需通过获取邮件详情接口查询。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ListMailboxMessageResponse {
-    /// 响应数据。
-    pub data: Option<serde_json::Value>,
+    /// 邮件 ID 列表。
+    #[serde(default)]
+    pub items: Vec<String>,
+    /// 分页标记。
+    #[serde(default)]
+    pub page_token: Option<String>,
+    /// 是否还有更多数据。
+    #[serde(default)]
+    pub has_more: bool,
 }
 
 impl ApiResponseTrait for ListMailboxMessageResponse {
@@ -36,9 +55,37 @@ impl ListMailboxMessageRequest {
         Self {
             config,
             user_mailbox_id: user_mailbox_id.into(),
+            folder_id: None,
+            page_size: None,
+            page_token: None,
+            only_unread: None,
         }
     }
 
+    /// 设置文件夹 ID（必填）
+    pub fn folder_id(mut self, folder_id: impl Into<String>) -> Self {
+        self.folder_id = Some(folder_id.into());
+        self
+    }
+
+    /// 设置每页数量（最大 20）
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// 设置分页标记
+    pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+        self.page_token = Some(page_token.into());
+        self
+    }
+
+    /// 设置是否只查询未读邮件
+    pub fn only_unread(mut self, only_unread: bool) -> Self {
+        self.only_unread = Some(only_unread);
+        self
+    }
+
     /// 执行请求。
     pub async fn execute(self) -> SDKResult<ListMailboxMessageResponse> {
         self.execute_with_options(RequestOption::default()).await
@@ -49,11 +96,21 @@ impl ListMailboxMessageRequest {
         self,
         option: RequestOption,
     ) -> SDKResult<ListMailboxMessageResponse> {
+        validate_required!(self.user_mailbox_id, "user_mailbox_id 不能为空");
+        let folder_id = self.folder_id.unwrap_or_default();
+        validate_required!(folder_id, "folder_id 不能为空");
+
         let path = format!(
             "/open-apis/mail/v1/user_mailboxes/{}/messages",
             self.user_mailbox_id
         );
-        let req: ApiRequest<ListMailboxMessageResponse> = ApiRequest::get(&path);
+        let mut req: ApiRequest<ListMailboxMessageResponse> = ApiRequest::get(&path)
+            .query("folder_id", folder_id)
+            .query("page_size", self.page_size.unwrap_or(20).to_string())
+            .query_opt("page_token", self.page_token);
+        if let Some(only_unread) = self.only_unread {
+            req = req.query("only_unread", only_unread.to_string());
+        }
 
         let resp = Transport::request(req, &self.config, Some(option)).await?;
         resp.data
@@ -76,11 +133,29 @@ mod tests {
                 .app_secret("test_secret")
                 .build(),
         );
-        let _config = openlark_core::config::Config::builder()
-            .app_id("test_app")
-            .app_secret("test_secret")
-            .build();
-        let request = ListMailboxMessageRequest::new(arc_config.clone(), "test".to_string());
-        let _ = request;
+        let request = ListMailboxMessageRequest::new(arc_config, "test".to_string())
+            .folder_id("INBOX")
+            .page_size(10)
+            .page_token("token_1")
+            .only_unread(true);
+
+        assert_eq!(request.folder_id.as_deref(), Some("INBOX"));
+        assert_eq!(request.page_size, Some(10));
+        assert_eq!(request.page_token.as_deref(), Some("token_1"));
+        assert_eq!(request.only_unread, Some(true));
+    }
+
+    #[test]
+    fn test_response_deserialization() {
+        let response: ListMailboxMessageResponse = serde_json::from_value(serde_json::json!({
+            "items": ["msg_1", "msg_2"],
+            "page_token": "next",
+            "has_more": true
+        }))
+        .expect("反序列化失败");
+
+        assert_eq!(response.items, vec!["msg_1", "msg_2"]);
+        assert_eq!(response.page_token.as_deref(), Some("next"));
+        assert!(response.has_more);
     }
 }
@@ -1,3 +1,5 @@
+/// 附件模块。
+pub mod attachment;
 /// 获取接口。
 pub mod get;
 /// get_by_card 模块。
@@ -44,6 +46,20 @@ impl Message {
         )
     }
 
+    /// 创建获取附件下载链接请求。
+    pub fn attachment_download_url(
+        &self,
+        message_id: impl Into<String>,
+        attachment_id: impl Into<String>,
+    ) -> attachment::download_url::GetAttachmentDownloadUrlRequest {
+        attachment::download_url::GetAttachmentDownloadUrlRequest::new(
+            self.config.clone(),
+            self.mailbox_id.clone(),
+            message_id,
+            attachment_id,
+        )
+    }
+
     /// send。
     pub fn send(&self) -> send::SendMailboxMessageRequest {
         send::SendMailboxMessageRequest::new(self.config.clone(), self.mailbox_id.clone())
@@ -13,4 +13,4 @@ pub use record::create as record_create;
 pub use record::delete as record_delete;
 pub use record::patch as record_patch;
 pub use record::query as record_query;
-pub use search::RecordSearchBuilder;
+pub use search::{RecordSearchBuilder, SearchObject};
@@ -9,41 +9,52 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+
+use crate::common::{
+    apaas_oql::OqlStatement,
+    apaas_record::{ApaasRecord, deserialize_records},
+    api_utils::write_response,
 };
-use serde::{Deserialize, Serialize};
 
 /// 执行 OQL Builder
+///
+/// 推荐使用 [`crate::common::apaas_oql::Oql`] 构建语句后通过 [`OqlQueryBuilder::from_statement`]
+/// 执行，字面量会作为具名参数绑定而不是拼接进语句。
 #[derive(Debug, Clone)]
 pub struct OqlQueryBuilder {
     config: Config,
     /// 应用命名空间
     namespace: String,
-    /// OQL 查询语句
-    oql: String,
-    /// 字段列表
-    fields: Vec<String>,
+    /// OQL 语句与具名参数
+    statement: OqlStatement,
 }
 
 impl OqlQueryBuilder {
     /// 创建新的 Builder
     pub fn new(config: Config, namespace: impl Into<String>, oql: impl Into<String>) -> Self {
+        Self::from_statement(config, namespace, OqlStatement::new(oql))
+    }
+
+    /// 使用已构建的语句创建 Builder
+    pub fn from_statement(
+        config: Config,
+        namespace: impl Into<String>,
+        statement: OqlStatement,
+    ) -> Self {
         Self {
             config,
             namespace: namespace.into(),
-            oql: oql.into(),
-            fields: Vec::new(),
+            statement,
         }
     }
 
-    /// 添加字段
-    pub fn field(mut self, field: impl Into<String>) -> Self {
-        self.fields.push(field.into());
-        self
-    }
-
-    /// 添加多个字段
-    pub fn fields(mut self, fields: impl IntoIterator<Item = impl Into<String>>) -> Self {
-        self.fields.extend(fields.into_iter().map(Into::into));
+    /// 绑定具名参数，语句中以 `$name` 引用
+    pub fn named_arg(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.statement = self.statement.bind(name, value);
         self
     }
 
@@ -54,55 +65,41 @@ impl OqlQueryBuilder {
 
     /// 使用选项执行请求
     pub async fn execute_with_options(self, option: RequestOption) -> SDKResult<OqlQueryResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        self.statement.validate()?;
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/oql_query",
             self.namespace
         );
 
-        let request = OqlQueryRequest {
-            oql: self.oql,
-            fields: self.fields,
-        };
-
-        let req: ApiRequest<OqlQueryResponse> =
-            ApiRequest::post(&url).body(serde_json::to_value(&request)?);
+        let req: ApiRequest<OqlQueryResponse> = ApiRequest::post(&url).json_body(&self.statement);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "执行 OQL")
     }
 }
 
-/// 执行 OQL 请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
-struct OqlQueryRequest {
-    /// OQL 查询语句
-    #[serde(rename = "oql")]
-    oql: String,
-    /// 字段列表
-    #[serde(rename = "fields", skip_serializing_if = "Vec::is_empty")]
-    fields: Vec<String>,
+/// 执行 OQL 响应
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct OqlQueryResponse {
+    /// 列名
+    #[serde(default)]
+    pub columns: Vec<String>,
+    /// 结果行
+    #[serde(default, deserialize_with = "deserialize_records")]
+    pub rows: Vec<ApaasRecord>,
 }
 
-/// OQL 查询结果
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct OqlRecord {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 记录数据
-    #[serde(rename = "data")]
-    data: serde_json::Value,
-}
+impl OqlQueryResponse {
+    /// 将结果行映射为业务结构体
+    pub fn decode_rows<T: DeserializeOwned>(&self) -> SDKResult<Vec<T>> {
+        self.rows.iter().map(ApaasRecord::decode).collect()
+    }
 
-/// 执行 OQL 响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct OqlQueryResponse {
-    /// 查询结果列表
-    #[serde(rename = "items")]
-    items: Vec<OqlRecord>,
-    /// 是否有更多
-    #[serde(rename = "has_more")]
-    has_more: bool,
+    /// 结果行的原始 JSON 对象
+    pub fn raw_rows(&self) -> impl Iterator<Item = &Map<String, Value>> {
+        self.rows.iter().map(|row| &row.0)
+    }
 }
 
 impl ApiResponseTrait for OqlQueryResponse {
@@ -113,21 +110,44 @@ impl ApiResponseTrait for OqlQueryResponse {
 
 #[cfg(test)]
 mod tests {
-
-    use serde_json;
+    use super::*;
+    use crate::common::apaas_oql::{Condition, Oql};
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_request_body_uses_named_args() {
+        let statement = Oql::select(["_id"])
+            .from("customer")
+            .filter(Condition::eq("name", "张三"))
+            .build()
+            .unwrap();
+        let body = serde_json::to_value(&statement).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "query": "SELECT _id FROM customer WHERE name = $p1",
+                "named_args": {"p1": "张三"}
+            })
+        );
     }
 
     #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    fn test_response_rows() {
+        let resp: OqlQueryResponse = serde_json::from_value(serde_json::json!({
+            "columns": ["_id", "name"],
+            "rows": [{"_id": 1, "name": "a"}]
+        }))
+        .unwrap();
+        assert_eq!(resp.rows[0].id().as_deref(), Some("1"));
+        let names: Vec<serde_json::Value> = resp.decode_rows().unwrap();
+        assert_eq!(names[0]["name"], "a");
+    }
+
+    #[tokio::test]
+    async fn test_unused_named_arg_rejected() {
+        let result = OqlQueryBuilder::new(Config::default(), "app", "SELECT _id FROM customer")
+            .named_arg("name", "x")
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
@@ -9,9 +9,18 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required, validate_required_list,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::{
+    apaas_record::{ApaasRecord, RecordOperationResult},
+    api_utils::write_response,
+};
+
+/// 单次批量新建的记录数上限
+pub const MAX_BATCH_CREATE_RECORDS: usize = 500;
+
 /// 批量新建记录 Builder
 #[derive(Debug, Clone)]
 pub struct RecordBatchCreateBuilder {
@@ -54,6 +63,18 @@ impl RecordBatchCreateBuilder {
         self
     }
 
+    /// 使用业务结构体添加多条记录
+    pub fn typed_records<'a, T: Serialize + 'a>(
+        self,
+        records: impl IntoIterator<Item = &'a T>,
+    ) -> SDKResult<Self> {
+        let records = records
+            .into_iter()
+            .map(ApaasRecord::encode)
+            .collect::<SDKResult<Vec<_>>>()?;
+        Ok(self.records(records))
+    }
+
     /// 执行请求
     pub async fn execute(self) -> SDKResult<RecordBatchCreateResponse> {
         self.execute_with_options(RequestOption::default()).await
@@ -64,6 +85,14 @@ impl RecordBatchCreateBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordBatchCreateResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        validate_required!(self.object_api_name, "object_api_name 不能为空");
+        validate_required_list!(
+            self.records,
+            MAX_BATCH_CREATE_RECORDS,
+            "records 不能为空且不能超过 500 条"
+        );
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records/batch_create",
             self.namespace, self.object_api_name
@@ -73,43 +102,29 @@ impl RecordBatchCreateBuilder {
             records: self.records,
         };
 
-        let req: ApiRequest<RecordBatchCreateResponse> =
-            ApiRequest::post(&url).body(serde_json::to_value(&request)?);
+        let req: ApiRequest<RecordBatchCreateResponse> = ApiRequest::post(&url).json_body(&request);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "批量新建记录")
     }
 }
 
 /// 批量新建记录请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct RecordBatchCreateRequest {
     /// 记录数据列表
-    #[serde(rename = "records")]
     records: Vec<serde_json::Value>,
 }
 
 /// 批量新建记录响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct RecordBatchCreateResponse {
-    /// 创建的记录列表
-    #[serde(rename = "items")]
-    items: Vec<RecordCreateResult>,
+    /// 每条记录的创建结果，与请求顺序一致
+    #[serde(default)]
+    pub items: Vec<RecordCreateResult>,
 }
 
 /// 记录创建结果
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct RecordCreateResult {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 是否成功
-    #[serde(rename = "success")]
-    success: bool,
-    /// 错误信息
-    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-}
+pub type RecordCreateResult = RecordOperationResult;
 
 impl ApiResponseTrait for RecordBatchCreateResponse {
     fn data_format() -> ResponseFormat {
@@ -119,21 +134,27 @@ impl ApiResponseTrait for RecordBatchCreateResponse {
 
 #[cfg(test)]
 mod tests {
-
-    use serde_json;
+    use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_response_deserialization() {
+        let resp: RecordBatchCreateResponse = serde_json::from_value(serde_json::json!({
+            "items": [
+                {"_id": 1, "success": true},
+                {"success": false, "errors": [{"code": 2300001, "message": "invalid", "fields": ["name"]}]}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(resp.items[0].id.as_deref(), Some("1"));
+        assert!(!resp.items[1].success);
+        assert_eq!(resp.items[1].errors[0].fields, vec!["name".to_string()]);
     }
 
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    #[tokio::test]
+    async fn test_empty_records_rejected() {
+        let result = RecordBatchCreateBuilder::new(Config::default(), "app", "customer")
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
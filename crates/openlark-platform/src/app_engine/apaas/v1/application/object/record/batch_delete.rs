@@ -9,9 +9,18 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required, validate_required_list,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::{
+    apaas_record::{RecordOperationResult, id_to_value},
+    api_utils::write_response,
+};
+
+/// 单次批量删除的记录数上限
+pub const MAX_BATCH_DELETE_RECORDS: usize = 500;
+
 /// 批量删除记录 Builder
 #[derive(Debug, Clone)]
 pub struct RecordBatchDeleteBuilder {
@@ -62,56 +71,55 @@ impl RecordBatchDeleteBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordBatchDeleteResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        validate_required!(self.object_api_name, "object_api_name 不能为空");
+        validate_required_list!(
+            self.record_ids,
+            MAX_BATCH_DELETE_RECORDS,
+            "record_ids 不能为空且不能超过 500 个"
+        );
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records/batch_delete",
             self.namespace, self.object_api_name
         );
 
         let request = RecordBatchDeleteRequest {
-            record_ids: self.record_ids,
+            ids: self.record_ids.iter().map(|id| id_to_value(id)).collect(),
         };
 
         let req: ApiRequest<RecordBatchDeleteResponse> =
-            ApiRequest::delete(&url).body(serde_json::to_value(&request)?);
+            ApiRequest::delete(&url).json_body(&request);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "批量删除记录")
     }
 }
 
 /// 批量删除记录请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct RecordBatchDeleteRequest {
     /// 记录 ID 列表
-    #[serde(rename = "record_ids")]
-    record_ids: Vec<String>,
+    ids: Vec<serde_json::Value>,
 }
 
 /// 批量删除记录响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct RecordBatchDeleteResponse {
-    /// 删除的记录数量
-    #[serde(rename = "deleted_count")]
-    deleted_count: u32,
-    /// 删除结果列表
-    #[serde(rename = "items")]
-    items: Vec<RecordDeleteResult>,
+    /// 每条记录的删除结果
+    #[serde(default)]
+    pub items: Vec<RecordDeleteResult>,
 }
 
-/// 记录删除结果
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct RecordDeleteResult {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 是否成功
-    #[serde(rename = "success")]
-    success: bool,
-    /// 错误信息
-    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+impl RecordBatchDeleteResponse {
+    /// 成功删除的记录数
+    pub fn deleted_count(&self) -> usize {
+        self.items.iter().filter(|item| item.success).count()
+    }
 }
 
+/// 记录删除结果
+pub type RecordDeleteResult = RecordOperationResult;
+
 impl ApiResponseTrait for RecordBatchDeleteResponse {
     fn data_format() -> ResponseFormat {
         ResponseFormat::Data
@@ -120,21 +128,22 @@ impl ApiResponseTrait for RecordBatchDeleteResponse {
 
 #[cfg(test)]
 mod tests {
-
-    use serde_json;
+    use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_deleted_count() {
+        let resp: RecordBatchDeleteResponse = serde_json::from_value(serde_json::json!({
+            "items": [{"_id": 1, "success": true}, {"_id": 2, "success": false}]
+        }))
+        .unwrap();
+        assert_eq!(resp.deleted_count(), 1);
     }
 
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    #[tokio::test]
+    async fn test_empty_ids_rejected() {
+        let result = RecordBatchDeleteBuilder::new(Config::default(), "app", "customer")
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
@@ -9,8 +9,15 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::common::{
+    apaas_oql::{Condition, Criterion, OrderBy},
+    apaas_record::{ApaasRecord, id_to_value},
+    api_utils::write_response,
 };
-use serde::{Deserialize, Serialize};
 
 /// 查询记录列表 Builder
 #[derive(Debug, Clone)]
@@ -20,10 +27,20 @@ pub struct RecordBatchQueryBuilder {
     namespace: String,
     /// 对象 API 名称
     object_api_name: String,
-    /// 记录 ID 列表
+    /// 记录 ID 列表，会转换为 `_id` 筛选条件
     record_ids: Vec<String>,
-    /// 字段列表
+    /// 返回的字段列表
     fields: Vec<String>,
+    /// 筛选条件
+    filter: Option<Condition>,
+    /// 排序
+    order_by: Vec<OrderBy>,
+    /// 每页数量
+    page_size: Option<u32>,
+    /// 分页标记
+    page_token: Option<String>,
+    /// 是否返回总数
+    need_total_count: Option<bool>,
 }
 
 impl RecordBatchQueryBuilder {
@@ -39,6 +56,11 @@ impl RecordBatchQueryBuilder {
             object_api_name: object_api_name.into(),
             record_ids: Vec::new(),
             fields: Vec::new(),
+            filter: None,
+            order_by: Vec::new(),
+            page_size: None,
+            page_token: None,
+            need_total_count: None,
         }
     }
 
@@ -67,6 +89,62 @@ impl RecordBatchQueryBuilder {
         self
     }
 
+    /// 追加筛选条件，多次调用以 `AND` 组合
+    pub fn filter(mut self, condition: Condition) -> Self {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => existing.and(condition),
+            None => condition,
+        });
+        self
+    }
+
+    /// 追加排序
+    pub fn order_by(mut self, order: OrderBy) -> Self {
+        self.order_by.push(order);
+        self
+    }
+
+    /// 设置每页数量
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// 设置分页标记
+    pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+        self.page_token = Some(page_token.into());
+        self
+    }
+
+    /// 是否返回符合条件的记录总数
+    pub fn need_total_count(mut self, need_total_count: bool) -> Self {
+        self.need_total_count = Some(need_total_count);
+        self
+    }
+
+    fn build_body(&self) -> SDKResult<RecordBatchQueryRequest> {
+        let mut filter = self.filter.clone();
+        if !self.record_ids.is_empty() {
+            let ids = Condition::in_list("_id", self.record_ids.iter().map(|id| id_to_value(id)));
+            filter = Some(match filter {
+                Some(existing) => existing.and(ids),
+                None => ids,
+            });
+        }
+
+        Ok(RecordBatchQueryRequest {
+            select: self.fields.clone(),
+            filter: filter
+                .map(|condition| condition.to_criterion(&self.object_api_name))
+                .transpose()?,
+            order_by: self.order_by.clone(),
+            page_size: self.page_size,
+            page_token: self.page_token.clone(),
+            use_page_token: true,
+            need_total_count: self.need_total_count,
+        })
+    }
+
     /// 执行请求
     pub async fn execute(self) -> SDKResult<RecordBatchQueryResponse> {
         self.execute_with_options(RequestOption::default()).await
@@ -77,58 +155,68 @@ impl RecordBatchQueryBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordBatchQueryResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        validate_required!(self.object_api_name, "object_api_name 不能为空");
+        let body = self.build_body()?;
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records/batch_query",
             self.namespace, self.object_api_name
         );
 
-        let request = RecordBatchQueryRequest {
-            record_ids: self.record_ids,
-            fields: self.fields,
-        };
-
-        let req: ApiRequest<RecordBatchQueryResponse> =
-            ApiRequest::post(&url).body(serde_json::to_value(&request)?);
+        let req: ApiRequest<RecordBatchQueryResponse> = ApiRequest::post(&url).json_body(&body);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "查询记录列表")
     }
 }
 
 /// 查询记录列表请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct RecordBatchQueryRequest {
-    /// 记录 ID 列表
-    #[serde(rename = "record_ids")]
-    record_ids: Vec<String>,
-    /// 字段列表
-    #[serde(rename = "fields", skip_serializing_if = "Vec::is_empty")]
-    fields: Vec<String>,
-}
-
-/// 记录信息
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct RecordInfo {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 记录数据
-    #[serde(rename = "data")]
-    data: serde_json::Value,
-    /// 创建时间
-    #[serde(rename = "created_time")]
-    created_time: i64,
-    /// 更新时间
-    #[serde(rename = "updated_time")]
-    updated_time: i64,
+    /// 返回的字段列表
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    select: Vec<String>,
+    /// 筛选条件
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Criterion>,
+    /// 排序
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    order_by: Vec<OrderBy>,
+    /// 每页数量
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_size: Option<u32>,
+    /// 分页标记
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_token: Option<String>,
+    /// 使用分页标记而非偏移量分页
+    use_page_token: bool,
+    /// 是否返回总数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    need_total_count: Option<bool>,
 }
 
 /// 查询记录列表响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct RecordBatchQueryResponse {
     /// 记录列表
-    #[serde(rename = "items")]
-    items: Vec<RecordInfo>,
+    #[serde(default)]
+    pub items: Vec<ApaasRecord>,
+    /// 符合条件的记录总数（需开启 `need_total_count`）
+    #[serde(default)]
+    pub total: Option<i64>,
+    /// 下一页分页标记
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+    /// 是否有更多
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+impl RecordBatchQueryResponse {
+    /// 将记录映射为业务结构体
+    pub fn decode_items<T: DeserializeOwned>(&self) -> SDKResult<Vec<T>> {
+        self.items.iter().map(ApaasRecord::decode).collect()
+    }
 }
 
 impl ApiResponseTrait for RecordBatchQueryResponse {
@@ -139,21 +227,39 @@ impl ApiResponseTrait for RecordBatchQueryResponse {
 
 #[cfg(test)]
 mod tests {
-
-    use serde_json;
+    use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_body_merges_record_ids_into_filter() {
+        let builder = RecordBatchQueryBuilder::new(Config::default(), "app", "customer")
+            .fields(["_id", "name"])
+            .filter(Condition::eq("city", "杭州"))
+            .record_ids(["1", "2"])
+            .order_by(OrderBy::desc("_id"))
+            .page_size(100);
+        let body = serde_json::to_value(builder.build_body().unwrap()).unwrap();
+        assert_eq!(body["select"], serde_json::json!(["_id", "name"]));
+        assert_eq!(body["filter"]["logic_expression"], "1 AND 2");
+        assert_eq!(body["filter"]["conditions"][1]["operator"], "hasAnyOf");
+        assert_eq!(
+            body["order_by"],
+            serde_json::json!([{"field": "_id", "direction": "desc"}])
+        );
+        assert_eq!(body["use_page_token"], true);
+        assert!(body.get("page_token").is_none());
     }
 
     #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    fn test_response_deserialization() {
+        let resp: RecordBatchQueryResponse = serde_json::from_value(serde_json::json!({
+            "items": [{"_id": 1, "name": "a"}],
+            "total": 10,
+            "next_page_token": "t2",
+            "has_more": true
+        }))
+        .unwrap();
+        assert_eq!(resp.items[0].id().as_deref(), Some("1"));
+        assert_eq!(resp.total, Some(10));
+        assert!(resp.has_more);
     }
 }
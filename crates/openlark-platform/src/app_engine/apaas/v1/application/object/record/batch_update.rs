@@ -7,11 +7,21 @@ use openlark_core::{
     SDKResult,
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
+    error::validation_error,
     http::Transport,
     req_option::RequestOption,
+    validate_required, validate_required_list,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::{
+    apaas_record::{ApaasRecord, RecordOperationResult, id_to_value},
+    api_utils::write_response,
+};
+
+/// 单次批量编辑的记录数上限
+pub const MAX_BATCH_UPDATE_RECORDS: usize = 500;
+
 /// 批量编辑记录 Builder
 #[derive(Debug, Clone)]
 pub struct RecordBatchUpdateBuilder {
@@ -65,22 +75,35 @@ impl RecordBatchUpdateBuilder {
         self
     }
 
-    /// 执行请求
-    pub async fn execute(self) -> SDKResult<RecordBatchUpdateResponse> {
-        let url = format!(
-            "/open-apis/apaas/v1/applications/{}/objects/{}/records/batch_update",
-            self.namespace, self.object_api_name
-        );
+    /// 使用业务结构体添加要更新的记录，系统字段（`_` 开头）会被忽略
+    pub fn typed_record<T: Serialize>(
+        self,
+        record_id: impl Into<String>,
+        record: &T,
+    ) -> SDKResult<Self> {
+        let data = ApaasRecord::encode(record)?.without_system_fields();
+        Ok(self.record(record_id, data))
+    }
 
-        let request = RecordBatchUpdateRequest {
-            records: self.records,
-        };
+    fn build_body(&self) -> SDKResult<RecordBatchUpdateRequest> {
+        let records = self
+            .records
+            .iter()
+            .map(|item| {
+                validate_required!(item.id, "record_id 不能为空");
+                let serde_json::Value::Object(mut map) = item.data.clone() else {
+                    return Err(validation_error("records", "更新的数据必须是 JSON 对象"));
+                };
+                map.insert("_id".to_string(), id_to_value(&item.id));
+                Ok(serde_json::Value::Object(map))
+            })
+            .collect::<SDKResult<Vec<_>>>()?;
+        Ok(RecordBatchUpdateRequest { records })
+    }
 
-        let req: ApiRequest<RecordBatchUpdateResponse> =
-            ApiRequest::patch(&url).body(serde_json::to_value(&request)?);
-        let resp = Transport::request(req, &self.config, Some(RequestOption::default())).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+    /// 执行请求
+    pub async fn execute(self) -> SDKResult<RecordBatchUpdateResponse> {
+        self.execute_with_options(RequestOption::default()).await
     }
 
     /// 使用选项执行请求
@@ -88,63 +111,53 @@ impl RecordBatchUpdateBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordBatchUpdateResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        validate_required!(self.object_api_name, "object_api_name 不能为空");
+        validate_required_list!(
+            self.records,
+            MAX_BATCH_UPDATE_RECORDS,
+            "records 不能为空且不能超过 500 条"
+        );
+        let request = self.build_body()?;
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records/batch_update",
             self.namespace, self.object_api_name
         );
 
-        let request = RecordBatchUpdateRequest {
-            records: self.records,
-        };
-
         let req: ApiRequest<RecordBatchUpdateResponse> =
-            ApiRequest::patch(&url).body(serde_json::to_value(&request)?);
+            ApiRequest::patch(&url).json_body(&request);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "批量编辑记录")
     }
 }
 
 /// 记录更新项
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 struct RecordUpdateItem {
     /// 记录 ID
-    #[serde(rename = "id")]
     id: String,
     /// 更新的数据
-    #[serde(rename = "data")]
     data: serde_json::Value,
 }
 
-/// 批量编辑记录请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// 批量编辑记录请求，每条记录以 `_id` 标识
+#[derive(Debug, Clone, Serialize)]
 struct RecordBatchUpdateRequest {
     /// 要更新的记录列表
-    #[serde(rename = "records")]
-    records: Vec<RecordUpdateItem>,
+    records: Vec<serde_json::Value>,
 }
 
 /// 批量编辑记录响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct RecordBatchUpdateResponse {
-    /// 更新的记录列表
-    #[serde(rename = "items")]
-    items: Vec<RecordUpdateResult>,
+    /// 每条记录的更新结果
+    #[serde(default)]
+    pub items: Vec<RecordUpdateResult>,
 }
 
 /// 记录更新结果
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct RecordUpdateResult {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 是否成功
-    #[serde(rename = "success")]
-    success: bool,
-    /// 错误信息
-    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-}
+pub type RecordUpdateResult = RecordOperationResult;
 
 impl ApiResponseTrait for RecordBatchUpdateResponse {
     fn data_format() -> ResponseFormat {
@@ -154,21 +167,28 @@ impl ApiResponseTrait for RecordBatchUpdateResponse {
 
 #[cfg(test)]
 mod tests {
-
-    use serde_json;
+    use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_body_inlines_record_id() {
+        let builder = RecordBatchUpdateBuilder::new(Config::default(), "app", "customer")
+            .record("1790000000000001", serde_json::json!({"name": "a"}))
+            .typed_record("abc", &serde_json::json!({"_id": 9, "name": "b"}))
+            .unwrap();
+        let body = serde_json::to_value(builder.build_body().unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({"records": [
+                {"_id": 1790000000000001i64, "name": "a"},
+                {"_id": "abc", "name": "b"}
+            ]})
+        );
     }
 
     #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    fn test_non_object_data_rejected() {
+        let builder = RecordBatchUpdateBuilder::new(Config::default(), "app", "customer")
+            .record("1", serde_json::json!([1]));
+        assert!(builder.build_body().is_err());
     }
 }
@@ -9,9 +9,15 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::{
+    apaas_record::{ApaasRecord, deserialize_optional_id},
+    api_utils::write_response,
+};
+
 /// 新建记录 Builder
 #[derive(Debug, Clone)]
 pub struct RecordCreateBuilder {
@@ -45,6 +51,11 @@ impl RecordCreateBuilder {
         self
     }
 
+    /// 使用业务结构体设置记录数据
+    pub fn record<T: Serialize>(self, record: &T) -> SDKResult<Self> {
+        Ok(self.data(ApaasRecord::encode(record)?))
+    }
+
     /// 执行请求
     pub async fn execute(self) -> SDKResult<RecordCreateResponse> {
         self.execute_with_options(RequestOption::default()).await
@@ -55,38 +66,35 @@ impl RecordCreateBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordCreateResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        validate_required!(self.object_api_name, "object_api_name 不能为空");
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records",
             self.namespace, self.object_api_name
         );
 
-        let request = RecordCreateRequest { data: self.data };
+        let request = RecordCreateRequest { record: self.data };
 
-        let req: ApiRequest<RecordCreateResponse> =
-            ApiRequest::post(&url).body(serde_json::to_value(&request)?);
+        let req: ApiRequest<RecordCreateResponse> = ApiRequest::post(&url).json_body(&request);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "新建记录")
     }
 }
 
 /// 新建记录请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct RecordCreateRequest {
     /// 记录数据
-    #[serde(rename = "data")]
-    data: serde_json::Value,
+    record: serde_json::Value,
 }
 
 /// 新建记录响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct RecordCreateResponse {
     /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 创建时间
-    #[serde(rename = "created_time")]
-    created_time: i64,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub id: Option<String>,
 }
 
 impl ApiResponseTrait for RecordCreateResponse {
@@ -110,4 +118,23 @@ mod tests {
                 .data(serde_json::json!({}));
         let _ = request;
     }
+
+    #[test]
+    fn test_request_body_and_response() {
+        let body = serde_json::to_value(RecordCreateRequest {
+            record: serde_json::json!({"name": "a"}),
+        })
+        .unwrap();
+        assert_eq!(body, serde_json::json!({"record": {"name": "a"}}));
+
+        let resp: RecordCreateResponse =
+            serde_json::from_value(serde_json::json!({"id": 1790000000000001i64})).unwrap();
+        assert_eq!(resp.id.as_deref(), Some("1790000000000001"));
+    }
+
+    #[test]
+    fn test_record_rejects_non_object() {
+        let result = RecordCreateBuilder::new(Config::default(), "app", "customer").record(&1);
+        assert!(result.is_err());
+    }
 }
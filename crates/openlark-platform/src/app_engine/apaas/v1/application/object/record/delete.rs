@@ -9,9 +9,12 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::api_utils::write_response;
+
 /// 删除记录 Builder
 #[derive(Debug, Clone)]
 pub struct RecordDeleteBuilder {
@@ -50,6 +53,8 @@ impl RecordDeleteBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordDeleteResponse> {
+        validate_required!(self.record_id, "record_id 不能为空");
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records/{}",
             self.namespace, self.object_api_name, self.record_id
@@ -57,21 +62,13 @@ impl RecordDeleteBuilder {
 
         let req: ApiRequest<RecordDeleteResponse> = ApiRequest::delete(&url);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("删除记录", "响应数据为空"))
+        write_response(resp, "删除记录")
     }
 }
 
-/// 删除记录响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct RecordDeleteResponse {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 结果消息
-    #[serde(rename = "message")]
-    message: String,
-}
+/// 删除记录响应（接口成功时不返回数据）
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RecordDeleteResponse {}
 
 impl ApiResponseTrait for RecordDeleteResponse {
     fn data_format() -> ResponseFormat {
@@ -9,9 +9,12 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::{apaas_record::ApaasRecord, api_utils::write_response};
+
 /// 编辑记录 Builder
 #[derive(Debug, Clone)]
 pub struct RecordPatchBuilder {
@@ -49,20 +52,14 @@ impl RecordPatchBuilder {
         self
     }
 
+    /// 使用业务结构体设置更新的数据，系统字段（`_` 开头）会被忽略
+    pub fn record<T: Serialize>(self, record: &T) -> SDKResult<Self> {
+        Ok(self.data(ApaasRecord::encode(record)?.without_system_fields()))
+    }
+
     /// 执行请求
     pub async fn execute(self) -> SDKResult<RecordPatchResponse> {
-        let url = format!(
-            "/open-apis/apaas/v1/applications/{}/objects/{}/records/{}",
-            self.namespace, self.object_api_name, self.record_id
-        );
-
-        let request = RecordPatchRequest { data: self.data };
-
-        let req: ApiRequest<RecordPatchResponse> =
-            ApiRequest::patch(&url).body(serde_json::to_value(&request)?);
-        let resp = Transport::request(req, &self.config, Some(RequestOption::default())).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        self.execute_with_options(RequestOption::default()).await
     }
 
     /// 使用选项执行请求
@@ -70,39 +67,33 @@ impl RecordPatchBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordPatchResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        validate_required!(self.object_api_name, "object_api_name 不能为空");
+        validate_required!(self.record_id, "record_id 不能为空");
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records/{}",
             self.namespace, self.object_api_name, self.record_id
         );
 
-        let request = RecordPatchRequest { data: self.data };
+        let request = RecordPatchRequest { record: self.data };
 
-        let req: ApiRequest<RecordPatchResponse> =
-            ApiRequest::patch(&url).body(serde_json::to_value(&request)?);
+        let req: ApiRequest<RecordPatchResponse> = ApiRequest::patch(&url).json_body(&request);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "编辑记录")
     }
 }
 
 /// 编辑记录请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct RecordPatchRequest {
     /// 更新的数据
-    #[serde(rename = "data")]
-    data: serde_json::Value,
+    record: serde_json::Value,
 }
 
-/// 编辑记录响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct RecordPatchResponse {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 更新时间
-    #[serde(rename = "updated_time")]
-    updated_time: i64,
-}
+/// 编辑记录响应（接口成功时不返回数据）
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RecordPatchResponse {}
 
 impl ApiResponseTrait for RecordPatchResponse {
     fn data_format() -> ResponseFormat {
@@ -112,21 +103,26 @@ impl ApiResponseTrait for RecordPatchResponse {
 
 #[cfg(test)]
 mod tests {
-
-    use serde_json;
+    use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_record_strips_system_fields() {
+        let builder = RecordPatchBuilder::new(Config::default(), "app", "customer", "1")
+            .record(&serde_json::json!({"_id": 1, "name": "a"}))
+            .unwrap();
+        assert_eq!(builder.data, serde_json::json!({"name": "a"}));
+        let body = serde_json::to_value(RecordPatchRequest {
+            record: builder.data,
+        })
+        .unwrap();
+        assert_eq!(body, serde_json::json!({"record": {"name": "a"}}));
     }
 
-    #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    #[tokio::test]
+    async fn test_empty_record_id_rejected() {
+        let result = RecordPatchBuilder::new(Config::default(), "app", "customer", "")
+            .execute()
+            .await;
+        assert!(result.is_err());
     }
 }
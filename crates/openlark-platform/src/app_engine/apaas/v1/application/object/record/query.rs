@@ -9,8 +9,11 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::common::{apaas_record::ApaasRecord, api_utils::write_response};
 
 /// 获取记录详情 Builder
 #[derive(Debug, Clone)]
@@ -65,46 +68,44 @@ impl RecordQueryBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordQueryResponse> {
+        validate_required!(self.record_id, "record_id 不能为空");
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/{}/records/{}/query",
             self.namespace, self.object_api_name, self.record_id
         );
 
         let request = RecordQueryRequest {
-            fields: self.fields,
+            select: self.fields,
         };
 
-        let req: ApiRequest<RecordQueryResponse> =
-            ApiRequest::post(&url).body(serde_json::to_value(&request)?);
+        let req: ApiRequest<RecordQueryResponse> = ApiRequest::post(&url).json_body(&request);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "获取记录详情")
     }
 }
 
 /// 获取记录详情请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct RecordQueryRequest {
-    /// 字段列表
-    #[serde(rename = "fields", skip_serializing_if = "Vec::is_empty")]
-    fields: Vec<String>,
+    /// 返回的字段列表
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    select: Vec<String>,
 }
 
 /// 获取记录详情响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct RecordQueryResponse {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
     /// 记录数据
-    #[serde(rename = "data")]
-    data: serde_json::Value,
-    /// 创建时间
-    #[serde(rename = "created_time")]
-    created_time: i64,
-    /// 更新时间
-    #[serde(rename = "updated_time")]
-    updated_time: i64,
+    #[serde(default)]
+    pub item: ApaasRecord,
+}
+
+impl RecordQueryResponse {
+    /// 将记录映射为业务结构体
+    pub fn decode<T: DeserializeOwned>(&self) -> SDKResult<T> {
+        self.item.decode()
+    }
 }
 
 impl ApiResponseTrait for RecordQueryResponse {
@@ -9,21 +9,30 @@ use openlark_core::{
     config::Config,
     http::Transport,
     req_option::RequestOption,
+    validate_required,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::common::{
+    apaas_oql::{Condition, Criterion, OrderBy},
+    apaas_record::{ApaasRecord, deserialize_records},
+    api_utils::write_response,
+};
+
 /// 搜索记录 Builder
 #[derive(Debug, Clone)]
 pub struct RecordSearchBuilder {
     config: Config,
     /// 应用命名空间
     namespace: String,
-    /// 搜索条件
+    /// 搜索关键词
     search: String,
-    /// 字段列表
+    /// 未单独指定返回字段的搜索对象默认返回的字段
     fields: Vec<String>,
-    /// 页码
-    page: Option<u32>,
+    /// 搜索范围
+    search_objects: Vec<SearchObject>,
+    /// 分页标记
+    page_token: Option<String>,
     /// 每页数量
     page_size: Option<u32>,
 }
@@ -36,26 +45,33 @@ impl RecordSearchBuilder {
             namespace: namespace.into(),
             search: search.into(),
             fields: Vec::new(),
-            page: None,
+            search_objects: Vec::new(),
+            page_token: None,
             page_size: None,
         }
     }
 
-    /// 添加字段
+    /// 添加默认返回字段
     pub fn field(mut self, field: impl Into<String>) -> Self {
         self.fields.push(field.into());
         self
     }
 
-    /// 添加多个字段
+    /// 添加多个默认返回字段
     pub fn fields(mut self, fields: impl IntoIterator<Item = impl Into<String>>) -> Self {
         self.fields.extend(fields.into_iter().map(Into::into));
         self
     }
 
-    /// 设置页码
-    pub fn page(mut self, page: u32) -> Self {
-        self.page = Some(page);
+    /// 添加搜索对象
+    pub fn search_object(mut self, object: SearchObject) -> Self {
+        self.search_objects.push(object);
+        self
+    }
+
+    /// 设置分页标记
+    pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+        self.page_token = Some(page_token.into());
         self
     }
 
@@ -65,6 +81,39 @@ impl RecordSearchBuilder {
         self
     }
 
+    fn build_body(&self) -> SDKResult<RecordSearchRequest> {
+        let search_objects = self
+            .search_objects
+            .iter()
+            .map(|object| {
+                validate_required!(object.api_name, "搜索对象 api_name 不能为空");
+                let select = if object.select.is_empty() {
+                    self.fields.clone()
+                } else {
+                    object.select.clone()
+                };
+                Ok(SearchObjectBody {
+                    api_name: object.api_name.clone(),
+                    search_fields: object.search_fields.clone(),
+                    select,
+                    filter: object
+                        .filter
+                        .as_ref()
+                        .map(|condition| condition.to_criterion(&object.api_name))
+                        .transpose()?,
+                    order_by: object.order_by.clone(),
+                })
+            })
+            .collect::<SDKResult<Vec<_>>>()?;
+
+        Ok(RecordSearchRequest {
+            q: self.search.clone(),
+            search_objects,
+            page_token: self.page_token.clone(),
+            page_size: self.page_size,
+        })
+    }
+
     /// 执行请求
     pub async fn execute(self) -> SDKResult<RecordSearchResponse> {
         self.execute_with_options(RequestOption::default()).await
@@ -75,72 +124,122 @@ impl RecordSearchBuilder {
         self,
         option: RequestOption,
     ) -> SDKResult<RecordSearchResponse> {
+        validate_required!(self.namespace, "namespace 不能为空");
+        validate_required!(self.search, "搜索关键词不能为空");
+        let body = self.build_body()?;
+
         let url = format!(
             "/open-apis/apaas/v1/applications/{}/objects/search",
             self.namespace
         );
 
-        let request = RecordSearchRequest {
-            search: self.search,
-            fields: self.fields,
-            page: self.page,
-            page_size: self.page_size,
-        };
-
-        let req: ApiRequest<RecordSearchResponse> =
-            ApiRequest::post(&url).body(serde_json::to_value(&request)?);
+        let req: ApiRequest<RecordSearchResponse> = ApiRequest::post(&url).json_body(&body);
         let resp = Transport::request(req, &self.config, Some(option)).await?;
-        resp.data
-            .ok_or_else(|| openlark_core::error::validation_error("Operation", "响应数据为空"))
+        write_response(resp, "搜索记录")
+    }
+}
+
+/// 搜索对象：限定在哪个对象、哪些字段中搜索
+#[derive(Debug, Clone, Default)]
+pub struct SearchObject {
+    /// 对象 API 名称
+    pub api_name: String,
+    /// 参与搜索的字段
+    pub search_fields: Vec<String>,
+    /// 返回的字段
+    pub select: Vec<String>,
+    /// 额外筛选条件
+    pub filter: Option<Condition>,
+    /// 排序
+    pub order_by: Vec<OrderBy>,
+}
+
+impl SearchObject {
+    /// 创建搜索对象
+    pub fn new(api_name: impl Into<String>) -> Self {
+        Self {
+            api_name: api_name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// 添加参与搜索的字段
+    pub fn search_field(mut self, field: impl Into<String>) -> Self {
+        self.search_fields.push(field.into());
+        self
+    }
+
+    /// 设置返回的字段
+    pub fn select(mut self, fields: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.select = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 设置筛选条件
+    pub fn filter(mut self, condition: Condition) -> Self {
+        self.filter = Some(condition);
+        self
+    }
+
+    /// 追加排序
+    pub fn order_by(mut self, order: OrderBy) -> Self {
+        self.order_by.push(order);
+        self
     }
 }
 
 /// 搜索记录请求
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct RecordSearchRequest {
-    /// 搜索条件
-    #[serde(rename = "search")]
-    search: String,
-    /// 字段列表
-    #[serde(rename = "fields", skip_serializing_if = "Vec::is_empty")]
-    fields: Vec<String>,
-    /// 页码
-    #[serde(rename = "page", skip_serializing_if = "Option::is_none")]
-    page: Option<u32>,
+    /// 搜索关键词
+    q: String,
+    /// 搜索范围
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    search_objects: Vec<SearchObjectBody>,
+    /// 分页标记
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_token: Option<String>,
     /// 每页数量
-    #[serde(rename = "page_size", skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     page_size: Option<u32>,
 }
 
-/// 搜索记录结果
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct SearchedRecord {
-    /// 记录 ID
-    #[serde(rename = "id")]
-    id: String,
-    /// 记录数据
-    #[serde(rename = "data")]
-    data: serde_json::Value,
-    /// 相关性分数
-    #[serde(rename = "score")]
-    score: f64,
+#[derive(Debug, Clone, Serialize)]
+struct SearchObjectBody {
+    api_name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    search_fields: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    select: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Criterion>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    order_by: Vec<OrderBy>,
+}
+
+/// 单个对象的搜索结果
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SearchObjectResult {
+    /// 对象 API 名称
+    #[serde(default, alias = "object_api_name")]
+    pub api_name: String,
+    /// 命中的记录
+    #[serde(default, deserialize_with = "deserialize_records")]
+    pub records: Vec<ApaasRecord>,
 }
 
 /// 搜索记录响应
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct RecordSearchResponse {
-    /// 搜索结果列表
-    #[serde(rename = "items")]
-    items: Vec<SearchedRecord>,
+    /// 按对象分组的搜索结果
+    #[serde(default)]
+    pub objects: Vec<SearchObjectResult>,
+    /// 下一页分页标记
+    #[serde(default)]
+    pub next_page_token: Option<String>,
     /// 是否有更多
-    #[serde(rename = "has_more")]
-    has_more: bool,
-    /// 页码
-    #[serde(rename = "page")]
-    page: u32,
-    /// 每页数量
-    #[serde(rename = "page_size")]
-    page_size: u32,
+    #[serde(default)]
+    pub has_more: bool,
 }
 
 impl ApiResponseTrait for RecordSearchResponse {
@@ -151,21 +250,37 @@ impl ApiResponseTrait for RecordSearchResponse {
 
 #[cfg(test)]
 mod tests {
-
-    use serde_json;
+    use super::*;
 
     #[test]
-    fn test_serialization_roundtrip() {
-        // 基础序列化测试
-        let json = r#"{"test": "value"}"#;
-        assert!(serde_json::from_str::<serde_json::Value>(json).is_ok());
+    fn test_body_applies_default_fields_and_filter() {
+        let builder = RecordSearchBuilder::new(Config::default(), "app", "飞书")
+            .fields(["_id", "name"])
+            .search_object(
+                SearchObject::new("customer")
+                    .search_field("name")
+                    .filter(Condition::eq("city", "杭州")),
+            )
+            .page_size(20);
+        let body = serde_json::to_value(builder.build_body().unwrap()).unwrap();
+        assert_eq!(body["q"], "飞书");
+        assert_eq!(
+            body["search_objects"][0]["select"],
+            serde_json::json!(["_id", "name"])
+        );
+        assert_eq!(body["search_objects"][0]["filter"]["logic_expression"], "1");
+        assert_eq!(body["page_size"], 20);
     }
 
     #[test]
-    fn test_deserialization_from_json() {
-        // 基础反序列化测试
-        let json = r#"{"field": "data"}"#;
-        let value: serde_json::Value = serde_json::from_str(json).expect("JSON 反序列化失败");
-        assert_eq!(value["field"], "data");
+    fn test_response_records_as_string() {
+        let resp: RecordSearchResponse = serde_json::from_value(serde_json::json!({
+            "objects": [{"api_name": "customer", "records": "[{\"_id\": 1}]"}],
+            "next_page_token": "t1",
+            "has_more": true
+        }))
+        .unwrap();
+        assert_eq!(resp.objects[0].records.len(), 1);
+        assert!(resp.has_more);
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! 提供 API 实现的通用工具和辅助函数，减少重复代码，提高一致性。
 
-use openlark_core::{SDKResult, error};
+use openlark_core::{SDKResult, api::Response, error};
 
 /// 标准化 API 响应数据提取
 pub fn extract_response_data<T>(
@@ -26,3 +26,38 @@ pub fn serialize_params<T: serde::Serialize>(
         )
     })
 }
+
+/// 写接口响应处理：`code != 0` 时返回错误，成功但未返回 `data` 时使用默认值。
+pub fn write_response<T: Default>(response: Response<T>, context: &str) -> SDKResult<T> {
+    if response.is_success() {
+        Ok(response.data.unwrap_or_default())
+    } else {
+        Err(error::validation_error(
+            format!("{context}失败: {}", response.code()),
+            response.raw_response.msg.to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openlark_core::api::RawResponse;
+
+    #[test]
+    fn test_write_response_defaults_missing_data() {
+        let response: Response<Vec<String>> = Response::new(None, RawResponse::success());
+        assert_eq!(
+            write_response(response, "测试").unwrap(),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_write_response_error_code() {
+        let mut raw = RawResponse::success();
+        raw.code = 1_230_001;
+        let response: Response<Vec<String>> = Response::new(None, raw);
+        assert!(write_response(response, "测试").is_err());
+    }
+}
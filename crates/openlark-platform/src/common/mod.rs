@@ -42,6 +42,14 @@ pub mod api_endpoints;
 pub mod api_utils;
 pub mod constants;
 
+// aPaaS 类型化访问
+#[cfg(all(feature = "app-engine", feature = "v1"))]
+pub mod apaas_object;
+#[cfg(all(feature = "app-engine", feature = "v1"))]
+pub mod apaas_oql;
+#[cfg(all(feature = "app-engine", feature = "v1"))]
+pub mod apaas_record;
+
 #[cfg(test)]
 mod tests {
 
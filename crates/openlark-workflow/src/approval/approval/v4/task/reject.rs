@@ -6,6 +6,7 @@ use openlark_core::{
     SDKResult,
     api::{ApiRequest, ApiResponseTrait, ResponseFormat},
     config::Config,
+    identity::UserRef,
     validate_required,
 };
 use serde::{Deserialize, Serialize};
@@ -42,6 +43,7 @@ pub struct RejectTaskRequestV4 {
     config: Arc<Config>,
     body: RejectTaskBodyV4,
     user_id_type: Option<String>,
+    user: Option<UserRef>,
 }
 
 impl RejectTaskRequestV4 {
@@ -50,6 +52,7 @@ impl RejectTaskRequestV4 {
             config,
             body: RejectTaskBodyV4::default(),
             user_id_type: None,
+            user: None,
         }
     }
 
@@ -71,6 +74,16 @@ impl RejectTaskRequestV4 {
         self
     }
 
+    /// 使用类型化的用户引用同时设置审批人用户 ID 与用户 ID 类型
+    ///
+    /// 邮箱、手机号与工号需先解析为 open_id / union_id / user_id，否则执行时返回校验错误。
+    pub fn user(mut self, user: UserRef) -> Self {
+        self.body.user_id = user.value().to_string();
+        self.user_id_type = user.user_id_type().map(str::to_string);
+        self.user = Some(user);
+        self
+    }
+
     /// 设置审批任务 ID
     pub fn task_id(mut self, task_id: impl Into<String>) -> Self {
         self.body.task_id = task_id.into();
@@ -109,6 +122,9 @@ impl RejectTaskRequestV4 {
         validate_required!(self.body.approval_code.trim(), "审批定义 Code 不能为空");
         validate_required!(self.body.instance_code.trim(), "审批实例 Code 不能为空");
         validate_required!(self.body.user_id.trim(), "审批人用户 ID 不能为空");
+        if let Some(user) = &self.user {
+            user.require_user_id_type()?;
+        }
         validate_required!(self.body.task_id.trim(), "审批任务 ID 不能为空");
 
         let api_endpoint = crate::common::api_endpoints::ApprovalApiV4::TaskReject;
@@ -173,6 +189,18 @@ mod tests {
         assert_eq!(request.user_id_type.as_deref(), Some("open_id"));
     }
 
+    #[test]
+    fn test_reject_task_request_with_user_ref() {
+        let request =
+            RejectTaskRequestV4::new(Arc::new(Config::default())).user(UserRef::user_id("u_1"));
+        assert_eq!(request.body.user_id, "u_1");
+        assert_eq!(request.user_id_type.as_deref(), Some("user_id"));
+
+        let request =
+            RejectTaskRequestV4::new(Arc::new(Config::default())).user(UserRef::email("a@b.com"));
+        assert_eq!(request.user_id_type, None);
+    }
+
     #[test]
     fn test_reject_task_response_accepts_empty_data() {
         let response: RejectTaskResponseV4 =
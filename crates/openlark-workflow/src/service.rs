@@ -7,7 +7,7 @@ mod approval_task_reject;
 #[path = "approval/approval/v4/task/resubmit.rs"]
 mod approval_task_resubmit;
 
-use openlark_core::{SDKResult, config::Config};
+use openlark_core::{SDKResult, config::Config, identity::UserRef};
 use std::sync::Arc;
 
 use crate::common::constants::MAX_PAGE_SIZE;
@@ -208,6 +208,8 @@ pub struct ApprovalTaskAction {
     pub comment: Option<String>,
     /// 表单内容。
     pub form: Option<String>,
+    /// 类型化的操作人引用，设置后覆盖 `user_id` 与 `user_id_type`。
+    pub user: Option<UserRef>,
 }
 
 impl ApprovalTaskAction {
@@ -226,9 +228,20 @@ impl ApprovalTaskAction {
             user_id_type: None,
             comment: None,
             form: None,
+            user: None,
         }
     }
 
+    /// 使用类型化的用户引用设置操作人及用户 ID 类型。
+    ///
+    /// 邮箱、手机号与工号需先解析为 open_id / union_id / user_id，否则执行时返回校验错误。
+    pub fn user(mut self, user: UserRef) -> Self {
+        self.user_id = user.value().to_string();
+        self.user_id_type = user.user_id_type().map(str::to_string);
+        self.user = Some(user);
+        self
+    }
+
     /// 设置备注。
     pub fn comment(mut self, comment: impl Into<String>) -> Self {
         self.comment = Some(comment.into());
@@ -456,6 +469,9 @@ impl WorkflowService {
         if let Some(user_id_type) = action.user_id_type {
             request = request.user_id_type(user_id_type);
         }
+        if let Some(user) = action.user {
+            request = request.user(user);
+        }
         if let Some(comment) = action.comment {
             request = request.comment(comment);
         }
@@ -480,6 +496,9 @@ impl WorkflowService {
         if let Some(user_id_type) = action.user_id_type {
             request = request.user_id_type(user_id_type);
         }
+        if let Some(user) = action.user {
+            request = request.user(user);
+        }
         if let Some(comment) = action.comment {
             request = request.comment(comment);
         }
@@ -504,6 +523,9 @@ impl WorkflowService {
         if let Some(user_id_type) = action.user_id_type {
             request = request.user_id_type(user_id_type);
         }
+        if let Some(user) = action.user {
+            request = request.user(user);
+        }
         if let Some(comment) = action.comment {
             request = request.comment(comment);
         }
@@ -606,4 +628,19 @@ mod tests {
         assert_eq!(action.comment.as_deref(), Some("已确认"));
         assert_eq!(action.form.as_deref(), Some("[{}]"));
     }
+
+    #[test]
+    fn test_approval_task_action_with_user_ref() {
+        let action = ApprovalTaskAction::new("approval_code", "instance_code", "", "task_123")
+            .user(UserRef::union_id("on_xxx"));
+        assert_eq!(action.user_id, "on_xxx");
+        assert_eq!(action.user_id_type.as_deref(), Some("union_id"));
+
+        let action = ApprovalTaskAction::new("approval_code", "instance_code", "", "task_123")
+            .user(UserRef::email("a@b.com"));
+        assert_eq!(action.user_id_type, None);
+        let service = WorkflowService::new(Config::default());
+        let rt = tokio::runtime::Runtime::new().expect("创建 tokio runtime 失败");
+        assert!(rt.block_on(service.approve_task(action)).is_err());
+    }
 }
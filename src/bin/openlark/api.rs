@@ -0,0 +1,327 @@
+//! `openlark api`：调用任意开放平台接口；`openlark endpoints`：检索接口目录
+
+use std::future::Future;
+use std::io::Read;
+
+use anyhow::{Context, bail};
+use clap::Args;
+use openlark_core::{api::ApiRequest, config::Config, http::Transport, req_option::RequestOption};
+use serde_json::{Value, json};
+
+use crate::output::{self, OutputFormat};
+
+/// 最多翻页次数，防止服务端分页异常时死循环
+const MAX_PAGES: usize = 10_000;
+
+/// 接口目录（method、path、文档地址）
+const API_CATALOG: &str = include_str!("../../../api_list_export.csv");
+
+/// `openlark api` 参数
+#[derive(Debug, Args)]
+pub struct ApiArgs {
+    /// HTTP 方法：GET / POST / PUT / PATCH / DELETE
+    pub method: String,
+    /// 接口路径，如 `/open-apis/im/v1/chats`（可省略 `/open-apis` 前缀）
+    pub path: String,
+    /// 查询参数，可重复
+    #[arg(short = 'q', long = "query", value_name = "KEY=VALUE")]
+    pub query: Vec<String>,
+    /// 请求体：JSON 文本、`@file.json` 或 `@-`（从标准输入读取）
+    #[arg(short = 'd', long = "data", value_name = "JSON|@FILE")]
+    pub data: Option<String>,
+    /// 额外请求头，可重复
+    #[arg(short = 'H', long = "header", value_name = "KEY:VALUE")]
+    pub headers: Vec<String>,
+    /// 以用户身份调用（user_access_token），默认使用 tenant_access_token
+    #[arg(long, env = "OPENLARK_USER_ACCESS_TOKEN", hide_env_values = true)]
+    pub user_token: Option<String>,
+    /// 自动翻页并合并所有结果
+    #[arg(long)]
+    pub all: bool,
+    /// 每页条数（写入 `page_size` 查询参数）
+    #[arg(long)]
+    pub page_size: Option<u32>,
+}
+
+/// `openlark endpoints` 参数
+#[derive(Debug, Args)]
+pub struct EndpointsArgs {
+    /// 关键字（匹配名称、路径、业务域），多个关键字需同时命中
+    pub keywords: Vec<String>,
+}
+
+/// 执行 `openlark api`
+pub async fn run(args: ApiArgs, config: &Config, format: OutputFormat) -> anyhow::Result<()> {
+    let path = normalize_path(&args.path);
+    let mut query = args
+        .query
+        .iter()
+        .map(|pair| split_pair(pair, '=').context("查询参数格式应为 KEY=VALUE"))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if let Some(page_size) = args.page_size {
+        query.push(("page_size".to_string(), page_size.to_string()));
+    }
+    let headers = args
+        .headers
+        .iter()
+        .map(|pair| split_pair(pair, ':').context("请求头格式应为 KEY:VALUE"))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let body = args.data.as_deref().map(read_body).transpose()?;
+
+    let mut option = RequestOption::builder();
+    if let Some(token) = args.user_token {
+        option = option.user_access_token(token);
+    }
+    for (key, value) in headers {
+        option = option.add_header(key, value);
+    }
+    let option = option.build();
+
+    let fetch = |page_token: Option<String>| {
+        let mut query = query.clone();
+        if let Some(token) = page_token {
+            query.retain(|(key, _)| key != "page_token");
+            query.push(("page_token".to_string(), token));
+        }
+        call(
+            config,
+            &args.method,
+            &path,
+            query,
+            body.clone(),
+            option.clone(),
+        )
+    };
+    let result = if args.all {
+        collect_pages(fetch).await?
+    } else {
+        fetch(None).await?
+    };
+    output::print(&result, format);
+    Ok(())
+}
+
+/// 发送一次请求并返回 `data`
+pub async fn call(
+    config: &Config,
+    method: &str,
+    path: &str,
+    query: Vec<(String, String)>,
+    body: Option<Value>,
+    option: RequestOption,
+) -> anyhow::Result<Value> {
+    let mut request: ApiRequest<Value> = match method.to_ascii_uppercase().as_str() {
+        "GET" => ApiRequest::get(path),
+        "POST" => ApiRequest::post(path),
+        "PUT" => ApiRequest::put(path),
+        "PATCH" => ApiRequest::patch(path),
+        "DELETE" => ApiRequest::delete(path),
+        other => bail!("不支持的 HTTP 方法 `{other}`"),
+    };
+    for (key, value) in query {
+        request = request.query(key, value);
+    }
+    if let Some(body) = &body {
+        request = request.json_body(body);
+    }
+
+    let response = Transport::<Value>::request(request, config, Some(option)).await?;
+    if !response.is_success() {
+        let log_id = response.log_id().unwrap_or("-").to_string();
+        bail!(
+            "接口返回错误 code={} msg={} log_id={log_id}",
+            response.code(),
+            response.msg()
+        );
+    }
+    Ok(response.data.unwrap_or_else(|| json!({})))
+}
+
+/// 按 `has_more` + `page_token` / `next_page_token` 翻页，合并列表字段
+pub async fn collect_pages<F, Fut>(mut fetch: F) -> anyhow::Result<Value>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = anyhow::Result<Value>>,
+{
+    let mut page = fetch(None).await?;
+    let Some(key) = list_field(&page) else {
+        // 不是列表接口，原样返回
+        return Ok(page);
+    };
+    let mut items = Vec::new();
+    let mut seen_tokens = Vec::new();
+    loop {
+        if let Some(Value::Array(batch)) = page.get_mut(&key).map(Value::take) {
+            items.extend(batch);
+        }
+        let Some(token) = next_page_token(&page) else {
+            break;
+        };
+        if seen_tokens.contains(&token) || seen_tokens.len() >= MAX_PAGES {
+            bail!("分页未结束但 page_token 重复或页数超限，已停止翻页");
+        }
+        seen_tokens.push(token.clone());
+        page = fetch(Some(token)).await?;
+    }
+    Ok(json!({ key: items, "has_more": false }))
+}
+
+/// 列表字段名：优先 `items`，否则取第一个数组字段
+fn list_field(data: &Value) -> Option<String> {
+    let map = data.as_object()?;
+    if map.get("items").is_some_and(Value::is_array) {
+        return Some("items".to_string());
+    }
+    map.iter()
+        .find(|(_, value)| value.is_array())
+        .map(|(key, _)| key.clone())
+}
+
+fn next_page_token(data: &Value) -> Option<String> {
+    if data["has_more"].as_bool() != Some(true) {
+        return None;
+    }
+    ["page_token", "next_page_token"]
+        .iter()
+        .find_map(|key| data[*key].as_str())
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+}
+
+/// 补全 `/open-apis` 前缀
+fn normalize_path(path: &str) -> String {
+    let path = path.trim();
+    if path.starts_with("/open-apis/") {
+        return path.to_string();
+    }
+    format!("/open-apis/{}", path.trim_start_matches('/'))
+}
+
+fn split_pair(pair: &str, separator: char) -> Option<(String, String)> {
+    let (key, value) = pair.split_once(separator)?;
+    let key = key.trim();
+    (!key.is_empty()).then(|| (key.to_string(), value.trim().to_string()))
+}
+
+fn read_body(data: &str) -> anyhow::Result<Value> {
+    let text = match data.strip_prefix('@') {
+        Some("-") => {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .context("读取标准输入失败")?;
+            text
+        }
+        Some(path) => std::fs::read_to_string(path).with_context(|| format!("读取 {path} 失败"))?,
+        None => data.to_string(),
+    };
+    serde_json::from_str(&text).context("请求体不是合法的 JSON")
+}
+
+/// 执行 `openlark endpoints`
+pub fn search_endpoints(args: EndpointsArgs, format: OutputFormat) -> anyhow::Result<()> {
+    let keywords: Vec<String> = args.keywords.iter().map(|k| k.to_lowercase()).collect();
+    let mut reader = csv::Reader::from_reader(API_CATALOG.as_bytes());
+    let headers = reader.headers()?.clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header == name)
+            .with_context(|| format!("接口目录缺少 {name} 列"))
+    };
+    let (name_col, biz_col, url_col, doc_col) = (
+        column("name")?,
+        column("bizTag")?,
+        column("url")?,
+        column("docPath")?,
+    );
+
+    let mut matches = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let field = |index: usize| record.get(index).unwrap_or_default();
+        let Some((method, path)) = field(url_col).split_once(':') else {
+            continue;
+        };
+        let haystack = format!("{} {} {}", field(name_col), field(biz_col), path).to_lowercase();
+        if keywords.iter().all(|keyword| haystack.contains(keyword)) {
+            matches.push(json!({
+                "method": method,
+                "path": path,
+                "name": field(name_col),
+                "doc": field(doc_col),
+            }));
+        }
+    }
+    output::print(&Value::Array(matches), format);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path("im/v1/chats"), "/open-apis/im/v1/chats");
+        assert_eq!(normalize_path("/im/v1/chats"), "/open-apis/im/v1/chats");
+        assert_eq!(
+            normalize_path("/open-apis/im/v1/chats"),
+            "/open-apis/im/v1/chats"
+        );
+    }
+
+    #[test]
+    fn test_split_pair() {
+        assert_eq!(
+            split_pair("user_id_type=open_id", '='),
+            Some(("user_id_type".to_string(), "open_id".to_string()))
+        );
+        assert_eq!(
+            split_pair("filter=a=b", '='),
+            Some(("filter".to_string(), "a=b".to_string()))
+        );
+        assert_eq!(split_pair("=x", '='), None);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_merges_items() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let log = requested.clone();
+        let result = collect_pages(move |token: Option<String>| {
+            log.lock().unwrap().push(token.clone());
+            async move {
+                Ok(match token.as_deref() {
+                    None => json!({"files": [1, 2], "has_more": true, "next_page_token": "p2"}),
+                    Some("p2") => json!({"files": [3], "has_more": false}),
+                    Some(other) => panic!("unexpected token {other}"),
+                })
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(result["files"], json!([1, 2, 3]));
+        assert_eq!(
+            *requested.lock().unwrap(),
+            vec![None, Some("p2".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_stops_on_repeated_token() {
+        let result = collect_pages(|_| async {
+            Ok(json!({"items": [1], "has_more": true, "page_token": "same"}))
+        })
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_catalog_is_parseable() {
+        let mut reader = csv::Reader::from_reader(API_CATALOG.as_bytes());
+        let headers = reader.headers().unwrap();
+        assert!(headers.iter().any(|header| header == "url"));
+        assert!(reader.records().count() > 1000);
+    }
+}
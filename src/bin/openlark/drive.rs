@@ -0,0 +1,93 @@
+//! `openlark drive`：上传文件到云空间
+
+use std::path::PathBuf;
+
+use anyhow::{Context, bail};
+use clap::{Args, Subcommand};
+use openlark_core::{config::Config, req_option::RequestOption};
+use openlark_docs::ccm::drive::v1::file::upload_all::UploadAllRequest;
+use serde_json::json;
+
+use crate::api;
+use crate::output::{self, OutputFormat};
+
+/// `upload_all` 接口允许的最大文件大小（20MB）
+const MAX_UPLOAD_SIZE: usize = 20 * 1024 * 1024;
+
+/// `openlark drive` 子命令
+#[derive(Debug, Subcommand)]
+pub enum DriveCommand {
+    /// 上传文件（不超过 20MB）
+    Upload(UploadArgs),
+}
+
+/// `openlark drive upload` 参数
+#[derive(Debug, Args)]
+pub struct UploadArgs {
+    /// 本地文件路径
+    pub file: PathBuf,
+    /// 目标文件夹 token，缺省时上传到应用的根目录
+    #[arg(long)]
+    pub folder: Option<String>,
+    /// 云空间中的文件名，缺省时使用本地文件名
+    #[arg(long)]
+    pub name: Option<String>,
+}
+
+/// 执行 `openlark drive`
+pub async fn run(
+    command: DriveCommand,
+    config: &Config,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let DriveCommand::Upload(args) = command;
+    let content =
+        std::fs::read(&args.file).with_context(|| format!("读取 {} 失败", args.file.display()))?;
+    if content.len() > MAX_UPLOAD_SIZE {
+        bail!("文件超过 20MB，请使用分片上传接口（upload_prepare / upload_part / upload_finish）");
+    }
+    let name = match args.name {
+        Some(name) => name,
+        None => args
+            .file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .context("无法从路径推断文件名，请使用 --name 指定")?
+            .to_string(),
+    };
+    let folder = match args.folder {
+        Some(folder) => folder,
+        None => root_folder_token(config).await?,
+    };
+
+    let size = content.len();
+    let response = UploadAllRequest::new(config.clone(), &name, &folder, "explorer", size, content)
+        .execute()
+        .await?;
+    output::print(
+        &json!({
+            "file_token": response.file_token,
+            "name": name,
+            "folder_token": folder,
+            "size": size,
+        }),
+        format,
+    );
+    Ok(())
+}
+
+async fn root_folder_token(config: &Config) -> anyhow::Result<String> {
+    let data = api::call(
+        config,
+        "GET",
+        "/open-apis/drive/explorer/v2/root_folder/meta",
+        Vec::new(),
+        None,
+        RequestOption::default(),
+    )
+    .await?;
+    data["token"]
+        .as_str()
+        .map(str::to_string)
+        .context("获取根目录 token 失败")
+}
@@ -0,0 +1,14 @@
+//! offline stub
+use crate::output::OutputFormat;
+use clap::Subcommand;
+#[derive(Debug, Subcommand)]
+pub enum EventsCommand {
+    Tail,
+}
+pub async fn run(
+    _c: EventsCommand,
+    _cfg: openlark_client::Config,
+    _f: OutputFormat,
+) -> anyhow::Result<()> {
+    Ok(())
+}
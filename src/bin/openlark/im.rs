@@ -0,0 +1,167 @@
+//! `openlark im`：发送消息、列出群组
+
+use anyhow::bail;
+use clap::{Args, Subcommand, ValueEnum};
+use openlark_communication::im::im::v1::{
+    chat::list::ListChatsRequest,
+    message::{
+        create::{CreateMessageBody, CreateMessageRequest},
+        models::ReceiveIdType,
+    },
+};
+use openlark_core::config::Config;
+use serde_json::{Value, json};
+
+use crate::api::collect_pages;
+use crate::output::{self, OutputFormat};
+
+/// `openlark im` 子命令
+#[derive(Debug, Subcommand)]
+pub enum ImCommand {
+    /// 发送消息
+    Send(SendArgs),
+    /// 列出机器人所在的群组
+    Chats(ChatsArgs),
+}
+
+/// 接收者 ID 类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum IdType {
+    /// open_id（`ou_` 开头）
+    OpenId,
+    /// union_id（`on_` 开头）
+    UnionId,
+    /// user_id
+    UserId,
+    /// 邮箱
+    Email,
+    /// 群组 ID（`oc_` 开头）
+    ChatId,
+}
+
+impl IdType {
+    /// 根据 ID 前缀推断类型
+    fn infer(receive_id: &str) -> Self {
+        if receive_id.starts_with("oc_") {
+            Self::ChatId
+        } else if receive_id.starts_with("ou_") {
+            Self::OpenId
+        } else if receive_id.starts_with("on_") {
+            Self::UnionId
+        } else if receive_id.contains('@') {
+            Self::Email
+        } else {
+            Self::UserId
+        }
+    }
+}
+
+impl From<IdType> for ReceiveIdType {
+    fn from(id_type: IdType) -> Self {
+        match id_type {
+            IdType::OpenId => Self::OpenId,
+            IdType::UnionId => Self::UnionId,
+            IdType::UserId => Self::UserId,
+            IdType::Email => Self::Email,
+            IdType::ChatId => Self::ChatId,
+        }
+    }
+}
+
+/// `openlark im send` 参数
+#[derive(Debug, Args)]
+pub struct SendArgs {
+    /// 接收者 ID（群组 ID、open_id、邮箱等）
+    #[arg(long)]
+    pub to: String,
+    /// 接收者 ID 类型，缺省时按前缀推断
+    #[arg(long, value_enum)]
+    pub id_type: Option<IdType>,
+    /// 文本消息内容
+    #[arg(long, conflicts_with = "content")]
+    pub text: Option<String>,
+    /// 消息类型，配合 `--content` 使用
+    #[arg(long, default_value = "text")]
+    pub msg_type: String,
+    /// 原始消息内容（JSON）
+    #[arg(long)]
+    pub content: Option<String>,
+    /// 幂等 uuid
+    #[arg(long)]
+    pub uuid: Option<String>,
+}
+
+/// `openlark im chats` 参数
+#[derive(Debug, Args)]
+pub struct ChatsArgs {
+    /// 每页条数
+    #[arg(long)]
+    pub page_size: Option<i32>,
+    /// 自动翻页
+    #[arg(long)]
+    pub all: bool,
+}
+
+/// 执行 `openlark im`
+pub async fn run(command: ImCommand, config: &Config, format: OutputFormat) -> anyhow::Result<()> {
+    let result = match command {
+        ImCommand::Send(args) => send(args, config).await?,
+        ImCommand::Chats(args) => {
+            let fetch = |page_token: Option<String>| {
+                let mut request = ListChatsRequest::new(config.clone());
+                if let Some(page_size) = args.page_size {
+                    request = request.page_size(page_size);
+                }
+                if let Some(page_token) = page_token {
+                    request = request.page_token(page_token);
+                }
+                async move { Ok(request.execute().await?) }
+            };
+            if args.all {
+                collect_pages(fetch).await?
+            } else {
+                fetch(None).await?
+            }
+        }
+    };
+    output::print(&result, format);
+    Ok(())
+}
+
+async fn send(args: SendArgs, config: &Config) -> anyhow::Result<Value> {
+    let (msg_type, content) = match (args.text, args.content) {
+        (Some(text), _) => ("text".to_string(), json!({ "text": text }).to_string()),
+        (None, Some(content)) => {
+            if let Err(err) = serde_json::from_str::<Value>(&content) {
+                bail!("--content 不是合法的 JSON：{err}");
+            }
+            (args.msg_type, content)
+        }
+        (None, None) => bail!("请通过 --text 或 --content 指定消息内容"),
+    };
+    let id_type = args.id_type.unwrap_or_else(|| IdType::infer(&args.to));
+    let body = CreateMessageBody {
+        receive_id: args.to,
+        msg_type,
+        content,
+        uuid: args.uuid,
+    };
+    Ok(CreateMessageRequest::new(config.clone())
+        .receive_id_type(id_type.into())
+        .execute(body)
+        .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infer_id_type() {
+        assert_eq!(IdType::infer("oc_123"), IdType::ChatId);
+        assert_eq!(IdType::infer("ou_123"), IdType::OpenId);
+        assert_eq!(IdType::infer("on_123"), IdType::UnionId);
+        assert_eq!(IdType::infer("ops@example.com"), IdType::Email);
+        assert_eq!(IdType::infer("3a7c91"), IdType::UserId);
+    }
+}
@@ -0,0 +1,316 @@
+//! `openlark` 命令行工具
+//!
+//! 通过 `cli` feature 构建：
+//!
+//! ```bash
+//! cargo install openlark --features cli
+//! openlark profile set prod --app-id cli_xxx --app-secret xxx --default
+//! openlark api GET /open-apis/im/v1/chats --all -o table
+//! openlark im send --to oc_xxx --text "发布完成"
+//! openlark events tail --event-type im.message.receive_v1
+//! ```
+
+mod api;
+mod bitable;
+mod drive;
+mod events;
+mod im;
+mod output;
+mod profile;
+
+use anyhow::{Context, bail};
+use clap::{Args, Parser, Subcommand};
+use serde_json::{Value, json};
+
+use crate::output::OutputFormat;
+use crate::profile::{CredentialOverrides, Credentials, ProfileFile, mask_secret};
+
+/// 飞书开放平台命令行工具
+#[derive(Debug, Parser)]
+#[command(name = "openlark", version, about)]
+struct Cli {
+    /// 使用的凭证 profile
+    #[arg(short, long, global = true, env = "OPENLARK_PROFILE")]
+    profile: Option<String>,
+    /// 输出格式
+    #[arg(short, long, global = true, value_enum, default_value_t = OutputFormat::Json)]
+    output: OutputFormat,
+    /// 应用 ID，优先于 profile
+    #[arg(long, env = "OPENLARK_APP_ID")]
+    app_id: Option<String>,
+    /// 应用密钥，优先于 profile
+    #[arg(long, env = "OPENLARK_APP_SECRET", hide_env_values = true)]
+    app_secret: Option<String>,
+    /// API 地址，优先于 profile
+    #[arg(long, env = "OPENLARK_BASE_URL")]
+    base_url: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// 调用任意开放平台接口，自动注入 tenant_access_token
+    Api(api::ApiArgs),
+    /// 检索内置的接口目录
+    Endpoints(api::EndpointsArgs),
+    /// 消息与群组
+    #[command(subcommand)]
+    Im(im::ImCommand),
+    /// 云空间
+    #[command(subcommand)]
+    Drive(drive::DriveCommand),
+    /// 多维表格
+    #[command(subcommand)]
+    Bitable(bitable::BitableCommand),
+    /// 事件订阅（长连接）
+    #[command(subcommand)]
+    Events(events::EventsCommand),
+    /// 管理凭证 profile
+    #[command(subcommand)]
+    Profile(ProfileCommand),
+}
+
+#[derive(Debug, Subcommand)]
+enum ProfileCommand {
+    /// 列出所有 profile
+    List,
+    /// 查看 profile（密钥打码）
+    Show {
+        /// profile 名称，缺省为当前选用的 profile
+        name: Option<String>,
+    },
+    /// 新增或更新 profile
+    Set(SetProfileArgs),
+    /// 设为默认 profile
+    Use {
+        /// profile 名称
+        name: String,
+    },
+    /// 删除 profile
+    Remove {
+        /// profile 名称
+        name: String,
+    },
+}
+
+#[derive(Debug, Args)]
+struct SetProfileArgs {
+    /// profile 名称
+    name: String,
+    /// 应用 ID
+    #[arg(long)]
+    app_id: Option<String>,
+    /// 应用密钥；不保存密钥时可在运行时通过 OPENLARK_APP_SECRET 提供
+    #[arg(long)]
+    app_secret: Option<String>,
+    /// API 地址，国际版 Lark 使用 `https://open.larksuite.com`
+    #[arg(long)]
+    base_url: Option<String>,
+    /// 应用类型：self_build / marketplace
+    #[arg(long)]
+    app_type: Option<String>,
+    /// 允许非飞书/Lark 域名的 base_url
+    #[arg(long)]
+    allow_custom_base_url: bool,
+    /// 同时设为默认 profile
+    #[arg(long)]
+    default: bool,
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    if let Err(err) = run(cli).await {
+        eprintln!("error: {err:#}");
+        std::process::exit(1);
+    }
+}
+
+async fn run(cli: Cli) -> anyhow::Result<()> {
+    let format = cli.output;
+    let path = ProfileFile::path().context("无法确定配置文件位置，请设置 OPENLARK_CONFIG")?;
+    let file = ProfileFile::load(&path)?;
+
+    let command = match cli.command {
+        Command::Profile(command) => {
+            return manage_profiles(command, file, &path, cli.profile.as_deref(), format);
+        }
+        Command::Endpoints(args) => return api::search_endpoints(args, format),
+        command => command,
+    };
+
+    let credentials = Credentials::resolve(
+        &file,
+        cli.profile.as_deref(),
+        CredentialOverrides {
+            app_id: cli.app_id,
+            app_secret: cli.app_secret,
+            base_url: cli.base_url,
+        },
+    )?;
+    let client_config = client_config(&credentials)?;
+    let command = match command {
+        Command::Events(command) => return events::run(command, client_config, format).await,
+        command => command,
+    };
+
+    let client = openlark_client::Client::with_config(client_config)?;
+    let config = client.api_config();
+    match command {
+        Command::Api(args) => api::run(args, config, format).await,
+        Command::Im(command) => im::run(command, config, format).await,
+        Command::Drive(command) => drive::run(command, config, format).await,
+        Command::Bitable(command) => bitable::run(command, config, format).await,
+        Command::Events(_) | Command::Endpoints(_) | Command::Profile(_) => unreachable!(),
+    }
+}
+
+fn client_config(credentials: &Credentials) -> anyhow::Result<openlark_client::Config> {
+    let mut config = openlark_client::Config::builder()
+        .app_id(&credentials.app_id)
+        .app_secret(&credentials.app_secret)
+        .base_url(&credentials.base_url)
+        .app_type(credentials.app_type)
+        .enable_log(false)
+        .build_unvalidated();
+    config.allow_custom_base_url = credentials.allow_custom_base_url;
+    config.validate()?;
+    Ok(config)
+}
+
+fn manage_profiles(
+    command: ProfileCommand,
+    mut file: ProfileFile,
+    path: &std::path::Path,
+    selected: Option<&str>,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    match command {
+        ProfileCommand::List => {
+            let current = file.selected_name(selected);
+            let rows: Vec<Value> = file
+                .profiles
+                .iter()
+                .map(|(name, profile)| {
+                    json!({
+                        "name": name,
+                        "current": name == current,
+                        "app_id": profile.app_id,
+                        "base_url": profile.base_url.as_deref().unwrap_or(profile::DEFAULT_BASE_URL),
+                    })
+                })
+                .collect();
+            output::print(&Value::Array(rows), format);
+        }
+        ProfileCommand::Show { name } => {
+            let name = name
+                .as_deref()
+                .unwrap_or_else(|| file.selected_name(selected));
+            let profile = file
+                .profiles
+                .get(name)
+                .with_context(|| format!("profile `{name}` 不存在"))?;
+            let mut shown = serde_json::to_value(profile)?;
+            shown["name"] = json!(name);
+            shown["app_secret"] = match &profile.app_secret {
+                Some(secret) => json!(mask_secret(secret)),
+                None => json!("(未保存，运行时读取 OPENLARK_APP_SECRET)"),
+            };
+            output::print(&shown, format);
+        }
+        ProfileCommand::Set(args) => {
+            let profile = file.profiles.entry(args.name.clone()).or_default();
+            if let Some(app_id) = args.app_id {
+                profile.app_id = app_id;
+            }
+            if profile.app_id.is_empty() {
+                bail!("新建 profile 时必须提供 --app-id");
+            }
+            if args.app_secret.is_some() {
+                profile.app_secret = args.app_secret;
+            }
+            if args.base_url.is_some() {
+                profile.base_url = args.base_url;
+            }
+            if args.app_type.is_some() {
+                profile.app_type = args.app_type;
+            }
+            if args.allow_custom_base_url {
+                profile.allow_custom_base_url = true;
+            }
+            if args.default || file.default_profile.is_none() {
+                file.default_profile = Some(args.name.clone());
+            }
+            file.save(path)?;
+            eprintln!("已保存 profile `{}` 到 {}", args.name, path.display());
+        }
+        ProfileCommand::Use { name } => {
+            if !file.profiles.contains_key(&name) {
+                bail!("profile `{name}` 不存在");
+            }
+            file.default_profile = Some(name);
+            file.save(path)?;
+        }
+        ProfileCommand::Remove { name } => {
+            if file.profiles.remove(&name).is_none() {
+                bail!("profile `{name}` 不存在");
+            }
+            if file.default_profile.as_deref() == Some(name.as_str()) {
+                file.default_profile = None;
+            }
+            file.save(path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_api_command() {
+        let cli = Cli::try_parse_from([
+            "openlark",
+            "--app-id",
+            "cli_x",
+            "api",
+            "GET",
+            "/open-apis/im/v1/chats",
+            "-q",
+            "page_size=50",
+            "--all",
+            "-o",
+            "table",
+        ])
+        .unwrap();
+        assert_eq!(cli.output, OutputFormat::Table);
+        assert_eq!(cli.app_id.as_deref(), Some("cli_x"));
+        let Command::Api(args) = cli.command else {
+            panic!("expected api command");
+        };
+        assert!(args.all);
+        assert_eq!(args.query, vec!["page_size=50".to_string()]);
+    }
+
+    #[test]
+    fn test_client_config_rejects_unknown_domain() {
+        let mut credentials = Credentials {
+            app_id: "cli_x".to_string(),
+            app_secret: "secret".to_string(),
+            base_url: "http://127.0.0.1:8080".to_string(),
+            app_type: openlark_core::constants::AppType::SelfBuild,
+            allow_custom_base_url: false,
+        };
+        assert!(client_config(&credentials).is_err());
+        credentials.allow_custom_base_url = true;
+        assert!(client_config(&credentials).is_ok());
+    }
+}
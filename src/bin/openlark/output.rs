@@ -0,0 +1,178 @@
+//! 输出格式化
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+/// 单元格最大显示宽度（字符）
+const MAX_CELL_WIDTH: usize = 48;
+
+/// 输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// 格式化 JSON
+    #[default]
+    Json,
+    /// 单行 JSON，便于交给 jq 等工具处理
+    Compact,
+    /// 表格，适合列表类结果
+    Table,
+}
+
+/// 打印结果
+pub fn print(value: &Value, format: OutputFormat) {
+    println!("{}", render(value, format));
+}
+
+/// 按格式渲染结果
+pub fn render(value: &Value, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value).unwrap_or_default(),
+        OutputFormat::Compact => value.to_string(),
+        OutputFormat::Table => match rows(value) {
+            Some(rows) => table(rows),
+            None => serde_json::to_string_pretty(value).unwrap_or_default(),
+        },
+    }
+}
+
+/// 找出可以按表格展示的行：数组本身，或对象里的第一个数组字段（如 `items`）
+fn rows(value: &Value) -> Option<&[Value]> {
+    match value {
+        Value::Array(items) => Some(items),
+        Value::Object(map) => map
+            .get("items")
+            .and_then(Value::as_array)
+            .or_else(|| map.values().find_map(Value::as_array))
+            .map(Vec::as_slice),
+        _ => None,
+    }
+}
+
+fn table(rows: &[Value]) -> String {
+    if rows.is_empty() {
+        return "(empty)".to_string();
+    }
+
+    // 列按首次出现的顺序排列；非对象行作为单列 `value`
+    let mut columns: Vec<String> = Vec::new();
+    for row in rows {
+        match row {
+            Value::Object(map) => {
+                for key in map.keys() {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                }
+            }
+            _ if !columns.iter().any(|column| column == "value") => {
+                columns.push("value".to_string());
+            }
+            _ => {}
+        }
+    }
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| match row {
+                    Value::Object(map) => map.get(column).map(cell).unwrap_or_default(),
+                    other if column == "value" => cell(other),
+                    _ => String::new(),
+                })
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            cells
+                .iter()
+                .map(|row| row[index].chars().count())
+                .chain(std::iter::once(column.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut out = String::new();
+    push_line(&mut out, &columns, &widths);
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    push_line(&mut out, &separator, &widths);
+    for row in &cells {
+        push_line(&mut out, row, &widths);
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+fn push_line(out: &mut String, cells: &[String], widths: &[usize]) {
+    let line: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{cell:<width$}"))
+        .collect();
+    out.push_str(line.join("  ").trim_end());
+    out.push('\n');
+}
+
+fn cell(value: &Value) -> String {
+    let text = match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    let text = text.replace(['\n', '\r', '\t'], " ");
+    if text.chars().count() > MAX_CELL_WIDTH {
+        let mut truncated: String = text.chars().take(MAX_CELL_WIDTH - 1).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_table_uses_items_field() {
+        let value = json!({
+            "items": [
+                {"chat_id": "oc_1", "name": "研发群"},
+                {"chat_id": "oc_2", "name": "运维", "owner_id": "ou_1"},
+            ],
+            "has_more": false,
+        });
+        let rendered = render(&value, OutputFormat::Table);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("chat_id"));
+        assert!(lines[0].ends_with("owner_id"));
+        assert!(lines[2].contains("研发群"));
+        assert!(lines[3].ends_with("ou_1"));
+    }
+
+    #[test]
+    fn test_table_falls_back_to_json() {
+        let value = json!({"file_token": "boxcn_1"});
+        assert_eq!(
+            render(&value, OutputFormat::Table),
+            render(&value, OutputFormat::Json)
+        );
+        assert_eq!(render(&json!([]), OutputFormat::Table), "(empty)");
+    }
+
+    #[test]
+    fn test_cell_truncates_and_flattens() {
+        let long = "x".repeat(MAX_CELL_WIDTH + 10);
+        assert_eq!(cell(&json!(long)).chars().count(), MAX_CELL_WIDTH);
+        assert_eq!(cell(&json!({"a": 1})), r#"{"a":1}"#);
+        assert_eq!(cell(&json!("a\nb")), "a b");
+    }
+}
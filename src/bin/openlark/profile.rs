@@ -0,0 +1,264 @@
+//! 凭证 profile 管理
+//!
+//! 配置文件默认位于 `~/.config/openlark/config.toml`（可用 `OPENLARK_CONFIG` 覆盖）：
+//!
+//! ```toml
+//! default_profile = "prod"
+//!
+//! [profiles.prod]
+//! app_id = "cli_xxx"
+//! app_secret = "xxx"
+//!
+//! [profiles.staging]
+//! app_id = "cli_yyy"
+//! # 未写 app_secret 时从 OPENLARK_APP_SECRET 读取
+//! base_url = "https://open.larksuite.com"
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, bail};
+use openlark_core::constants::AppType;
+use serde::{Deserialize, Serialize};
+
+/// 未指定时使用的 profile 名称
+pub const DEFAULT_PROFILE: &str = "default";
+/// 默认 API 地址
+pub const DEFAULT_BASE_URL: &str = "https://open.feishu.cn";
+
+/// 单个 profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    /// 应用 ID
+    pub app_id: String,
+    /// 应用密钥，缺省时从环境变量读取
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_secret: Option<String>,
+    /// API 地址
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    /// 应用类型：`self_build` / `marketplace`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<String>,
+    /// 是否允许非飞书/Lark 域名的 `base_url`（私有化部署、本地仿真服务）
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_custom_base_url: bool,
+}
+
+/// 配置文件内容
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileFile {
+    /// 默认 profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+    /// 所有 profile
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl ProfileFile {
+    /// 配置文件路径
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os("OPENLARK_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(base.join("openlark").join("config.toml"))
+    }
+
+    /// 读取配置文件，文件不存在时返回空配置
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .with_context(|| format!("解析配置文件 {} 失败", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("读取配置文件 {} 失败", path.display())),
+        }
+    }
+
+    /// 从 TOML 文本解析
+    pub fn parse(content: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// 写回配置文件
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("创建目录 {} 失败", parent.display()))?;
+        }
+        std::fs::write(path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("写入配置文件 {} 失败", path.display()))?;
+        restrict_permissions(path);
+        Ok(())
+    }
+
+    /// 选用的 profile 名称：显式指定 > `default_profile` > `default`
+    pub fn selected_name<'a>(&'a self, explicit: Option<&'a str>) -> &'a str {
+        explicit
+            .or(self.default_profile.as_deref())
+            .unwrap_or(DEFAULT_PROFILE)
+    }
+}
+
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    // 配置文件含密钥，尽量只允许当前用户读取
+    let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600));
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) {}
+
+/// 命令行上显式传入的凭证
+#[derive(Debug, Clone, Default)]
+pub struct CredentialOverrides {
+    /// `--app-id` / `OPENLARK_APP_ID`
+    pub app_id: Option<String>,
+    /// `--app-secret` / `OPENLARK_APP_SECRET`
+    pub app_secret: Option<String>,
+    /// `--base-url` / `OPENLARK_BASE_URL`
+    pub base_url: Option<String>,
+}
+
+/// 最终生效的凭证
+#[derive(Debug, Clone, PartialEq)]
+pub struct Credentials {
+    /// 应用 ID
+    pub app_id: String,
+    /// 应用密钥
+    pub app_secret: String,
+    /// API 地址
+    pub base_url: String,
+    /// 应用类型
+    pub app_type: AppType,
+    /// 是否允许自定义域名
+    pub allow_custom_base_url: bool,
+}
+
+impl Credentials {
+    /// 合并命令行参数与 profile，命令行参数优先
+    pub fn resolve(
+        file: &ProfileFile,
+        profile: Option<&str>,
+        overrides: CredentialOverrides,
+    ) -> anyhow::Result<Self> {
+        let name = file.selected_name(profile);
+        let stored = file.profiles.get(name);
+        if stored.is_none() && profile.is_some() {
+            bail!("profile `{name}` 不存在，可用 `openlark profile list` 查看");
+        }
+        let stored = stored.cloned().unwrap_or_default();
+
+        let app_id = overrides
+            .app_id
+            .or(Some(stored.app_id).filter(|id| !id.is_empty()))
+            .context("缺少 app_id：请使用 --app-id、OPENLARK_APP_ID 或 `openlark profile set`")?;
+        let app_secret = overrides.app_secret.or(stored.app_secret).context(
+            "缺少 app_secret：请使用 --app-secret、OPENLARK_APP_SECRET 或 `openlark profile set`",
+        )?;
+        let app_type = match stored.app_type.as_deref() {
+            None | Some("self_build") => AppType::SelfBuild,
+            Some("marketplace") => AppType::Marketplace,
+            Some(other) => bail!("未知的 app_type `{other}`，可选值：self_build / marketplace"),
+        };
+        Ok(Self {
+            app_id,
+            app_secret,
+            base_url: overrides
+                .base_url
+                .or(stored.base_url)
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            app_type,
+            allow_custom_base_url: stored.allow_custom_base_url,
+        })
+    }
+}
+
+/// 展示用的密钥掩码
+pub fn mask_secret(secret: &str) -> String {
+    let visible: String = secret.chars().take(4).collect();
+    if secret.chars().count() <= 4 {
+        "****".to_string()
+    } else {
+        format!("{visible}****")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+default_profile = "prod"
+
+[profiles.prod]
+app_id = "cli_prod"
+app_secret = "prod_secret"
+
+[profiles.lark]
+app_id = "cli_lark"
+base_url = "https://open.larksuite.com"
+app_type = "marketplace"
+"#;
+
+    #[test]
+    fn test_resolve_default_profile() {
+        let file = ProfileFile::parse(SAMPLE).unwrap();
+        let creds = Credentials::resolve(&file, None, CredentialOverrides::default()).unwrap();
+        assert_eq!(creds.app_id, "cli_prod");
+        assert_eq!(creds.app_secret, "prod_secret");
+        assert_eq!(creds.base_url, DEFAULT_BASE_URL);
+        assert_eq!(creds.app_type, AppType::SelfBuild);
+    }
+
+    #[test]
+    fn test_overrides_take_precedence() {
+        let file = ProfileFile::parse(SAMPLE).unwrap();
+        let creds = Credentials::resolve(
+            &file,
+            Some("lark"),
+            CredentialOverrides {
+                app_secret: Some("from_env".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(creds.app_id, "cli_lark");
+        assert_eq!(creds.app_secret, "from_env");
+        assert_eq!(creds.base_url, "https://open.larksuite.com");
+        assert_eq!(creds.app_type, AppType::Marketplace);
+    }
+
+    #[test]
+    fn test_missing_profile_and_secret() {
+        let file = ProfileFile::parse(SAMPLE).unwrap();
+        assert!(Credentials::resolve(&file, Some("nope"), CredentialOverrides::default()).is_err());
+        let err =
+            Credentials::resolve(&file, Some("lark"), CredentialOverrides::default()).unwrap_err();
+        assert!(err.to_string().contains("app_secret"));
+    }
+
+    #[test]
+    fn test_save_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("config.toml");
+        let file = ProfileFile::parse(SAMPLE).unwrap();
+        file.save(&path).unwrap();
+        assert_eq!(ProfileFile::load(&path).unwrap(), file);
+        assert_eq!(
+            ProfileFile::load(&dir.path().join("missing.toml")).unwrap(),
+            ProfileFile::default()
+        );
+    }
+
+    #[test]
+    fn test_mask_secret() {
+        assert_eq!(mask_secret("abcdefgh"), "abcd****");
+        assert_eq!(mask_secret("abc"), "****");
+    }
+}